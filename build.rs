@@ -0,0 +1,175 @@
+// `data/emoji-test.txt`(유니코드 공식 emoji-test.txt 포맷)를 빌드 시점에 파싱해,
+// `src/emoji_table.rs`가 `include!`하는 정적 조회 테이블을 생성한다.
+//
+// 손으로 적은 매직 넘버(예: `0x1F000` 이상이면 이모지, `0x1F1E0..=0x1F1FF`면 국기,
+// `\u{200D}`면 ZWJ)는 유니코드 버전이 올라갈 때마다 새로 추가되는 이모지 블록이나
+// BMP 안의 이모지(`©`, `‼` 등)를 놓치기 쉽다. 이 빌드 스크립트는 그 대신 원본
+// 데이터 파일의 `codepoints ; qualification # name` 줄과 `# group:`/`# subgroup:`
+// 헤더를 그대로 읽어, 단일 코드포인트 이모지 목록과 유효한 ZWJ/국기/키캡/변경자
+// 시퀀스 목록(그룹/서브그룹 메타데이터 포함)을 만든다.
+
+use std::collections::BTreeSet;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+struct Entry {
+    codepoints: Vec<u32>,
+    group: String,
+    subgroup: String,
+}
+
+fn parse_emoji_test(contents: &str) -> Vec<Entry> {
+    let mut entries = Vec::new();
+    let mut group = String::new();
+    let mut subgroup = String::new();
+
+    for line in contents.lines() {
+        let line = line.trim();
+
+        if let Some(rest) = line.strip_prefix("# group:") {
+            group = rest.trim().to_string();
+            continue;
+        }
+        if let Some(rest) = line.strip_prefix("# subgroup:") {
+            subgroup = rest.trim().to_string();
+            continue;
+        }
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        // "1F468 200D 1F469 200D 1F467 ; fully-qualified # 👨‍👩‍👧 family: man, woman, girl"
+        let Some((codepoints_part, _rest)) = line.split_once(';') else {
+            continue;
+        };
+
+        let codepoints: Vec<u32> = codepoints_part
+            .split_whitespace()
+            .filter_map(|hex| u32::from_str_radix(hex, 16).ok())
+            .collect();
+
+        if codepoints.is_empty() {
+            continue;
+        }
+
+        entries.push(Entry {
+            codepoints,
+            group: group.clone(),
+            subgroup: subgroup.clone(),
+        });
+    }
+
+    entries
+}
+
+/// Fitzpatrick 피부톤 변경자 범위. 이 범위의 두 번째 코드포인트가 뒤따르는 첫
+/// 코드포인트를 "변경자 기반 문자(emoji modifier base)"로 취급한다.
+fn is_skin_tone_modifier(code: u32) -> bool {
+    (0x1F3FB..=0x1F3FF).contains(&code)
+}
+
+fn generate_source(entries: &[Entry]) -> String {
+    let mut single_codepoints: BTreeSet<u32> = BTreeSet::new();
+    let mut modifier_bases: BTreeSet<u32> = BTreeSet::new();
+    let mut sequences: Vec<&Entry> = Vec::new();
+
+    for entry in entries {
+        match entry.codepoints.as_slice() {
+            [only] => {
+                single_codepoints.insert(*only);
+            }
+            [base, modifier] if is_skin_tone_modifier(*modifier) => {
+                modifier_bases.insert(*base);
+                sequences.push(entry);
+            }
+            _ => sequences.push(entry),
+        }
+    }
+
+    let mut out = String::new();
+    out.push_str("// 이 파일은 build.rs가 data/emoji-test.txt로부터 생성했습니다. 손으로 고치지 마세요.\n\n");
+
+    write!(out, "pub static EMOJI_CODEPOINTS: &[u32] = &[").unwrap();
+    for code in &single_codepoints {
+        write!(out, "0x{code:X}, ").unwrap();
+    }
+    out.push_str("];\n\n");
+
+    write!(out, "pub static EMOJI_MODIFIER_BASES: &[u32] = &[").unwrap();
+    for code in &modifier_bases {
+        write!(out, "0x{code:X}, ").unwrap();
+    }
+    out.push_str("];\n\n");
+
+    out.push_str("pub static EMOJI_SEQUENCES: &[(&[u32], &str, &str)] = &[\n");
+    for entry in &sequences {
+        out.push_str("    (&[");
+        for code in &entry.codepoints {
+            write!(out, "0x{code:X}, ").unwrap();
+        }
+        write!(out, "], {:?}, {:?}),\n", entry.group, entry.subgroup).unwrap();
+    }
+    out.push_str("];\n");
+
+    out
+}
+
+/// `data/unsafe_ranges.json`(실제 DLL을 상대로 한 discovery 테스트가
+/// `char_ranges::generate::write_table`로 남긴, 정렬된 `(start, end)` 구간 목록)을
+/// `char_ranges::GENERATED_UNSAFE_RANGES` 정적 배열로 컴파일해 넣는다.
+///
+/// 이 discovery 데이터는 Windows + 실제 `J2KEngine.dll`이 있어야만 만들 수 있어,
+/// `emoji-test.txt`와 달리 리포에 항상 존재한다고 가정할 수 없다. 파일이 없으면 빈
+/// 테이블을 생성해 빌드는 계속 통과시키되, `is_safe_chars_generated`를 쓰려는 코드가
+/// 있다면 아직 실측 데이터가 없다는 뜻임을 경고로 남긴다.
+fn generate_unsafe_ranges_table() -> String {
+    let data_path = "data/unsafe_ranges.json";
+    println!("cargo:rerun-if-changed={data_path}");
+
+    let ranges: Vec<(u32, u32)> = match fs::read_to_string(data_path) {
+        Ok(contents) => {
+            serde_json::from_str(&contents).expect("data/unsafe_ranges.json 형식이 잘못되었습니다")
+        }
+        Err(_) => {
+            println!(
+                "cargo:warning=data/unsafe_ranges.json이 없어 GENERATED_UNSAFE_RANGES를 빈 \
+                 테이블로 생성합니다. char_ranges::generate::write_table로 discovery 결과를 \
+                 채워 넣으세요."
+            );
+            Vec::new()
+        }
+    };
+
+    let mut out = String::new();
+    out.push_str(
+        "// 이 파일은 build.rs가 data/unsafe_ranges.json으로부터 생성했습니다. 손으로 고치지 마세요.\n\n",
+    );
+    write!(out, "pub static GENERATED_UNSAFE_RANGES: &[(u32, u32)] = &[").unwrap();
+    for (start, end) in ranges {
+        write!(out, "(0x{start:X}, 0x{end:X}), ").unwrap();
+    }
+    out.push_str("];\n");
+
+    out
+}
+
+fn main() {
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR이 설정되어 있지 않습니다");
+
+    let data_path = "data/emoji-test.txt";
+    println!("cargo:rerun-if-changed={data_path}");
+
+    let contents = fs::read_to_string(data_path).expect("emoji-test.txt를 읽지 못했습니다");
+    let entries = parse_emoji_test(&contents);
+    let generated = generate_source(&entries);
+
+    let dest = Path::new(&out_dir).join("emoji_table_generated.rs");
+    fs::write(dest, generated).expect("생성된 이모지 테이블을 쓰지 못했습니다");
+
+    let unsafe_ranges_generated = generate_unsafe_ranges_table();
+    let unsafe_ranges_dest = Path::new(&out_dir).join("char_ranges_generated.rs");
+    fs::write(unsafe_ranges_dest, unsafe_ranges_generated)
+        .expect("생성된 char_ranges 테이블을 쓰지 못했습니다");
+}
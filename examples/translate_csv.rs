@@ -1,3 +1,5 @@
+use eztrans_rs::csv_dialect;
+use eztrans_rs::csv_glossary::{self, Glossary};
 use eztrans_rs::EzTransEngine;
 use serde::{Deserialize, Serialize};
 use std::error::Error;
@@ -64,12 +66,40 @@ fn main() -> Result<(), Box<dyn Error>> {
     }
     println!("초기화 완료!\n");
 
-    // CSV 읽기
+    // 같은 `character` 문자열이 여러 행에 반복되는 CSV가 흔하므로, 캐시를 켜서 반복된
+    // 행은 DLL을 다시 왕복하지 않고 이전 결과를 그대로 재사용하게 한다.
+    engine.enable_translation_cache();
+
+    // CSV 읽기 (구분자/인용 문자/헤더 유무와 인코딩을 먼저 추정한다 - 일본어 원문/
+    // 한국어 기대 번역 열이 Shift-JIS나 EUC-KR로 저장된 덤프가 흔하기 때문)
     println!("CSV 파일 읽는 중...");
-    let file = File::open(&input_csv)?;
-    let mut reader = csv::ReaderBuilder::new()
-        .has_headers(true)
-        .from_reader(file);
+    let detected = csv_dialect::detect_dialect(&input_csv)?;
+    println!(
+        "방언 감지: 구분자 {:?}, 인용 문자 {:?}, 헤더 {}, 인코딩 {}",
+        detected.report.delimiter as char,
+        detected.report.quote as char,
+        detected.report.has_headers,
+        detected.report.encoding.name()
+    );
+    let mut reader_builder = detected.reader_builder;
+    let mut reader = reader_builder.from_reader(detected.contents.as_bytes());
+    let records: Vec<InputRecord> = reader.deserialize().collect::<Result<_, _>>()?;
+
+    // char_name/trans_name 열로 용어집을 지어 둔다. EzTrans 사전이 고유 명사를 의역해
+    // 버리는 걸 막아야 `character`를 번역할 때 그 이름이 그대로 살아남는다.
+    let mut glossary_csv = csv::WriterBuilder::new()
+        .has_headers(false)
+        .from_writer(Vec::new());
+    for record in &records {
+        if let (Some(char_name), Some(trans_name)) = (&record.char_name, &record.trans_name) {
+            if !char_name.is_empty() && !trans_name.is_empty() {
+                glossary_csv.write_record([char_name, trans_name])?;
+            }
+        }
+    }
+    let glossary_csv = glossary_csv.into_inner()?;
+    let glossary = Glossary::from_csv(glossary_csv.as_slice())?;
+    println!("용어집 적용: {}개 용어\n", glossary.len());
 
     let mut output_records = Vec::new();
     let mut total = 0;
@@ -77,8 +107,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     let mut skipped = 0;
     let mut matches = 0;
 
-    for result in reader.deserialize() {
-        let record: InputRecord = result?;
+    for record in records {
         total += 1;
 
         // accept가 TRUE인 경우 건너뛰기
@@ -103,8 +132,8 @@ fn main() -> Result<(), Box<dyn Error>> {
             continue;
         }
 
-        // 번역 실행
-        match engine.default_translate(&record.character) {
+        // 번역 실행 (용어집에 등록된 고유 명사는 번역을 건너뛰고 그대로 치환됨)
+        match csv_glossary::translate_with_glossary(&engine, &record.character, &glossary) {
             Ok(eztrans_result) => {
                 translated += 1;
 
@@ -144,6 +173,8 @@ fn main() -> Result<(), Box<dyn Error>> {
     println!("번역 성공: {}", translated);
     println!("건너뜀: {}", skipped);
     println!("일치: {} ({:.1}%)", matches, (matches as f64 / translated as f64) * 100.0);
+    let (cache_hits, cache_misses) = engine.translation_cache_stats();
+    println!("캐시: 히트 {}, 미스 {}", cache_hits, cache_misses);
 
     // 결과를 CSV로 저장
     println!("\n결과 저장 중: {}", output_csv.display());
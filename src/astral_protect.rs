@@ -0,0 +1,191 @@
+// J2KEngine.dll은 `tests/japanese_translation_test.rs`의 `test_emoji_translation`류
+// 케이스가 보여주듯 사실상 UCS-2/BMP 전용이라, U+10000 이상의 코드포인트(이모지 대부분,
+// 서로게이트 쌍으로 인코딩되는 모든 문자)나 ZWJ(U+200D)로 묶인 시퀀스, variation
+// selector(U+FE0E/U+FE0F)가 붙은 시퀀스를 통째로 깨뜨리거나 잘라낸다.
+//
+// `grapheme_encode`도 비슷한 센티널 치환 기법을 쓰지만, 그쪽은 `is_safe_chars`를
+// 통과하지 못하는 한글 등 BMP 문자까지 폭넓게 보호 대상으로 잡아 `translate_grapheme_safe`
+// 라는 별도 옵트인 경로로만 쓰인다. 이 모듈은 "애초에 BMP 전용 엔진이 물리적으로
+// 표현하지 못하는 클러스터"만 좁게 골라내, `default_translate`/`translate_mmntw`가
+// 평소처럼 호출될 때도 자동으로 적용할 수 있게 한다.
+//
+// 치환한 자리에는 repo 관례대로 센티널을 심어 두되(`grapheme_encode`의 `QZ` 계열과
+// 헷갈리지 않도록 별도 알파벳 사용), 엔진이 영숫자 토큰 사이에 공백을 끼워 넣는 경우를
+// 감안해 복원 시 센티널 내부에 섞여 들어간 공백도 건너뛰며 읽는다.
+
+use unicode_segmentation::UnicodeSegmentation;
+
+const SENTINEL_PREFIX: &str = "XJ";
+const SENTINEL_SUFFIX: &str = "JX";
+/// 센티널 안의 숫자 자릿수. `XJ0000JX`처럼 4자리 0-패딩 고정폭으로 맞춘다.
+const SENTINEL_DIGITS: usize = 4;
+
+const ZWJ: char = '\u{200D}';
+const VARIATION_SELECTOR_15: char = '\u{FE0E}';
+const VARIATION_SELECTOR_16: char = '\u{FE0F}';
+const COMBINING_ENCLOSING_KEYCAP: char = '\u{20E3}';
+
+/// 클러스터가 BMP 전용 엔진이 표현할 수 없는 구성 요소를 담고 있는지 확인한다:
+/// 코드포인트가 U+10000 이상(국기·피부톤 변경자·대부분의 이모지가 여기 속한다)이거나,
+/// ZWJ/variation selector/키캡 결합 문자처럼 BMP 안에 있지만 단독으로는 의미가 없는
+/// 결합자를 포함하는 경우.
+fn needs_protection(cluster: &str) -> bool {
+    cluster.chars().any(|c| {
+        (c as u32) >= 0x10000
+            || c == ZWJ
+            || c == VARIATION_SELECTOR_15
+            || c == VARIATION_SELECTOR_16
+            || c == COMBINING_ENCLOSING_KEYCAP
+    })
+}
+
+fn format_sentinel(index: usize) -> String {
+    format!("{SENTINEL_PREFIX}{:0width$}{SENTINEL_SUFFIX}", index, width = SENTINEL_DIGITS)
+}
+
+/// `protect`의 결과: 센티널로 치환된 텍스트와, 인덱스로 복원할 수 있는 원본 클러스터
+/// 표.
+pub struct Protected {
+    pub text: String,
+    clusters: Vec<String>,
+}
+
+/// `input`을 확장 자소 클러스터(UAX #29) 단위로 나눠, BMP 전용 엔진이 다루지 못하는
+/// 클러스터를 통째로 센티널로 바꾼다. 👨‍👩‍👧 같은 ZWJ 시퀀스는 코드포인트 하나하나가
+/// 아니라 클러스터 전체가 한 번에 치환되어야 엔진이 그 사이를 갈라놓지 못한다.
+pub fn protect(input: &str) -> Protected {
+    let mut clusters = Vec::new();
+    let mut text = String::with_capacity(input.len());
+
+    for grapheme in input.graphemes(true) {
+        if needs_protection(grapheme) {
+            let index = clusters.len();
+            clusters.push(grapheme.to_string());
+            text.push_str(&format_sentinel(index));
+        } else {
+            text.push_str(grapheme);
+        }
+    }
+
+    Protected { text, clusters }
+}
+
+impl Protected {
+    /// 번역된 텍스트에서 센티널을 찾아 원래 클러스터로 되돌린다. 엔진이 `XJ`/숫자/`JX`
+    /// 사이에 공백을 끼워 넣어도(영숫자 경계에서 토큰을 나누는 엔진이 있을 수 있으므로)
+    /// 그 공백을 건너뛰고 같은 센티널로 인식한다. 숫자가 알려진 클러스터 개수를
+    /// 벗어나거나 형식이 깨진 경우는 손대지 않고 그대로 둔다.
+    pub fn restore(&self, translated: &str) -> String {
+        let mut result = String::with_capacity(translated.len());
+        let mut rest = translated;
+
+        while let Some(start) = rest.find(SENTINEL_PREFIX) {
+            result.push_str(&rest[..start]);
+            rest = &rest[start..];
+
+            match match_sentinel(rest).and_then(|(index, len)| {
+                self.clusters.get(index).map(|cluster| (cluster, len))
+            }) {
+                Some((cluster, len)) => {
+                    result.push_str(cluster);
+                    rest = &rest[len..];
+                }
+                None => {
+                    result.push_str(SENTINEL_PREFIX);
+                    rest = &rest[SENTINEL_PREFIX.len()..];
+                }
+            }
+        }
+        result.push_str(rest);
+        result
+    }
+}
+
+/// `rest`(반드시 `SENTINEL_PREFIX`로 시작한다)의 맨 앞에서 센티널을 찾되, 영숫자가
+/// 아닌 공백류 문자는 건너뛰며 읽는다. 매치에 성공하면 클러스터 인덱스와, `rest` 기준
+/// 소비한 바이트 길이를 돌려준다.
+fn match_sentinel(rest: &str) -> Option<(usize, usize)> {
+    let target_len = SENTINEL_PREFIX.len() + SENTINEL_DIGITS + SENTINEL_SUFFIX.len();
+    // 센티널 자체보다 너무 많은 공백이 끼어 있으면 같은 센티널이 아니라 우연히 멀리
+    // 떨어진 텍스트가 매치되는 것을 막기 위해, 끼어들 수 있는 공백 개수에 여유만 둔다.
+    let max_chars = target_len + 8;
+
+    let mut collected = String::with_capacity(target_len);
+    let mut consumed_bytes = 0usize;
+
+    for (count, c) in rest.chars().enumerate() {
+        if count >= max_chars || collected.len() >= target_len {
+            break;
+        }
+        consumed_bytes += c.len_utf8();
+        if c.is_whitespace() {
+            continue;
+        }
+        collected.push(c);
+    }
+
+    if collected.len() != target_len || !collected.is_ascii() {
+        return None;
+    }
+    if &collected[..SENTINEL_PREFIX.len()] != SENTINEL_PREFIX {
+        return None;
+    }
+    let digits = &collected[SENTINEL_PREFIX.len()..SENTINEL_PREFIX.len() + SENTINEL_DIGITS];
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    if &collected[SENTINEL_PREFIX.len() + SENTINEL_DIGITS..] != SENTINEL_SUFFIX {
+        return None;
+    }
+
+    let index = digits.parse::<usize>().ok()?;
+    Some((index, consumed_bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zwj_family_emoji_is_protected_as_one_cluster() {
+        let input = format!("앞{}뒤", "👨‍👩‍👧");
+        let protected = protect(&input);
+        assert_eq!(protected.clusters.len(), 1);
+        assert_eq!(protected.restore(&protected.text), input);
+    }
+
+    #[test]
+    fn test_flag_sequence_round_trips() {
+        let protected = protect("🇰🇷");
+        assert_eq!(protected.clusters.len(), 1);
+        assert_eq!(protected.restore(&protected.text), "🇰🇷");
+    }
+
+    #[test]
+    fn test_skin_tone_modifier_round_trips() {
+        let protected = protect("👋🏻");
+        assert_eq!(protected.clusters.len(), 1);
+        assert_eq!(protected.restore(&protected.text), "👋🏻");
+    }
+
+    #[test]
+    fn test_hangul_and_ascii_are_left_untouched() {
+        let protected = protect("안녕 hello");
+        assert!(protected.clusters.is_empty());
+        assert_eq!(protected.text, "안녕 hello");
+    }
+
+    #[test]
+    fn test_restore_tolerates_engine_inserted_spaces_inside_sentinel() {
+        let protected = protect("hi 👋🏻 there");
+        let mangled = protected.text.replace("XJ", "XJ ").replacen("JX", " JX", 1);
+        assert_eq!(protected.restore(&mangled), "hi 👋🏻 there");
+    }
+
+    #[test]
+    fn test_restore_tolerates_spaces_surrounding_sentinel() {
+        let protected = protect("a👋🏻b");
+        let mangled = protected.text.replace("XJ", " XJ").replace("JX", "JX ");
+        assert_eq!(protected.restore(&mangled), "a 👋🏻 b");
+    }
+}
@@ -0,0 +1,113 @@
+// FFI 호출을 전담 스레드 하나에 고정시키고, 비동기 메서드로 감싸 노출하는 래퍼.
+//
+// J2K DLL은 스레드 안전하지 않으므로, `translate_fm`/`set_property` 같은 호출은
+// 항상 같은 스레드에서 일어나야 한다. `AsyncEzTransEngine::spawn`은 전용 OS
+// 스레드를 하나 띄워 `EzTransEngine` 클론을 그 스레드에 묶어두고, 호출자는 요청을
+// mpsc 채널로 보낸 뒤 `tokio::sync::oneshot`으로 응답을 기다린다. 이렇게 하면
+// 비동기 런타임을 블로킹하지 않으면서도 모든 unsafe 호출이 소유 스레드 밖으로
+// 새어나가지 않는다.
+
+use std::ffi::c_int;
+use std::sync::mpsc;
+use std::thread;
+
+use tokio::sync::oneshot;
+
+use crate::{EzTransEngine, EzTransError};
+
+enum Request {
+    TranslateFm(String, oneshot::Sender<Result<String, EzTransError>>),
+    DefaultTranslate(String, oneshot::Sender<Result<String, EzTransError>>),
+    SetProperty(c_int, c_int, oneshot::Sender<Result<(), EzTransError>>),
+    ReloadUserDict(oneshot::Sender<Result<(), EzTransError>>),
+}
+
+/// 전담 워커 스레드가 독점하는 `EzTransEngine`을 비동기 메서드로 감싼 핸들.
+pub struct AsyncEzTransEngine {
+    engine: EzTransEngine,
+    requests: mpsc::Sender<Request>,
+}
+
+impl AsyncEzTransEngine {
+    /// `engine`을 소유할 워커 스레드를 띄우고 핸들을 반환한다.
+    ///
+    /// 핸들이 모두 드롭되어 요청 채널이 끊기면 워커 스레드는 자연히 종료된다.
+    pub fn spawn(engine: EzTransEngine) -> Self {
+        let worker_engine = engine.clone();
+        let (requests, rx) = mpsc::channel::<Request>();
+
+        thread::spawn(move || {
+            for request in rx {
+                match request {
+                    Request::TranslateFm(text, reply) => {
+                        let _ = reply.send(worker_engine.translate_fm(&text));
+                    }
+                    Request::DefaultTranslate(text, reply) => {
+                        let _ = reply.send(worker_engine.default_translate(&text));
+                    }
+                    Request::SetProperty(property_id, value, reply) => {
+                        let _ = reply.send(worker_engine.set_property(property_id, value));
+                    }
+                    Request::ReloadUserDict(reply) => {
+                        let _ = reply.send(worker_engine.reload_user_dict());
+                    }
+                }
+            }
+        });
+
+        Self { engine, requests }
+    }
+
+    /// 워커 스레드에서 `translate_fm`을 실행하고 결과를 기다린다.
+    pub async fn translate_fm(&self, text: impl Into<String>) -> Result<String, EzTransError> {
+        let (reply, reply_rx) = oneshot::channel();
+        self.send(Request::TranslateFm(text.into(), reply))?;
+        self.await_reply(reply_rx).await
+    }
+
+    /// 워커 스레드에서 `default_translate`를 실행하고 결과를 기다린다.
+    pub async fn default_translate(&self, text: impl Into<String>) -> Result<String, EzTransError> {
+        let (reply, reply_rx) = oneshot::channel();
+        self.send(Request::DefaultTranslate(text.into(), reply))?;
+        self.await_reply(reply_rx).await
+    }
+
+    /// 워커 스레드에서 `set_property`를 실행하고 결과를 기다린다.
+    pub async fn set_property(&self, property_id: c_int, value: c_int) -> Result<(), EzTransError> {
+        let (reply, reply_rx) = oneshot::channel();
+        self.send(Request::SetProperty(property_id, value, reply))?;
+        self.await_reply(reply_rx).await
+    }
+
+    /// 워커 스레드에서 `reload_user_dict`를 실행하고 결과를 기다린다.
+    pub async fn reload_user_dict(&self) -> Result<(), EzTransError> {
+        let (reply, reply_rx) = oneshot::channel();
+        self.send(Request::ReloadUserDict(reply))?;
+        self.await_reply(reply_rx).await
+    }
+
+    /// 워커 스레드에서 진행 중인 `translate_fm`을 즉시 중단시킨다.
+    ///
+    /// `J2K_StopTranslation`은 다른 스레드에서 호출해 진행 중인 번역을 끊도록
+    /// 설계된 유일한 예외 진입점이므로, 요청 큐를 거치지 않고 엔진 핸들에 직접
+    /// 호출한다 — 큐를 거치면 이미 처리 중인 `translate_fm` 뒤로 밀려 취소
+    /// 신호가 제때 도착하지 못한다.
+    pub fn stop_translation(&self) -> Result<(), EzTransError> {
+        self.engine.stop_translation()
+    }
+
+    fn send(&self, request: Request) -> Result<(), EzTransError> {
+        self.requests
+            .send(request)
+            .map_err(|_| EzTransError::FunctionCallFailed("worker thread is gone".into()))
+    }
+
+    async fn await_reply<T>(
+        &self,
+        reply_rx: oneshot::Receiver<Result<T, EzTransError>>,
+    ) -> Result<T, EzTransError> {
+        reply_rx
+            .await
+            .map_err(|_| EzTransError::FunctionCallFailed("worker thread dropped the reply".into()))?
+    }
+}
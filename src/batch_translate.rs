@@ -0,0 +1,86 @@
+// `set_del_jpn`이 다루는 일본어 문장 구분을 이용해, 큰 입력을 문장 단위로 쪼개
+// `translate_fm`으로 순서대로 번역하는 고수준 배치 API.
+//
+// 게임 스크립트 덤프나 파일 전체를 한 번의 `translate_fm` 호출로 넘기면 오래 걸리고
+// 중간에 멈출 수도 없다. 문장 단위로 쪼개 순서대로 번역하면 진행률을 보고할 수 있고,
+// 문장 사이에서 취소 여부를 확인해 `stop_translation`으로 즉시 중단할 수 있다.
+
+use crate::{EzTransError, EzTransInner};
+
+impl EzTransInner {
+    /// `input`을 문장 단위로 쪼개 차례로 `translate_fm`으로 번역하고 이어 붙인 결과를
+    /// 반환한다.
+    ///
+    /// 세그먼트 하나를 번역할 때마다 `progress(완료한 세그먼트 수, 전체 세그먼트 수)`가
+    /// 호출된다. 세그먼트 사이마다 `should_cancel()`을 확인하며, `true`를 반환하면
+    /// `stop_translation`을 호출해 엔진에 중단을 알리고 그때까지 번역된 세그먼트들만
+    /// 이어 붙여 돌려준다.
+    pub fn translate_batch(
+        &self,
+        input: &str,
+        progress: impl Fn(usize, usize),
+        should_cancel: impl Fn() -> bool,
+    ) -> Result<String, EzTransError> {
+        let segments = split_into_sentences(input);
+        let total = segments.len();
+        let mut translated = Vec::with_capacity(total);
+
+        for (done, segment) in segments.iter().enumerate() {
+            if should_cancel() {
+                self.stop_translation()?;
+                break;
+            }
+
+            translated.push(self.translate_fm(segment)?);
+            progress(done + 1, total);
+        }
+
+        Ok(translated.concat())
+    }
+}
+
+/// 일본어 문장 종결 부호(`。`, `！`, `？`)와 개행을 경계로 `input`을 문장 단위로
+/// 나눈다. 구분자는 직전 문장 끝에 붙여 두므로, 반환된 세그먼트들을 그대로 이어
+/// 붙이면 원문이 복원된다.
+fn split_into_sentences(input: &str) -> Vec<String> {
+    let mut segments = Vec::new();
+    let mut current = String::new();
+
+    for c in input.chars() {
+        current.push(c);
+        if matches!(c, '。' | '！' | '？' | '\n') {
+            segments.push(std::mem::take(&mut current));
+        }
+    }
+
+    if !current.is_empty() {
+        segments.push(current);
+    }
+
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_into_sentences_keeps_terminators_and_reassembles() {
+        let input = "こんにちは。元気ですか？はい！";
+        let segments = split_into_sentences(input);
+        assert_eq!(segments, vec!["こんにちは。", "元気ですか？", "はい！"]);
+        assert_eq!(segments.concat(), input);
+    }
+
+    #[test]
+    fn test_split_into_sentences_keeps_trailing_fragment() {
+        let input = "最初の文。続き";
+        let segments = split_into_sentences(input);
+        assert_eq!(segments, vec!["最初の文。", "続き"]);
+    }
+
+    #[test]
+    fn test_split_into_sentences_empty_input() {
+        assert!(split_into_sentences("").is_empty());
+    }
+}
@@ -0,0 +1,50 @@
+// `EzTransPool`이 띄우는 워커 프로세스의 진입점.
+//
+// 부모 프로세스(`EzTransPool`)로부터 stdin을 통해 한 줄에 한 건씩 번역할 텍스트를 받고,
+// 번역 결과를 stdout에 한 줄로 돌려준다. 줄바꿈 문자는 입력에 올 수 없다고 가정한다
+// (호출 측에서 미리 이스케이프한다).
+
+use std::env;
+use std::io::{self, BufRead, Write};
+
+use eztrans_rs::EzTransEngine;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let dll_path = args.get(1).cloned().unwrap_or_default();
+    let dat_path = args.get(2).cloned().unwrap_or_default();
+
+    let engine = match EzTransEngine::new(&dll_path) {
+        Ok(engine) => engine,
+        Err(e) => {
+            eprintln!("워커: 엔진 로드 실패: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    if let Err(e) = engine.initialize_ex("CSUSER123455", &dat_path) {
+        eprintln!("워커: 엔진 초기화 실패: {}", e);
+        std::process::exit(1);
+    }
+
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+
+    for line in stdin.lock().lines() {
+        let line = match line {
+            Ok(l) => l,
+            Err(_) => break,
+        };
+
+        match engine.default_translate(&line) {
+            Ok(translated) => {
+                let _ = writeln!(out, "OK\t{}", translated.replace('\n', " "));
+            }
+            Err(e) => {
+                let _ = writeln!(out, "ERR\t{}", e);
+            }
+        }
+        let _ = out.flush();
+    }
+}
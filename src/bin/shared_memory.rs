@@ -1,5 +1,6 @@
 // Shared Memory 서버 진입점
 use std::{env, error::Error, u32};
+use eztrans_rs::translation_server::{HandleOutcome, TranslationServer};
 use eztrans_rs::EzTransEngine;
 use windows_shared_memory::{Client, ReceiveMessage};
 
@@ -24,19 +25,23 @@ pub fn main() -> Result<(), Box<dyn Error>> {
         "C:/Program Files (x86)/ChangShinSoft/ezTrans XP/Dat",
     )?;
 
+    eprintln!("output encoding: {:?}", ez_trans.output_encoding());
+
+    let server = TranslationServer::new(ez_trans.clone());
     let client = Client::new(None)?;
 
     loop {
         let receive_server = client.receive(Some(u32::MAX));
 
         if let ReceiveMessage::Message(recv_message) = receive_server {
-            match ez_trans.default_translate(&recv_message) {
-                Ok(translated) => {
+            match server.handle_message(recv_message.as_bytes()) {
+                HandleOutcome::Reply(Ok(translated)) => {
                     client.send(translated.as_bytes())?;
                 }
-                Err(error) => {
-                    client.send(format!("Translation error: {}", &error).as_bytes())?;
+                HandleOutcome::Reply(Err(error)) => {
+                    client.send(format!("Translation error: {}", error).as_bytes())?;
                 }
+                HandleOutcome::Shutdown => break,
             }
         } else if let ReceiveMessage::Exit = receive_server {
             break;
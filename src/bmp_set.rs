@@ -0,0 +1,183 @@
+// ICU의 BMPSet을 본뜬 3단 멤버십 구조로 `char_ranges::is_safe_chars`를 감싼 빠른 조회.
+//
+// `is_safe_chars`는 듬성듬성한 `(start,end)` 구간들에 대한 `matches!` 체인이라, 맞는
+// 구간을 찾을 때까지 앞에서부터 비교를 반복한다. 핫 패스(`hangul_encode`가 문자열의
+// 모든 문자를 훑는 경로)에서는 이 비교 체인 길이가 그대로 비용이 된다. 여기서는 그
+// 구간들로부터 한 번만 다음 세 단계 구조를 만들어 둔다.
+//
+//   1. U+0000–U+00FF: 256비트 비트맵 하나로 직접 조회.
+//   2. 나머지 BMP(U+0100–U+FFFF): 64코드포인트 블록마다 2비트 요약(all-in/all-out/
+//      mixed)을 둬서, 대부분의 블록은 시프트+마스크 한 번으로 끝난다.
+//   3. mixed로 표시된 블록만 정렬된 구간 목록에 대해 이진 탐색한다.
+//
+// BMP 밖(U+FFFF 초과) 문자는 안전 집합에 없는 것으로 간주한다.
+
+use std::sync::OnceLock;
+
+use crate::char_ranges::is_safe_chars;
+
+const BLOCK_SIZE: u32 = 64;
+const BLOCK_COUNT: usize = (0x10000 / BLOCK_SIZE) as usize;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BlockSummary {
+    AllOut,
+    AllIn,
+    Mixed,
+}
+
+struct BmpSet {
+    /// U+0000–U+00FF 전용 비트맵 (4 x 64비트 = 256비트).
+    latin1: [u64; 4],
+    /// U+0100 이상 블록들의 2비트 요약. 인덱스는 `code / BLOCK_SIZE`.
+    blocks: Vec<BlockSummary>,
+    /// mixed 블록을 이진 탐색으로 해소하기 위한, 안전 문자의 정렬된 연속 구간 목록.
+    ranges: Vec<(u32, u32)>,
+}
+
+impl BmpSet {
+    fn build() -> Self {
+        let ranges = find_continuous_safe_ranges();
+
+        let mut latin1 = [0u64; 4];
+        for code in 0..=0xFFu32 {
+            if is_safe_chars(char::from_u32(code).unwrap()) {
+                latin1[(code / 64) as usize] |= 1 << (code % 64);
+            }
+        }
+
+        let mut blocks = vec![BlockSummary::AllOut; BLOCK_COUNT];
+        for (block_index, summary) in blocks.iter_mut().enumerate() {
+            let block_start = block_index as u32 * BLOCK_SIZE;
+            let block_end = block_start + BLOCK_SIZE - 1;
+
+            let mut any_in = false;
+            let mut any_out = false;
+            for code in block_start..=block_end {
+                let safe = char::from_u32(code).is_some_and(is_safe_chars);
+                if safe {
+                    any_in = true;
+                } else {
+                    any_out = true;
+                }
+                if any_in && any_out {
+                    break;
+                }
+            }
+
+            *summary = match (any_in, any_out) {
+                (true, false) => BlockSummary::AllIn,
+                (true, true) => BlockSummary::Mixed,
+                (false, _) => BlockSummary::AllOut,
+            };
+        }
+
+        Self {
+            latin1,
+            blocks,
+            ranges,
+        }
+    }
+
+    fn contains(&self, c: char) -> bool {
+        let code = c as u32;
+
+        if code <= 0xFF {
+            return (self.latin1[(code / 64) as usize] >> (code % 64)) & 1 != 0;
+        }
+
+        if code > 0xFFFF {
+            return false;
+        }
+
+        match self.blocks[(code / BLOCK_SIZE) as usize] {
+            BlockSummary::AllIn => true,
+            BlockSummary::AllOut => false,
+            BlockSummary::Mixed => self
+                .ranges
+                .binary_search_by(|&(start, end)| {
+                    if code < start {
+                        std::cmp::Ordering::Greater
+                    } else if code > end {
+                        std::cmp::Ordering::Less
+                    } else {
+                        std::cmp::Ordering::Equal
+                    }
+                })
+                .is_ok(),
+        }
+    }
+}
+
+/// BMP 전체를 훑어 `is_safe_chars`가 연속으로 참인 `(start, end)` 구간들을 모은다.
+/// 서로게이트 구간(U+D800–U+DFFF)은 유효한 문자가 아니므로 건너뛴다.
+fn find_continuous_safe_ranges() -> Vec<(u32, u32)> {
+    let mut ranges = Vec::new();
+    let mut current: Option<(u32, u32)> = None;
+
+    for code in 0..=0xFFFFu32 {
+        let safe = char::from_u32(code).is_some_and(is_safe_chars);
+        match (current, safe) {
+            (Some((start, end)), true) if end + 1 == code => current = Some((start, code)),
+            (Some((start, end)), true) => {
+                ranges.push((start, end));
+                current = Some((code, code));
+            }
+            (None, true) => current = Some((code, code)),
+            (Some((start, end)), false) => {
+                ranges.push((start, end));
+                current = None;
+            }
+            (None, false) => {}
+        }
+    }
+
+    if let Some(range) = current {
+        ranges.push(range);
+    }
+
+    ranges
+}
+
+static BMP_SET: OnceLock<BmpSet> = OnceLock::new();
+
+/// `c`가 `is_safe_chars`의 안전 문자 집합 밖에 있어 `hangul_encode` 계열의 특수
+/// 인코딩이 필요한지 확인한다. 내부적으로 ICU BMPSet 스타일의 3단 구조를 한 번만
+/// 빌드해 재사용하므로, 호출마다 구간 체인을 선형으로 비교하는 `is_safe_chars`보다
+/// 분기가 적다.
+pub fn needs_special_encoding(c: char) -> bool {
+    !BMP_SET.get_or_init(BmpSet::build).contains(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_is_safe_chars_for_known_samples() {
+        for c in [' ', '¡', 'À', 'Ø', 'A', 'Ａ', '０', '　', '①', '€', '㎕'] {
+            assert_eq!(
+                needs_special_encoding(c),
+                !is_safe_chars(c),
+                "mismatch for U+{:04X}",
+                c as u32
+            );
+        }
+    }
+
+    #[test]
+    fn test_matches_is_safe_chars_across_full_bmp() {
+        for code in 0..=0xFFFFu32 {
+            let Some(c) = char::from_u32(code) else {
+                continue;
+            };
+            assert_eq!(needs_special_encoding(c), !is_safe_chars(c), "mismatch for U+{code:04X}");
+        }
+    }
+
+    #[test]
+    fn test_above_bmp_always_needs_encoding() {
+        assert!(needs_special_encoding('😀'));
+        assert!(needs_special_encoding('\u{10000}'));
+    }
+}
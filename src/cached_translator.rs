@@ -0,0 +1,214 @@
+// `translate_fm`(전문 모드)을 우선 시도하고, 실패하면 표준 번역으로 넘어가면서 결과를
+// 캐시해 두는 래퍼.
+//
+// 자막/게임 텍스트 덤프는 같은 줄이 수백 번 반복되는 경우가 흔한데, 매번 DLL을 왕복하는
+// 건 낭비다. `CachedTranslator`는 `(입력 텍스트, 번역 경로)`를 키로 LRU 캐시를 두고,
+// `reload_user_dict`/`set_prior_dict`/`set_property`처럼 번역 결과에 영향을 주는 호출이
+// 오면 캐시를 통째로 비운다.
+
+use std::collections::{HashMap, VecDeque};
+use std::ffi::c_int;
+use std::sync::Mutex;
+
+use crate::{EzTransEngine, EzTransError};
+
+/// 결과가 어느 경로로 만들어졌는지. `translate_fm`이 실패해 표준 번역으로 폴백한
+/// 결과는 전문 모드 결과와 다를 수 있으므로 따로 캐싱한다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum TranslatePath {
+    Professional,
+    Standard,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    text: String,
+    path: TranslatePath,
+}
+
+/// 용량이 고정된 LRU 캐시. 이 크레이트가 쓰는 다른 곳의 관용구(`glossary.rs`의
+/// `HashMap`+`VecDeque` 조합)를 따라, 별도 의존성 없이 둘만으로 구현한다.
+struct Lru {
+    capacity: usize,
+    map: HashMap<CacheKey, String>,
+    order: VecDeque<CacheKey>,
+}
+
+impl Lru {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            map: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    fn get(&mut self, key: &CacheKey) -> Option<String> {
+        let value = self.map.get(key)?.clone();
+        self.touch(key);
+        Some(value)
+    }
+
+    fn touch(&mut self, key: &CacheKey) {
+        if let Some(pos) = self.order.iter().position(|k| k == key) {
+            let key = self.order.remove(pos).unwrap();
+            self.order.push_back(key);
+        }
+    }
+
+    fn insert(&mut self, key: CacheKey, value: String) {
+        if self.map.contains_key(&key) {
+            self.map.insert(key.clone(), value);
+            self.touch(&key);
+            return;
+        }
+
+        if self.map.len() >= self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.map.remove(&oldest);
+            }
+        }
+
+        self.order.push_back(key.clone());
+        self.map.insert(key, value);
+    }
+
+    fn clear(&mut self) {
+        self.map.clear();
+        self.order.clear();
+    }
+}
+
+/// `EzTransEngine`을 감싸, 전문 모드 실패 시 표준 모드로 폴백하고 결과를 LRU로
+/// 캐싱하는 번역기.
+pub struct CachedTranslator {
+    engine: EzTransEngine,
+    cache: Mutex<Lru>,
+}
+
+impl CachedTranslator {
+    /// `capacity`개의 고유 `(텍스트, 경로)` 조합을 보관하는 캐시를 둔 번역기를 만든다.
+    pub fn new(engine: EzTransEngine, capacity: usize) -> Self {
+        Self {
+            engine,
+            cache: Mutex::new(Lru::new(capacity)),
+        }
+    }
+
+    /// `translate_fm`을 먼저 시도하고, 엔진이 널 포인터를 돌려주거나 호출에 실패하면
+    /// 표준 번역(`default_translate`)으로 넘어간다. 둘 다 실패하면 표준 번역의 에러를
+    /// 그대로 반환한다.
+    pub fn translate(&self, text: &str) -> Result<String, EzTransError> {
+        let professional_key = CacheKey {
+            text: text.to_string(),
+            path: TranslatePath::Professional,
+        };
+        if let Some(cached) = self.cache.lock().unwrap().get(&professional_key) {
+            return Ok(cached);
+        }
+
+        match self.engine.translate_fm(text) {
+            Ok(translated) => {
+                self.cache
+                    .lock()
+                    .unwrap()
+                    .insert(professional_key, translated.clone());
+                Ok(translated)
+            }
+            Err(EzTransError::FunctionCallFailed(_) | EzTransError::TranslationError(_)) => {
+                let standard_key = CacheKey {
+                    text: text.to_string(),
+                    path: TranslatePath::Standard,
+                };
+                if let Some(cached) = self.cache.lock().unwrap().get(&standard_key) {
+                    return Ok(cached);
+                }
+
+                let translated = self.engine.default_translate(text)?;
+                self.cache
+                    .lock()
+                    .unwrap()
+                    .insert(standard_key, translated.clone());
+                Ok(translated)
+            }
+            Err(other) => Err(other),
+        }
+    }
+
+    /// 사전/속성이 바뀌어 기존 캐시 결과가 더 이상 유효하지 않을 때 캐시를 비운다.
+    pub fn invalidate(&self) {
+        self.cache.lock().unwrap().clear();
+    }
+
+    /// 사용자 사전을 다시 불러온 뒤 캐시를 비운다.
+    pub fn reload_user_dict(&self) -> Result<(), EzTransError> {
+        self.engine.reload_user_dict()?;
+        self.invalidate();
+        Ok(())
+    }
+
+    /// 사용자 사전 우선순위를 바꾼 뒤 캐시를 비운다.
+    pub fn set_prior_dict(&self, dict_path: &str) -> Result<(), EzTransError> {
+        self.engine.set_prior_dict(dict_path)?;
+        self.invalidate();
+        Ok(())
+    }
+
+    /// 엔진 속성을 바꾼 뒤 캐시를 비운다.
+    pub fn set_property(&self, property_id: c_int, value: c_int) -> Result<(), EzTransError> {
+        self.engine.set_property(property_id, value)?;
+        self.invalidate();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lru_evicts_oldest_entry_past_capacity() {
+        let mut lru = Lru::new(2);
+        let key = |text: &str| CacheKey {
+            text: text.to_string(),
+            path: TranslatePath::Professional,
+        };
+
+        lru.insert(key("a"), "A".to_string());
+        lru.insert(key("b"), "B".to_string());
+        lru.insert(key("c"), "C".to_string());
+
+        assert_eq!(lru.get(&key("a")), None);
+        assert_eq!(lru.get(&key("b")), Some("B".to_string()));
+        assert_eq!(lru.get(&key("c")), Some("C".to_string()));
+    }
+
+    #[test]
+    fn test_lru_get_refreshes_recency() {
+        let mut lru = Lru::new(2);
+        let key = |text: &str| CacheKey {
+            text: text.to_string(),
+            path: TranslatePath::Professional,
+        };
+
+        lru.insert(key("a"), "A".to_string());
+        lru.insert(key("b"), "B".to_string());
+        lru.get(&key("a"));
+        lru.insert(key("c"), "C".to_string());
+
+        assert_eq!(lru.get(&key("b")), None);
+        assert_eq!(lru.get(&key("a")), Some("A".to_string()));
+    }
+
+    #[test]
+    fn test_clear_empties_cache() {
+        let mut lru = Lru::new(2);
+        let key = CacheKey {
+            text: "a".to_string(),
+            path: TranslatePath::Professional,
+        };
+        lru.insert(key.clone(), "A".to_string());
+        lru.clear();
+        assert_eq!(lru.get(&key), None);
+    }
+}
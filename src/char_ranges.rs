@@ -0,0 +1,235 @@
+// EzTrans 엔진이 `hangul_encode`의 일반 규칙(한글 범위, `special_chars`) 밖에서도
+// 원본 그대로 통과시키는 것으로 확인된 추가 코드포인트 목록.
+//
+// `tests/char_range_discovery.rs`, `tests/char_optimization.rs` 등에서 실제 DLL을
+// 상대로 한 글자씩 찔러 보며 찾아낸 결과를 굳힌 것이라, 범위가 연속적이지 않고 듬성듬성
+// 하다. 새 범위를 추가할 때도 실측 없이 추측으로 넓히지 말 것.
+
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder};
+
+use crate::bmp_set::needs_special_encoding;
+use crate::EzTransError;
+
+pub mod generate;
+
+include!(concat!(env!("OUT_DIR"), "/char_ranges_generated.rs"));
+
+/// `build.rs`가 `data/unsafe_ranges.json`으로부터 생성한 `GENERATED_UNSAFE_RANGES`로
+/// 안전 여부를 판정한다. 그 JSON 파일은 discovery 테스트가 `generate::write_table`로
+/// 남긴 불안전 구간 목록이다(`crate::char_safety_probe::CharSafetyProbe`가 저장하는 것은
+/// 안전 구간 목록이라 이 파일로 쓸 수 없다). 아직 실제 DLL로 discovery를 돌려 데이터를
+/// 채워 넣지 않았다면 `GENERATED_UNSAFE_RANGES`가 비어 있어 언제나 `true`를 돌려주므로,
+/// 실측 데이터가 확보되기 전까지는 [`is_safe_chars`] 대신 쓰지 말 것.
+pub fn is_safe_chars_generated(c: char) -> bool {
+    !generate::lookup(c as u32, GENERATED_UNSAFE_RANGES)
+}
+
+/// 주어진 문자가 `is_hangul_range`/`special_chars` 처리 없이도 EzTrans 엔진에 그대로
+/// 통과되는, 별도로 확인된 추가 안전 문자인지 확인합니다.
+#[inline]
+pub const fn is_safe_chars(c: char) -> bool {
+    let code = c as u32;
+    matches!(code,
+        0x000020 |
+        0x0000A0..=0x0000A3 |
+        0x0000A5..=0x0000A6 |
+        0x0000A9..=0x0000AC |
+        0x0000AE..=0x0000B0 |
+        0x0000B2..=0x0000B3 |
+        0x0000B9 |
+        0x0000C0..=0x0000D6 |
+        0x0000D8..=0x0000F6 |
+        0x0000F8..=0x0000FF |
+        0x00029E |
+        0x00033A |
+        0x000492 |
+        0x0004A1 |
+        0x0004A4 |
+        0x00210D |
+        0x002202 |
+        0x00222B..=0x00222C |
+        0x0022E6 |
+        0x00246F..=0x002473 |
+        0x0024B6..=0x0024BE |
+        0x0024C0..=0x0024C3 |
+        0x0024C5..=0x0024C8 |
+        0x0024CA..=0x0024CF |
+        0x002582 |
+        0x0025EF |
+        0x003013 |
+        0x003099..=0x00309A |
+        0x003232 |
+        0x003239 |
+        0x0032A4..=0x0032A8 |
+        0x00565B |
+        0x005699 |
+        0x005BE4 |
+        0x005CFB |
+        0x006766 |
+        0x0067BB |
+        0x0067C0 |
+        0x006844 |
+        0x0068CF |
+        0x006998 |
+        0x0069E2 |
+        0x006A30 |
+        0x006A46 |
+        0x006A73 |
+        0x006A7E |
+        0x006AE2 |
+        0x006AE4 |
+        0x006BD6 |
+        0x006C3F |
+        0x006C5C |
+        0x006C6F |
+        0x006C86 |
+        0x006CDA |
+        0x006D04 |
+        0x006D6F |
+        0x006D87 |
+        0x007195 |
+        0x007F52 |
+        0x008A51 |
+        0x009357 |
+        0x0093A4 |
+        0x0093C6 |
+        0x0093DE |
+        0x0093F8 |
+        0x009431 |
+        0x009445 |
+        0x009448 |
+        0x00969D |
+        0x0096AF |
+        0x009733 |
+        0x00973B |
+        0x009743 |
+        0x00974D |
+        0x00974F |
+        0x009755 |
+        0x009857 |
+        0x009865 |
+        0x009927 |
+        0x00999E |
+        0x00F929 |
+        0x00F9DC |
+        0x00FA13..=0x00FA14 |
+        0x00FA29..=0x00FA2C
+    )
+}
+
+/// `is_safe_chars`/`bmp_set::needs_special_encoding`는 코드포인트 하나만 보고 안전
+/// 여부를 판정하므로, 결합 문자가 기반 문자 뒤에 붙어야만 문제가 되는 시퀀스처럼 여러
+/// 코드포인트가 모여야 비로소 안전하지 않은 패턴은 놓친다. `UnsafeScanner`는 그런
+/// 다중 코드포인트 패턴을 Aho-Corasick 자동자로 미리 지어 두고, 단일 문자 검사와 한
+/// 번에 묶어 입력을 단 한 번만 훑어 인코딩이 필요한 구간을 전부 찾아낸다 — 문서가 길수록
+/// 문자마다 `is_safe_chars`를 다시 묻는 것보다 유리하다.
+pub struct UnsafeScanner {
+    automaton: AhoCorasick,
+}
+
+impl UnsafeScanner {
+    /// `bad_sequences`(둘 이상의 코드포인트로 이뤄져 단일 문자 검사로는 못 잡는 패턴)
+    /// 로 자동자를 짓는다. 비어 있어도 되며, 그 경우 `scan`은 단일 문자 검사
+    /// (`bmp_set::needs_special_encoding`) 결과만 돌려준다.
+    pub fn new<S: AsRef<str>>(bad_sequences: &[S]) -> Result<Self, EzTransError> {
+        let automaton = AhoCorasickBuilder::new().build(bad_sequences).map_err(|e| {
+            EzTransError::FunctionLoadError(format!("UnsafeScanner 자동자 생성 실패: {e}"))
+        })?;
+        Ok(Self { automaton })
+    }
+
+    /// 자동자에 등록된 다중 코드포인트 패턴만 찾아 바이트 `(start, end)` 구간으로
+    /// 돌려준다(겹치거나 맞닿은 매치는 합친다). 호출자가 자신만의 단일 문자 판정
+    /// (`is_engine_safe` 같은)과 함께 합성하고 싶을 때 쓴다 — 단일 문자 판정까지
+    /// 포함한 전체 결과가 필요하면 [`UnsafeScanner::scan`]을 쓴다.
+    pub fn sequence_spans(&self, input: &str) -> Vec<(usize, usize)> {
+        let mut spans: Vec<(usize, usize)> = self
+            .automaton
+            .find_overlapping_iter(input)
+            .map(|m| (m.start(), m.end()))
+            .collect();
+        spans.sort_unstable();
+        merge_spans(spans)
+    }
+
+    /// `input`을 한 번 훑어 인코딩이 필요한 구간을 바이트 `(start, end)`로 돌려준다.
+    /// 자동자가 찾은 다중 코드포인트 매치와 `bmp_set`의 단일 문자 검사 결과를 합쳐,
+    /// 겹치거나 맞닿은 구간은 중첩 인코딩을 피하기 위해 하나의 최대 구간으로 합친다.
+    pub fn scan(&self, input: &str) -> Vec<(usize, usize)> {
+        let mut spans: Vec<(usize, usize)> = self
+            .automaton
+            .find_overlapping_iter(input)
+            .map(|m| (m.start(), m.end()))
+            .collect();
+
+        for (index, c) in input.char_indices() {
+            if needs_special_encoding(c) {
+                spans.push((index, index + c.len_utf8()));
+            }
+        }
+
+        spans.sort_unstable();
+        merge_spans(spans)
+    }
+}
+
+/// 정렬된 `(start, end)` 구간들 중 겹치거나 맞닿은 것들을 하나의 최대 구간으로 합친다.
+pub(crate) fn merge_spans(spans: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
+    let mut merged: Vec<(usize, usize)> = Vec::with_capacity(spans.len());
+    for (start, end) in spans {
+        match merged.last_mut() {
+            Some(&mut (_, ref mut last_end)) if start <= *last_end => {
+                *last_end = (*last_end).max(end);
+            }
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unsafe_scanner_finds_single_char_needing_encoding() {
+        let scanner = UnsafeScanner::new::<&str>(&[]).unwrap();
+        // U+0020(공백)은 `is_safe_chars`에 있어 'A'만 홀로 인코딩이 필요한 구간이 된다.
+        assert_eq!(scanner.scan("  A  "), vec![(2, 3)]);
+    }
+
+    #[test]
+    fn test_unsafe_scanner_extends_span_into_an_otherwise_safe_neighbor() {
+        // '¡'(U+00A1) 혼자는 `is_safe_chars`를 통과하지만, 그 뒤에 결합 급강세 악센트
+        // (U+0301)가 붙으면 한 자소 클러스터로 통째로 보호해야 한다 — 단일 문자 검사
+        // 만으로는 결합 문자 쪽만 찾아내고 '¡'는 놓친다.
+        let input = "¡\u{0301}";
+        let scanner = UnsafeScanner::new(&["¡\u{0301}"]).unwrap();
+        assert_eq!(scanner.scan(input), vec![(0, input.len())]);
+
+        let without_sequence = UnsafeScanner::new::<&str>(&[]).unwrap();
+        assert_eq!(without_sequence.scan(input), vec![('¡'.len_utf8(), input.len())]);
+    }
+
+    #[test]
+    fn test_unsafe_scanner_merges_overlapping_and_adjacent_spans() {
+        let scanner = UnsafeScanner::new(&["가나", "나다"]).unwrap();
+        // "가나"(0..6)와 "나다"(3..9)가 "나"에서 겹치므로 하나로 합쳐져야 한다.
+        assert_eq!(scanner.scan("가나다"), vec![(0, 9)]);
+    }
+
+    #[test]
+    fn test_safe_chars() {
+        // 안전한 문자 테스트 (범위에 포함된 문자들)
+        assert!(is_safe_chars(' ')); // U+000020
+        assert!(is_safe_chars('¡')); // U+0000A1
+        assert!(is_safe_chars('À')); // U+0000C0
+        assert!(is_safe_chars('Ø')); // U+0000D8
+
+        // 안전하지 않은 문자 테스트 (전각 문자 및 범위 밖 문자)
+        assert!(!is_safe_chars('A')); // U+000041 (범위 밖, 다만 ASCII이므로 엔진 자체는 통과시킴)
+        assert!(!is_safe_chars('Ａ')); // U+FF21 (전각 A)
+        assert!(!is_safe_chars('０')); // U+FF10 (전각 0)
+        assert!(!is_safe_chars('　')); // U+003000 (전각 공백)
+    }
+}
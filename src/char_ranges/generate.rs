@@ -0,0 +1,110 @@
+// Discovery 테스트(`tests/char_range_discovery.rs`의 `#[ignore]`된
+// `test_discover_problematic_unicode_ranges`/`test_generate_optimized_special_chars`)가
+// 실제 DLL을 상대로 찾아낸 "안전하지 않은" 코드포인트를, `build.rs`가 읽어 들여
+// `GENERATED_UNSAFE_RANGES` 정적 테이블로 컴파일해 넣을 수 있는 재현 가능한
+// 중간 표현(정렬된 포함 구간 목록)으로 옮긴다.
+//
+// 지금까지는 discovery 결과가 `println!`으로만 출력되고 끝나, 사람이 손으로
+// `is_safe_chars`의 `matches!` 체인을 다시 타이핑해야 했다. 이 모듈은 그 수작업을
+// "테스트가 `write_table`로 구간을 파일에 남긴다 → `build.rs`가 그 파일을 읽어 코드를
+// 생성한다"는 재현 가능한 파이프라인으로 바꾼다.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// 코드포인트 집합을 연속된 포함 구간으로 합친다. `codes`는 정렬되어 있지 않아도 된다.
+pub fn merge_ranges(codes: impl IntoIterator<Item = u32>) -> Vec<(u32, u32)> {
+    let mut sorted: Vec<u32> = codes.into_iter().collect();
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    let mut ranges = Vec::new();
+    let mut current: Option<(u32, u32)> = None;
+
+    for code in sorted {
+        match current {
+            Some((start, end)) if end + 1 == code => current = Some((start, code)),
+            Some(range) => {
+                ranges.push(range);
+                current = Some((code, code));
+            }
+            None => current = Some((code, code)),
+        }
+    }
+    if let Some(range) = current {
+        ranges.push(range);
+    }
+
+    ranges
+}
+
+/// `code`가 정렬된 포함 구간 목록 `ranges` 중 하나에 속하는지 이진 탐색으로 확인한다.
+pub fn lookup(code: u32, ranges: &[(u32, u32)]) -> bool {
+    ranges
+        .binary_search_by(|&(start, end)| {
+            if code < start {
+                std::cmp::Ordering::Greater
+            } else if code > end {
+                std::cmp::Ordering::Less
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// `ranges`를 `path`에 JSON으로 저장한다. `tests/char_range_discovery.rs`의 discovery
+/// 테스트가 실제 DLL을 상대로 찾아낸 결과를 여기에 남기면, `build.rs`가 다음 빌드에서
+/// 읽어 `GENERATED_UNSAFE_RANGES`를 다시 생성한다.
+pub fn write_table(ranges: &[(u32, u32)], path: impl AsRef<Path>) -> io::Result<()> {
+    let json = serde_json::to_string_pretty(ranges)?;
+    fs::write(path, json)
+}
+
+/// `write_table`이 남긴 구간 목록을 다시 읽는다.
+pub fn read_table(path: impl AsRef<Path>) -> io::Result<Vec<(u32, u32)>> {
+    let contents = fs::read_to_string(path)?;
+    serde_json::from_str(&contents).map_err(io::Error::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_ranges_combines_consecutive_codes() {
+        assert_eq!(merge_ranges([1, 2, 3, 10, 11, 20]), vec![(1, 3), (10, 11), (20, 20)]);
+    }
+
+    #[test]
+    fn test_merge_ranges_handles_unsorted_and_duplicate_input() {
+        assert_eq!(merge_ranges([5, 1, 2, 1, 2]), vec![(1, 2), (5, 5)]);
+    }
+
+    #[test]
+    fn test_lookup_finds_membership_in_ranges() {
+        let ranges = vec![(1, 3), (10, 11), (20, 20)];
+        assert!(lookup(2, &ranges));
+        assert!(lookup(10, &ranges));
+        assert!(lookup(20, &ranges));
+        assert!(!lookup(4, &ranges));
+        assert!(!lookup(19, &ranges));
+    }
+
+    #[test]
+    fn test_write_then_read_table_round_trips() {
+        let dir = std::env::temp_dir().join(format!(
+            "eztrans_rs_unsafe_ranges_test_{:?}",
+            std::thread::current().id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("unsafe_ranges.json");
+
+        let ranges = vec![(1, 3), (100, 105)];
+        write_table(&ranges, &path).unwrap();
+        assert_eq!(read_table(&path).unwrap(), ranges);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
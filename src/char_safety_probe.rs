@@ -0,0 +1,213 @@
+// 실제 DLL을 상대로 코드포인트 범위를 훑어 "이 글자가 `hangul_encode` 없이도 안전하게
+// 통과하는지" 실측하고, 결과를 재사용 가능한 안전 구간 목록으로 저장하는 파이프라인.
+//
+// `tests/char_optimization.rs`의 `#[ignore]`된 테스트들(`test_verify_current_special_chars`,
+// `test_optimize_special_chars`, `test_find_missing_chars`)은 전부 이 "한 글자씩 찔러
+// 보고 println!로 결과를 흘려보내는" 로직을 손으로 반복하고 있었다. `CharSafetyProbe`는
+// 그 로직을 한 곳에 모으고, 안전하다고 분류된 코드포인트를 `char_ranges::generate`가
+// 이미 쓰는 `(start, end)` 구간 JSON 포맷으로 저장한다. 이 표는 안전 구간 목록이라,
+// 불안전 구간을 담는 `data/unsafe_ranges.json`(`is_safe_chars_generated`의 입력)과는
+// 뜻이 반대다. 0x0000~0xFFFF
+// 전체를 한 세션에 다 훑기엔 DLL 호출이 느려서, 체크포인트 파일을 남겨 여러 세션에
+// 걸쳐 이어서 돌릴 수 있다.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::char_ranges::generate;
+use crate::EzTransEngine;
+
+/// 한 코드포인트의 실측 결과.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ProbeResult {
+    pub code: u32,
+    pub safe: bool,
+}
+
+/// 진행 중인 스윕의 체크포인트. `next_code`는 다음에 검사할 코드포인트.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Checkpoint {
+    next_code: u32,
+    results: Vec<ProbeResult>,
+}
+
+/// `start..=end` 범위를 실제 엔진으로 훑어 안전/불안전을 분류하는 스윕. 중간에 멈춰도
+/// `checkpoint_path`의 체크포인트 파일로 이어서 돌릴 수 있다.
+pub struct CharSafetyProbe<'a> {
+    engine: &'a EzTransEngine,
+    end: u32,
+    checkpoint_path: PathBuf,
+    checkpoint: Checkpoint,
+}
+
+impl<'a> CharSafetyProbe<'a> {
+    /// `checkpoint_path`에 기존 체크포인트가 있으면 이어서, 없으면 `start`부터 새로
+    /// 스윕을 시작한다.
+    pub fn new(
+        engine: &'a EzTransEngine,
+        start: u32,
+        end: u32,
+        checkpoint_path: impl Into<PathBuf>,
+    ) -> io::Result<Self> {
+        let checkpoint_path = checkpoint_path.into();
+        let checkpoint = match fs::read_to_string(&checkpoint_path) {
+            Ok(contents) => serde_json::from_str(&contents)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Checkpoint {
+                next_code: start,
+                results: Vec::new(),
+            },
+            Err(e) => return Err(e),
+        };
+
+        Ok(Self {
+            engine,
+            end,
+            checkpoint_path,
+            checkpoint,
+        })
+    }
+
+    /// 아직 검사하지 않은 남은 코드포인트 수.
+    pub fn remaining(&self) -> u32 {
+        if self.is_done() {
+            return 0;
+        }
+        self.end - self.checkpoint.next_code + 1
+    }
+
+    /// 스윕이 끝까지 진행됐는지(더 검사할 코드포인트가 없는지) 여부.
+    pub fn is_done(&self) -> bool {
+        self.checkpoint.next_code > self.end
+    }
+
+    /// 남은 코드포인트 중 최대 `budget`개를 검사하고, 체크포인트 파일에 진행 상황을
+    /// 저장한다. 전체 스윕이 끝나 있으면 아무 것도 하지 않는다.
+    pub fn run_batch(&mut self, budget: u32) -> Result<(), crate::EzTransError> {
+        let mut checked = 0;
+        while checked < budget && !self.is_done() {
+            let code = self.checkpoint.next_code;
+            if let Some(c) = char::from_u32(code) {
+                let safe = !self.needs_encoding(c);
+                self.checkpoint.results.push(ProbeResult { code, safe });
+            }
+            self.checkpoint.next_code = code + 1;
+            checked += 1;
+        }
+
+        self.save_checkpoint()?;
+        Ok(())
+    }
+
+    /// `tests/char_optimization.rs`의 `needs_encoding`과 같은 판정: 인코딩을 거치지
+    /// 않고 보낸 결과와 `hangul_encode`로 감싸 보낸 뒤 `hangul_decode`로 복원한 결과를
+    /// 비교해, 다르거나(또는 둘 중 하나라도 실패하면) 인코딩이 필요한 것으로 본다.
+    fn needs_encoding(&self, c: char) -> bool {
+        let test_str = format!("あ{c}い");
+
+        let plain = self.engine.translate_mmntw(&test_str);
+        let encoded = self.engine.hangul_encode(&test_str);
+        let round_tripped = self.engine.translate_mmntw(&encoded);
+
+        match (plain, round_tripped) {
+            (Ok(plain), Ok(round_tripped)) => plain != self.engine.hangul_decode(&round_tripped),
+            _ => true,
+        }
+    }
+
+    fn save_checkpoint(&self) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(&self.checkpoint)?;
+        fs::write(&self.checkpoint_path, json)
+    }
+
+    /// 지금까지 모인 결과 중 안전하다고 분류된 코드포인트를 (start, end) 구간 목록으로
+    /// 합친다.
+    pub fn safe_ranges(&self) -> Vec<(u32, u32)> {
+        generate::merge_ranges(
+            self.checkpoint
+                .results
+                .iter()
+                .filter(|r| r.safe)
+                .map(|r| r.code),
+        )
+    }
+
+    /// 안전 구간 목록을 `char_ranges::generate::read_table`이 읽을 수 있는 형식으로
+    /// `path`에 저장한다. `build.rs`는 `data/unsafe_ranges.json`을 불안전 구간으로
+    /// 읽으므로, 이 안전 구간 표를 그 파일로 쓰면 판정이 뒤집힌다.
+    pub fn save_safe_ranges(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        generate::write_table(&self.safe_ranges(), path)
+    }
+
+    /// 지금까지 모인 결과 중 불안전하다고 분류된(`needs_encoding`이 참이었던)
+    /// 코드포인트를 [`crate::problematic_char_set::ProblematicCharSet`]로 묶어
+    /// 돌려준다. `translate_mmntw`가 이 결과를 바로 `EzTransInner`에 실어 선택적
+    /// 인코딩에 쓸 수 있다.
+    pub fn problematic_chars(&self) -> crate::problematic_char_set::ProblematicCharSet {
+        crate::problematic_char_set::ProblematicCharSet::from_codes(
+            self.checkpoint
+                .results
+                .iter()
+                .filter(|r| !r.safe)
+                .map(|r| r.code),
+        )
+    }
+}
+
+/// 두 probe 실행 사이에서 안전/불안전 분류가 바뀐 코드포인트.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SafetyChange {
+    /// 이전 실행에서는 안전했지만 이번에는 불안전하다고 나왔다.
+    BecameUnsafe(u32),
+    /// 이전 실행에서는 불안전했지만 이번에는 안전하다고 나왔다.
+    BecameSafe(u32),
+}
+
+/// `old`/`new` 두 안전 구간 표(둘 다 `start..=end`를 다 다뤘다는 전제)를 비교해, 분류가
+/// 바뀐 코드포인트를 낮은 코드포인트부터 보고한다. DLL/Dat 버전이 바뀐 뒤 다시 돌린
+/// probe 결과를 이전 결과와 비교해 회귀를 잡아낼 때 쓴다.
+pub fn diff_safe_ranges(
+    old: &[(u32, u32)],
+    new: &[(u32, u32)],
+    start: u32,
+    end: u32,
+) -> Vec<SafetyChange> {
+    let mut changes = Vec::new();
+    for code in start..=end {
+        match (generate::lookup(code, old), generate::lookup(code, new)) {
+            (true, false) => changes.push(SafetyChange::BecameUnsafe(code)),
+            (false, true) => changes.push(SafetyChange::BecameSafe(code)),
+            _ => {}
+        }
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_safe_ranges_reports_both_directions() {
+        let old = vec![(0, 5), (10, 10)];
+        let new = vec![(0, 3), (10, 10), (20, 20)];
+        let changes = diff_safe_ranges(&old, &new, 0, 20);
+
+        assert_eq!(
+            changes,
+            vec![
+                SafetyChange::BecameUnsafe(4),
+                SafetyChange::BecameUnsafe(5),
+                SafetyChange::BecameSafe(20),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_safe_ranges_is_empty_when_unchanged() {
+        let ranges = vec![(0, 5), (10, 10)];
+        assert!(diff_safe_ranges(&ranges, &ranges, 0, 15).is_empty());
+    }
+}
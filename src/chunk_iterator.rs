@@ -0,0 +1,180 @@
+// ezTrans DLL의 호출당 입력 길이 제한을 넘는 긴 문서를, 문장/자소 클러스터를
+// 깨뜨리지 않고 나눠 보내기 위한 경계 안전 청크 분할기.
+//
+// `batch_translate::split_into_sentences`는 문장 단위로는 나누지만 세그먼트 크기에
+// 상한이 없어, 문장 하나가 그 자체로 DLL 제한을 넘으면 여전히 실패한다. 반대로
+// 바이트/문자 수로 기계적으로 자르면 멀티바이트 시퀀스가 끊기거나 문장 중간이
+// 잘려 `full_unicode_scan` 같은 진단에서 "문제 있는 문자"가 실제보다 부풀려 보고된다.
+// `ChunkIterator`는 `max_len` 안에서 가능한 한 뒤쪽 문장 종결 부호/개행을 찾아 그
+// 자리에서 끊고, window 안에 그런 경계가 없으면 자소 클러스터 경계에서만 끊는다.
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{EzTransError, EzTransInner};
+
+/// 문장 종결로 취급해 우선적으로 끊어 주는 구분자들.
+const BOUNDARY_MARKERS: [char; 5] = ['。', '．', '！', '？', '\n'];
+
+/// `input`을 `max_len` 바이트 이하의 조각으로 나눠 주는 반복자. 단, 자소 클러스터
+/// 하나가 그 자체로 `max_len`보다 길면 그 클러스터 하나만 담은 조각은 `max_len`을
+/// 넘는다(클러스터는 쪼개지 않는다). 원본을 그대로
+/// 슬라이스하므로 복사가 없고, 조각을 이어 붙이면(`concat`) 원문이 그대로
+/// 복원된다 — 경계에 있던 공백/개행도 어느 한쪽 조각에 그대로 남아 있기 때문이다.
+pub struct ChunkIterator<'a> {
+    remaining: &'a str,
+    max_len: usize,
+}
+
+impl<'a> ChunkIterator<'a> {
+    /// `max_len`은 0보다 커야 한다.
+    pub fn new(input: &'a str, max_len: usize) -> Self {
+        assert!(max_len > 0, "max_len은 0보다 커야 합니다");
+        Self {
+            remaining: input,
+            max_len,
+        }
+    }
+}
+
+impl<'a> Iterator for ChunkIterator<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        if self.remaining.len() <= self.max_len {
+            let chunk = self.remaining;
+            self.remaining = "";
+            return Some(chunk);
+        }
+
+        let split_at = find_split_point(self.remaining, self.max_len);
+        let (chunk, rest) = self.remaining.split_at(split_at);
+        self.remaining = rest;
+        Some(chunk)
+    }
+}
+
+/// `s`에서 `max_len` 바이트 이내로 끊을 지점을 찾는다. 뒤에서부터가 아니라 앞에서부터
+/// 훑으면서, `max_len`을 넘기 직전까지 본 마지막 경계 부호 뒤를 기억해 둔다 — 결과적으로
+/// window 안의 *가장 뒤쪽* 경계에서 끊는 효과를 낸다.
+fn find_split_point(s: &str, max_len: usize) -> usize {
+    let mut last_boundary_end = None;
+    for (idx, c) in s.char_indices() {
+        let end = idx + c.len_utf8();
+        if end > max_len {
+            break;
+        }
+        if BOUNDARY_MARKERS.contains(&c) {
+            last_boundary_end = Some(end);
+        }
+    }
+    if let Some(end) = last_boundary_end {
+        return end;
+    }
+
+    // 문장 경계가 window 안에 없으면, 자소 클러스터를 쪼개지 않는 선에서 최대한
+    // max_len에 가깝게 끊는다.
+    let mut last_grapheme_end = None;
+    for (idx, g) in s.grapheme_indices(true) {
+        let end = idx + g.len();
+        if end > max_len {
+            break;
+        }
+        last_grapheme_end = Some(end);
+    }
+    if let Some(end) = last_grapheme_end {
+        return end;
+    }
+
+    // 첫 자소 클러스터 하나만으로도 max_len을 넘는 극단적인 경우에도, 앞으로 나아갈 수
+    // 있도록 그 클러스터 전체는 포함시킨다.
+    s.graphemes(true).next().map(str::len).unwrap_or(s.len())
+}
+
+impl EzTransInner {
+    /// `input`을 [`ChunkIterator`]로 `max_len` 이하의 조각으로 나눠 차례로
+    /// `translate_fm`으로 번역한 뒤 이어 붙인다. 조각 경계의 공백/개행은 원본 그대로
+    /// 어느 한쪽 조각에 남아 있으므로 따로 손댈 필요가 없다.
+    pub fn translate_chunked(&self, input: &str, max_len: usize) -> Result<String, EzTransError> {
+        let mut translated = Vec::new();
+        for chunk in ChunkIterator::new(input, max_len) {
+            translated.push(self.translate_fm(chunk)?);
+        }
+        Ok(translated.concat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_splits_at_last_sentence_boundary_within_window() {
+        let input = "最初の文。次の文です。まだ続く";
+        let chunks: Vec<&str> = ChunkIterator::new(input, 33).collect();
+        assert_eq!(chunks.concat(), input);
+        assert!(chunks.iter().all(|c| c.len() <= 33));
+        assert_eq!(chunks[0], "最初の文。次の文です。");
+    }
+
+    #[test]
+    fn test_falls_back_to_grapheme_boundary_when_no_marker_in_window() {
+        let input = "ひらがなだけでくぎりふごうがないながいぶんしょう";
+        let chunks: Vec<&str> = ChunkIterator::new(input, 10).collect();
+        assert_eq!(chunks.concat(), input);
+        assert!(chunks.iter().all(|c| c.len() <= 10));
+        assert!(chunks.len() > 1);
+    }
+
+    #[test]
+    fn test_never_splits_inside_a_multi_codepoint_grapheme_cluster() {
+        let input = format!("abc{}def", "👨‍👩‍👧"); // ZWJ 시퀀스, 여러 코드포인트
+        let cluster_start = "abc".len();
+        let cluster_end = cluster_start + "👨‍👩‍👧".len();
+        let max_len = cluster_start + "👨‍👩‍👧".len() / 2; // 클러스터 한가운데를 가리키는 경계
+
+        let chunks: Vec<&str> = ChunkIterator::new(&input, max_len).collect();
+        assert_eq!(chunks.concat(), input);
+
+        let mut boundary = 0;
+        for chunk in &chunks {
+            boundary += chunk.len();
+            if boundary != input.len() {
+                assert!(
+                    boundary <= cluster_start || boundary >= cluster_end,
+                    "split at byte {} falls inside the grapheme cluster",
+                    boundary
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_whole_input_returned_as_one_chunk_when_under_max_len() {
+        let input = "短い文。";
+        let chunks: Vec<&str> = ChunkIterator::new(input, 100).collect();
+        assert_eq!(chunks, vec![input]);
+    }
+
+    #[test]
+    fn test_empty_input_yields_no_chunks() {
+        assert_eq!(ChunkIterator::new("", 10).count(), 0);
+    }
+
+    #[test]
+    fn test_single_grapheme_cluster_larger_than_max_len_still_makes_progress() {
+        let input = format!("{}x", "👨‍👩‍👧");
+        let chunks: Vec<&str> = ChunkIterator::new(&input, 1).collect();
+        assert_eq!(chunks.concat(), input);
+        assert_eq!(chunks[0], "👨‍👩‍👧");
+    }
+
+    #[test]
+    #[should_panic(expected = "max_len은 0보다 커야 합니다")]
+    fn test_zero_max_len_panics() {
+        ChunkIterator::new("x", 0);
+    }
+}
@@ -0,0 +1,394 @@
+// `TransProxyServer`와 통신하는 파이프 클라이언트
+//
+// 서버가 이해하는 명령 세트를 1:1로 감싸는 동기 클라이언트(`PipeClient`)와, 별도의 I/O
+// 스레드에 작업을 맡기고 `Future`로 결과를 돌려받는 비동기 클라이언트(`AsyncPipeClient`)를
+// 제공한다. `MultiplexedPipeClient`는 한 걸음 더 나아가 `MessageHeader::request_id`로
+// 요청/응답을 상관시켜, 같은 파이프 위에 여러 `TranslateMMNTW` 요청을 응답을 기다리지
+// 않고 파이프라이닝할 수 있게 한다.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::mem::size_of;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread::JoinHandle;
+
+use tokio::sync::oneshot;
+use windows::Win32::Foundation::{CloseHandle, HANDLE};
+use windows::Win32::Storage::FileSystem::{
+    CreateFileW, FILE_GENERIC_READ, FILE_GENERIC_WRITE, FILE_SHARE_MODE, OPEN_EXISTING, ReadFile,
+    WriteFile,
+};
+use windows::core::PCWSTR;
+
+use crate::EzTransError;
+use crate::ipc_protocol::*;
+
+/// `PIPE_NAME`에 이미 떠 있는 서버에 연결해 원시 핸들을 반환한다. `PipeClient`와
+/// `MultiplexedPipeClient`가 공유한다.
+fn connect_pipe() -> Result<HANDLE, EzTransError> {
+    let name: Vec<u16> = PIPE_NAME.encode_utf16().chain(std::iter::once(0)).collect();
+
+    unsafe {
+        CreateFileW(
+            PCWSTR(name.as_ptr()),
+            (FILE_GENERIC_READ | FILE_GENERIC_WRITE).0,
+            FILE_SHARE_MODE(0),
+            None,
+            OPEN_EXISTING,
+            Default::default(),
+            None,
+        )
+        .map_err(|e| EzTransError::PipeError(e.to_string()))
+    }
+}
+
+fn write_all_to(handle: HANDLE, bytes: &[u8]) -> Result<(), EzTransError> {
+    let mut written = 0u32;
+    unsafe {
+        WriteFile(handle, Some(bytes), Some(&mut written), None)?;
+    }
+    if written as usize != bytes.len() {
+        return Err(EzTransError::IncompleteWrite);
+    }
+    Ok(())
+}
+
+fn read_exact_from(handle: HANDLE, size: usize) -> Result<Vec<u8>, EzTransError> {
+    let mut buf = vec![0u8; size];
+    let mut filled = 0usize;
+    while filled < size {
+        let mut read = 0u32;
+        unsafe {
+            ReadFile(handle, Some(&mut buf[filled..]), Some(&mut read), None)?;
+        }
+        if read == 0 {
+            return Err(EzTransError::IncompleteRead);
+        }
+        filled += read as usize;
+    }
+    Ok(buf)
+}
+
+fn header_bytes(header: &MessageHeader) -> &[u8] {
+    unsafe {
+        std::slice::from_raw_parts(header as *const MessageHeader as *const u8, size_of::<MessageHeader>())
+    }
+}
+
+fn struct_bytes<T>(value: &T) -> &[u8] {
+    unsafe { std::slice::from_raw_parts(value as *const T as *const u8, size_of::<T>()) }
+}
+
+/// `TranslateMMNTW` 응답 바디(`TranslateResponseHeader` + UTF-16 텍스트)를 디코딩한다.
+fn decode_translate_response(response: &[u8]) -> Result<String, EzTransError> {
+    let result_code = i32::from_le_bytes(response[4..8].try_into().unwrap_or_default());
+    if result_code != 0 {
+        return Err(EzTransError::FunctionCallFailed(format!(
+            "translate 실패 (코드: {})",
+            result_code
+        )));
+    }
+
+    let text_bytes = &response[8..];
+    let utf16: Vec<u16> = text_bytes
+        .chunks_exact(2)
+        .map(|b| u16::from_le_bytes([b[0], b[1]]))
+        .collect();
+    Ok(String::from_utf16_lossy(&utf16))
+}
+
+/// 이름 있는 파이프를 통해 `TransProxyServer`와 동기적으로 통신하는 클라이언트.
+pub struct PipeClient {
+    handle: HANDLE,
+}
+
+unsafe impl Send for PipeClient {}
+
+impl PipeClient {
+    /// `PIPE_NAME`에 이미 떠 있는 서버에 연결한다.
+    pub fn connect() -> Result<Self, EzTransError> {
+        Ok(Self {
+            handle: connect_pipe()?,
+        })
+    }
+
+    fn write_all(&self, bytes: &[u8]) -> Result<(), EzTransError> {
+        write_all_to(self.handle, bytes)
+    }
+
+    fn read_exact(&self, size: usize) -> Result<Vec<u8>, EzTransError> {
+        read_exact_from(self.handle, size)
+    }
+
+    fn request(&self, command: Command, body: &[u8]) -> Result<(), EzTransError> {
+        let header = MessageHeader {
+            command: command as u32,
+            payload_size: body.len() as u32,
+            request_id: 0,
+        };
+        self.write_all(header_bytes(&header))?;
+        self.write_all(body)
+    }
+
+    fn read_header(&self) -> Result<MessageHeader, EzTransError> {
+        let bytes = self.read_exact(size_of::<MessageHeader>())?;
+        Ok(unsafe { std::ptr::read_unaligned(bytes.as_ptr() as *const MessageHeader) })
+    }
+
+    /// 텍스트를 기본(한글 인코딩 포함) 모드로 번역한다.
+    pub fn translate(&self, text: &str) -> Result<String, EzTransError> {
+        let mut body = Vec::with_capacity(size_of::<TranslateRequestHeader>() + text.len() * 2);
+        body.extend_from_slice(&0u32.to_le_bytes()); // data0
+        body.extend(text.encode_utf16().flat_map(|u| u.to_le_bytes()));
+
+        self.request(Command::TranslateMMNTW, &body)?;
+        let header = self.read_header()?;
+        let response = self.read_exact(header.payload_size as usize)?;
+        decode_translate_response(&response)
+    }
+
+    /// `source<TAB>replacement` 줄들로 이루어진 용어집을 서버에 올린다.
+    pub fn load_glossary(&self, body: &str) -> Result<(), EzTransError> {
+        let utf16: Vec<u16> = body.encode_utf16().collect();
+        let len = utf16.len().min(16384);
+
+        let mut request = LoadGlossaryRequest {
+            size: len as u32,
+            data: [0; 16384],
+        };
+        request.data[..len].copy_from_slice(&utf16[..len]);
+
+        self.request(Command::LoadGlossary, struct_bytes(&request))?;
+        let _ = self.read_exact(size_of::<GenericResponse>())?;
+        Ok(())
+    }
+}
+
+impl Drop for PipeClient {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = CloseHandle(self.handle);
+        }
+    }
+}
+
+/// `PipeClient` 호출 하나의 결과를 표현하는 `Future`. 전용 I/O 스레드가 작업을 끝내면
+/// 저장해 둔 `Waker`를 깨운다.
+struct JobState<T> {
+    result: Option<Result<T, EzTransError>>,
+    waker: Option<Waker>,
+}
+
+pub struct JobHandle<T> {
+    state: Arc<Mutex<JobState<T>>>,
+}
+
+impl<T> Future for JobHandle<T> {
+    type Output = Result<T, EzTransError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+        if let Some(result) = state.result.take() {
+            Poll::Ready(result)
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+type Job = Box<dyn FnOnce(&PipeClient) + Send>;
+
+/// 전용 I/O 스레드에 번역 작업을 맡기고 `Future`로 결과를 돌려주는 비동기 클라이언트.
+///
+/// 파이프 I/O 자체는 여전히 블로킹이지만, 그 블로킹이 이 스레드 안에서만 일어나므로
+/// 호출자(async 런타임의 작업)는 막히지 않는다.
+pub struct AsyncPipeClient {
+    sender: Sender<Job>,
+    _worker: JoinHandle<()>,
+}
+
+impl AsyncPipeClient {
+    pub fn connect() -> Result<Self, EzTransError> {
+        let client = PipeClient::connect()?;
+        let (sender, receiver): (Sender<Job>, Receiver<Job>) = mpsc::channel();
+
+        let worker = std::thread::spawn(move || {
+            while let Ok(job) = receiver.recv() {
+                job(&client);
+            }
+        });
+
+        Ok(Self {
+            sender,
+            _worker: worker,
+        })
+    }
+
+    /// 텍스트 번역을 I/O 스레드에 제출하고, 완료 시 값을 돌려줄 `JobHandle`을 반환한다.
+    pub fn translate(&self, text: String) -> JobHandle<String> {
+        let state = Arc::new(Mutex::new(JobState {
+            result: None,
+            waker: None,
+        }));
+        let state_for_job = Arc::clone(&state);
+
+        let _ = self.sender.send(Box::new(move |client| {
+            let result = client.translate(&text);
+            let mut state = state_for_job.lock().unwrap();
+            state.result = Some(result);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        }));
+
+        JobHandle { state }
+    }
+}
+
+type InFlightMap = Arc<Mutex<HashMap<u64, oneshot::Sender<Result<String, EzTransError>>>>>;
+
+/// 대기 중인 `TranslateFuture`가 드롭될 때 `in_flight` 표에서 자기 항목을 지우는
+/// `Future`. 호출자가 응답을 기다리다 취소(타임아웃, 드롭 등)하면, 나중에 서버가
+/// 실제로 응답을 보내와도 리더 스레드가 그냥 버리도록 한다.
+pub struct TranslateFuture {
+    request_id: u64,
+    in_flight: InFlightMap,
+    receiver: oneshot::Receiver<Result<String, EzTransError>>,
+    completed: bool,
+}
+
+impl Future for TranslateFuture {
+    type Output = Result<String, EzTransError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match Pin::new(&mut self.receiver).poll(cx) {
+            Poll::Ready(result) => {
+                self.completed = true;
+                Poll::Ready(result.unwrap_or_else(|_| {
+                    Err(EzTransError::FunctionCallFailed(
+                        "reader thread dropped the reply".into(),
+                    ))
+                }))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl Drop for TranslateFuture {
+    fn drop(&mut self) {
+        if !self.completed {
+            self.in_flight.lock().unwrap().remove(&self.request_id);
+        }
+    }
+}
+
+/// `MessageHeader::request_id`로 요청/응답을 상관시켜, 응답을 기다리지 않고 여러
+/// `TranslateMMNTW` 요청을 같은 파이프에 파이프라이닝하는 클라이언트.
+///
+/// 쓰기는 호출자 스레드에서 `write_lock`으로 직렬화하고, 읽기는 백그라운드 리더
+/// 스레드 하나가 전담한다. 리더는 16바이트 `MessageHeader`를 읽고 `payload_size`
+/// 만큼의 바디를 읽어 `request_id`로 `in_flight`에서 대기 중인 송신자를 찾아
+/// 채워준다 — 모르는 `request_id`가 와도(이미 취소되어 지워졌거나 손상된 경우) 바이트
+/// 정렬이 깨지지 않도록 바디는 항상 끝까지 읽어 버린다.
+pub struct MultiplexedPipeClient {
+    handle: HANDLE,
+    write_lock: Mutex<()>,
+    next_request_id: AtomicU64,
+    in_flight: InFlightMap,
+    _reader: JoinHandle<()>,
+}
+
+unsafe impl Send for MultiplexedPipeClient {}
+unsafe impl Sync for MultiplexedPipeClient {}
+
+impl MultiplexedPipeClient {
+    pub fn connect() -> Result<Self, EzTransError> {
+        let handle = connect_pipe()?;
+        let in_flight: InFlightMap = Arc::new(Mutex::new(HashMap::new()));
+
+        let reader_handle = handle;
+        let in_flight_for_reader = Arc::clone(&in_flight);
+        let reader = std::thread::spawn(move || loop {
+            let Ok(header_bytes) = read_exact_from(reader_handle, size_of::<MessageHeader>())
+            else {
+                break;
+            };
+            let header = unsafe {
+                std::ptr::read_unaligned(header_bytes.as_ptr() as *const MessageHeader)
+            };
+
+            // 모르는 request_id라도 페이로드는 끝까지 읽어 다음 메시지와 바이트가
+            // 어긋나지 않게 한다.
+            let Ok(payload) = read_exact_from(reader_handle, header.payload_size as usize) else {
+                break;
+            };
+
+            let sender = in_flight_for_reader.lock().unwrap().remove(&header.request_id);
+            if let Some(sender) = sender {
+                let _ = sender.send(decode_translate_response(&payload));
+            }
+        });
+
+        Ok(Self {
+            handle,
+            write_lock: Mutex::new(()),
+            next_request_id: AtomicU64::new(1),
+            in_flight,
+            _reader: reader,
+        })
+    }
+
+    /// `text`를 번역 요청으로 제출하고, 서버 응답이 도착하면 완료되는 `TranslateFuture`를
+    /// 돌려준다. 응답을 기다리는 동안 다른 `send_translate` 호출을 계속 제출해, 같은
+    /// 파이프 위에 여러 요청을 파이프라이닝할 수 있다.
+    pub fn send_translate(&self, text: &str) -> TranslateFuture {
+        let request_id = self.next_request_id.fetch_add(1, Ordering::Relaxed);
+        let (sender, receiver) = oneshot::channel();
+
+        let mut body = Vec::with_capacity(size_of::<TranslateRequestHeader>() + text.len() * 2);
+        body.extend_from_slice(&0u32.to_le_bytes()); // data0
+        body.extend(text.encode_utf16().flat_map(|u| u.to_le_bytes()));
+
+        self.in_flight.lock().unwrap().insert(request_id, sender);
+
+        let write_result = {
+            let _guard = self.write_lock.lock().unwrap();
+            let header = MessageHeader {
+                command: Command::TranslateMMNTW as u32,
+                payload_size: body.len() as u32,
+                request_id,
+            };
+            write_all_to(self.handle, header_bytes(&header)).and_then(|_| {
+                write_all_to(self.handle, &body)
+            })
+        };
+
+        if let Err(e) = write_result {
+            // 요청 자체가 나가지 못했으니 리더가 응답할 일도 없다 — 직접 에러로 채운다.
+            if let Some(sender) = self.in_flight.lock().unwrap().remove(&request_id) {
+                let _ = sender.send(Err(e));
+            }
+        }
+
+        TranslateFuture {
+            request_id,
+            in_flight: Arc::clone(&self.in_flight),
+            receiver,
+            completed: false,
+        }
+    }
+}
+
+impl Drop for MultiplexedPipeClient {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = CloseHandle(self.handle);
+        }
+    }
+}
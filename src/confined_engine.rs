@@ -0,0 +1,125 @@
+// `tests/thread_safety_test.rs`/`tests/thread_local_test.rs`의 `UnsafeEngineWrapper`를
+// 대체하는, 오용 불가능한 단일 엔진 래퍼.
+//
+// 그 테스트들은 `unsafe impl Send/Sync`로 `EzTransEngine`을 강제로 스레드 경계 너머로
+// 밀어 넣고, DLL이 실제로 깨지는지(Test 2) 혹은 `Mutex`로 직렬화하면 안전한지(Test 3)
+// 확인했다. `ConfinedEngine`은 그 결론 — "엔진을 한 스레드에 묶고 호출을 직렬화하면
+// 안전하다" — 을 안전한 공개 API로 승격한다. 엔진은 `ConfinedEngine::new`가 띄운
+// 전용 스레드 안에서 만들어지고 `initialize_ex`까지 그 스레드에서 끝마치므로, DLL의
+// 스레드 로컬/FFI 상태가 다른 스레드로 새어나갈 일이 없다. `translate_mmntw` 호출은
+// 채널로 그 스레드에 전달되어 순서대로 처리되고, 호출자는 결과가 돌아올 때까지
+// 블록한다.
+//
+// `engine_pool::EzTransPool`과 달리 워커는 항상 하나뿐이고 핸들 자체가 `Clone`이라,
+// 여러 스레드가 `Arc`로 감싸지 않고도 `ConfinedEngine`을 그대로 나눠 가질 수 있다.
+
+use std::path::Path;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread::JoinHandle;
+
+use crate::{EzTransEngine, EzTransError};
+
+struct TranslateJob {
+    text: String,
+    reply: mpsc::Sender<Result<String, EzTransError>>,
+}
+
+struct Inner {
+    jobs: mpsc::Sender<TranslateJob>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl Drop for Inner {
+    /// `jobs` 송신 쪽을 먼저 닫아 워커의 `for job in rx` 루프가 끝나게 한 뒤, 워커가
+    /// 자신의 엔진을 스스로 드롭할 때까지 기다린다 — 엔진은 생성한 스레드에서만
+    /// 버려지므로, DLL의 스레드 로컬 상태도 거기서 그대로 해제된다.
+    fn drop(&mut self) {
+        let Inner { jobs, worker } = self;
+        drop(std::mem::replace(jobs, mpsc::channel().0));
+        if let Some(worker) = worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+/// 전용 스레드 하나에 고정된 `EzTransEngine`을 감싼, 오용 불가능한 핸들.
+///
+/// `Clone + Send + Sync`라 여러 스레드가 같은 엔진을 안전하게 나눠 쓸 수 있고,
+/// `translate_mmntw`는 그 스레드로 요청을 넘긴 뒤 결과가 돌아올 때까지 블록한다.
+#[derive(Clone)]
+pub struct ConfinedEngine {
+    inner: Arc<Inner>,
+}
+
+impl ConfinedEngine {
+    /// `dll_path`/`dat_path`로 엔진을 로드·초기화할 전용 스레드를 띄운다. 엔진은 이
+    /// 스레드 안에서 만들어지고 초기화되므로, 생성이 실패하면 스레드도 함께
+    /// 종료된다.
+    pub fn new(dll_path: impl AsRef<Path>, dat_path: impl AsRef<Path>) -> Result<Self, EzTransError> {
+        let dll_path = dll_path.as_ref().to_path_buf();
+        let dat_path = dat_path
+            .as_ref()
+            .to_str()
+            .ok_or(EzTransError::InvalidPath)?
+            .to_string();
+
+        let (jobs, rx) = mpsc::channel::<TranslateJob>();
+        let (init_tx, init_rx) = mpsc::channel();
+
+        let worker = std::thread::spawn(move || {
+            let engine = match EzTransEngine::new(&dll_path)
+                .and_then(|engine| engine.initialize_ex("CSUSER123455", &dat_path).map(|_| engine))
+            {
+                Ok(engine) => {
+                    let _ = init_tx.send(Ok(()));
+                    engine
+                }
+                Err(e) => {
+                    let _ = init_tx.send(Err(e));
+                    return;
+                }
+            };
+
+            for job in rx {
+                let _ = job.reply.send(engine.translate_mmntw(&job.text));
+            }
+            // `engine`은 여기서 드롭된다 — 이 스레드에서 DLL의 상태가 해제된다.
+        });
+
+        match init_rx.recv() {
+            Ok(Ok(())) => Ok(Self {
+                inner: Arc::new(Inner {
+                    jobs,
+                    worker: Some(worker),
+                }),
+            }),
+            Ok(Err(e)) => {
+                let _ = worker.join();
+                Err(e)
+            }
+            Err(_) => {
+                let _ = worker.join();
+                Err(EzTransError::FunctionCallFailed(
+                    "워커 스레드가 초기화 중 panic했습니다".to_string(),
+                ))
+            }
+        }
+    }
+
+    /// 요청을 전용 스레드로 넘기고, 그 스레드에서 `translate_mmntw`를 실행한 결과가
+    /// 돌아올 때까지 블록한다.
+    pub fn translate_mmntw(&self, text: impl Into<String>) -> Result<String, EzTransError> {
+        let (reply, reply_rx) = mpsc::channel();
+        self.inner
+            .jobs
+            .send(TranslateJob {
+                text: text.into(),
+                reply,
+            })
+            .map_err(|_| EzTransError::FunctionCallFailed("워커 스레드가 종료되었습니다".to_string()))?;
+        reply_rx
+            .recv()
+            .map_err(|_| EzTransError::FunctionCallFailed("워커 스레드가 응답 없이 종료되었습니다".to_string()))?
+    }
+}
@@ -0,0 +1,159 @@
+// 엔진의 글리프 커버리지를 한 번 스캔해 집계하는 라이브러리 함수.
+//
+// `tests/default_translate_detection_test.rs`의 분류 로직(□ / ? / 원문과 다름 /
+// 에러)은 지금까지 탐색용 테스트 안에 갇혀 콘솔과 CSV로만 나갔다. `scan_charset_coverage`는
+// 같은 분류를 라이브러리 함수로 꺼내 `CoverageReport`로 돌려주므로, 코디네이터가 그대로
+// 써서 대시보드/CSV를 채울 수도 있고, CI가 그 리포트를 직렬화해 저장된 베이스라인과
+// 비교해 "square/question_mark 회귀 없음"을 검증하는 품질 게이트로도 쓸 수 있다.
+
+use serde::{Deserialize, Serialize};
+
+use crate::EzTransEngine;
+
+/// 코드포인트 하나를 번역해 본 뒤 분류한 결과.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IssueType {
+    /// "□"가 섞여 나왔다.
+    Square,
+    /// 원문이 "?"가 아닌데 번역문에 "?"가 섞여 나왔다.
+    QuestionMark,
+    /// 번역문이 원문과 다르고, 한국어로 번역된 것도 아니다.
+    Different,
+    /// 번역 호출 자체가 실패했다.
+    Error,
+}
+
+/// 탐지된 문제 하나를 코드포인트와 함께 기록한다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DetectionResult {
+    pub code: u32,
+    pub character: String,
+    pub translation: String,
+    pub issue_type: IssueType,
+}
+
+/// [`CoverageReport::status`]로 요약되는 전체 판정.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CoverageStatus {
+    /// `Square`/`QuestionMark`/`Error` 종류의 문제가 하나도 없었다.
+    Ok,
+    /// `Square`/`QuestionMark`/`Error` 종류의 문제가 하나 이상 발견되었다. `Different`만
+    /// 있는 경우는 `Ok`로 본다.
+    Degraded,
+}
+
+/// [`scan_charset_coverage`] 한 번 호출의 결과.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoverageReport {
+    pub total_tested: usize,
+    pub square_count: usize,
+    pub question_mark_count: usize,
+    pub different_count: usize,
+    pub error_count: usize,
+    pub results: Vec<DetectionResult>,
+    pub status: CoverageStatus,
+}
+
+/// 한글 범위에 속하는 문자가 하나라도 있으면 참.
+fn is_korean(s: &str) -> bool {
+    s.chars().any(|c| {
+        let code = c as u32;
+        (0xAC00..=0xD7A3).contains(&code)
+            || (0x1100..=0x11FF).contains(&code)
+            || (0x3130..=0x318F).contains(&code)
+            || (0xA960..=0xA97F).contains(&code)
+            || (0xD7B0..=0xD7FF).contains(&code)
+    })
+}
+
+/// `ranges`에 속한 모든 코드포인트를 `engine.default_translate`로 번역해 보고,
+/// □/물음표/원문과 다름/에러 여부를 분류해 [`CoverageReport`]로 집계한다.
+pub fn scan_charset_coverage(engine: &EzTransEngine, ranges: &[(u32, u32)]) -> CoverageReport {
+    let mut results = Vec::new();
+    let mut square_count = 0;
+    let mut question_mark_count = 0;
+    let mut different_count = 0;
+    let mut error_count = 0;
+    let mut total_tested = 0;
+
+    for &(start, end) in ranges {
+        for code in start..=end {
+            let Some(c) = char::from_u32(code) else {
+                continue;
+            };
+            total_tested += 1;
+            let test_str = c.to_string();
+
+            match engine.default_translate(&test_str) {
+                Ok(translated) => {
+                    let issue_type = if translated.contains('□') {
+                        Some(IssueType::Square)
+                    } else if c != '?' && translated.contains('?') {
+                        Some(IssueType::QuestionMark)
+                    } else if translated != test_str && !is_korean(&translated) {
+                        Some(IssueType::Different)
+                    } else {
+                        None
+                    };
+
+                    if let Some(issue_type) = issue_type {
+                        match issue_type {
+                            IssueType::Square => square_count += 1,
+                            IssueType::QuestionMark => question_mark_count += 1,
+                            IssueType::Different => different_count += 1,
+                            IssueType::Error => unreachable!("Ok branch never produces Error"),
+                        }
+                        results.push(DetectionResult {
+                            code,
+                            character: test_str,
+                            translation: translated,
+                            issue_type,
+                        });
+                    }
+                }
+                Err(e) => {
+                    error_count += 1;
+                    results.push(DetectionResult {
+                        code,
+                        character: test_str,
+                        translation: format!("ERROR: {:?}", e),
+                        issue_type: IssueType::Error,
+                    });
+                }
+            }
+        }
+    }
+
+    let status = if square_count == 0 && question_mark_count == 0 && error_count == 0 {
+        CoverageStatus::Ok
+    } else {
+        CoverageStatus::Degraded
+    };
+
+    CoverageReport {
+        total_tested,
+        square_count,
+        question_mark_count,
+        different_count,
+        error_count,
+        results,
+        status,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_korean_detects_hangul_syllables_and_jamo() {
+        assert!(is_korean("안녕"));
+        assert!(is_korean("ㄱㄴㄷ"));
+        assert!(!is_korean("hello"));
+    }
+
+    #[test]
+    fn test_is_korean_ignores_empty_string() {
+        assert!(!is_korean(""));
+    }
+}
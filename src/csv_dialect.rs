@@ -0,0 +1,249 @@
+// CSV를 실제로 읽기 전에 구분자/인용 문자/헤더 유무와 인코딩을 추정한다.
+//
+// `examples/translate_csv.rs`는 구분자를 콤마로, 인용 문자를 큰따옴표로, 헤더가 항상
+// 있다고, 그리고 내용이 UTF-8이라고 가정해 왔다. EzTrans 생태계에서 나도는 원본 CSV
+// 덤프는 일본어 원문 열이 Shift-JIS로, 한국어 기대 번역 열이 EUC-KR로 저장된 경우가
+// 흔해서, 그 가정이 틀리면 `csv::Reader`가 깨진 바이트를 그대로 들고 가다가 한참
+// 지나서야 `deserialize` 중간에 패닉/에러로 터진다. `detect_dialect`는 첫
+// `SAMPLE_ROWS`행을 표본 삼아 구분자/인용 문자/헤더 여부를 추정하고,
+// `encoding_detect::detect_encoding`으로 고른 인코딩으로 파일 전체를 먼저 UTF-8로
+// 트랜스코딩해, 디코딩 실패를 파일을 읽는 시점에 한 번에 드러낸다.
+
+use std::fs;
+use std::path::Path;
+
+use encoding_rs::Encoding;
+
+use crate::EzTransError;
+
+/// 구분자/인용 문자/헤더 여부를 추정할 때 들여다볼 표본 행 수.
+const SAMPLE_ROWS: usize = 100;
+const DELIMITER_CANDIDATES: &[u8] = b",\t;|";
+const QUOTE_CANDIDATES: &[u8] = b"\"'";
+
+/// `detect_dialect`가 고른 값들. 호출자가 로그를 남기거나 사용자에게 "이렇게
+/// 가정했습니다"를 보여줄 수 있도록 그대로 돌려준다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DialectReport {
+    pub delimiter: u8,
+    pub quote: u8,
+    pub has_headers: bool,
+    pub encoding: &'static Encoding,
+}
+
+/// `detect_dialect`의 결과: 미리 구성된 리더, 추정 내용 보고서, 그리고 이미 감지된
+/// 인코딩으로 UTF-8로 트랜스코딩된 파일 전체 내용.
+pub struct DetectedCsv {
+    pub reader_builder: csv::ReaderBuilder,
+    pub report: DialectReport,
+    pub contents: String,
+}
+
+/// `path`의 앞부분을 표본으로 CSV 방언과 인코딩을 추정하고, 이미 트랜스코딩된 내용과
+/// 그 내용을 읽을 `csv::ReaderBuilder`를 함께 돌려준다.
+///
+/// 감지된 인코딩으로도 디코딩에 실패하면(치환 문자가 나오면) 호출자가 나중에
+/// 레코드 단위로 알아채는 대신 여기서 바로 에러로 돌려준다.
+pub fn detect_dialect(path: impl AsRef<Path>) -> Result<DetectedCsv, EzTransError> {
+    let bytes = fs::read(path.as_ref())?;
+
+    let encoding = crate::encoding_detect::detect_encoding(&bytes);
+    let (decoded, _, had_errors) = encoding.decode(&bytes);
+    if had_errors {
+        return Err(EzTransError::FunctionCallFailed(format!(
+            "{}(으)로 감지되었으나 {}의 내용을 디코딩하는 데 실패했습니다",
+            encoding.name(),
+            path.as_ref().display()
+        )));
+    }
+    let contents = decoded.into_owned();
+
+    let sample: Vec<&str> = contents.lines().take(SAMPLE_ROWS).collect();
+    if sample.is_empty() {
+        return Err(EzTransError::FunctionCallFailed(format!(
+            "{}에 읽을 행이 없습니다",
+            path.as_ref().display()
+        )));
+    }
+
+    let delimiter = detect_delimiter(&sample);
+    let quote = detect_quote(&sample);
+    let has_headers = detect_has_headers(&sample, delimiter);
+
+    let mut reader_builder = csv::ReaderBuilder::new();
+    reader_builder
+        .delimiter(delimiter)
+        .quote(quote)
+        .has_headers(has_headers);
+
+    Ok(DetectedCsv {
+        reader_builder,
+        report: DialectReport {
+            delimiter,
+            quote,
+            has_headers,
+            encoding,
+        },
+        contents,
+    })
+}
+
+/// 후보 구분자 중, 표본 행들을 가장 일관된 열 개수로 나누는 것을 고른다.
+fn detect_delimiter(sample: &[&str]) -> u8 {
+    DELIMITER_CANDIDATES
+        .iter()
+        .copied()
+        .max_by_key(|&candidate| delimiter_consistency(sample, candidate))
+        .unwrap_or(b',')
+}
+
+/// 이 구분자로 각 행을 나눴을 때, 가장 흔한 열 개수로 나뉘는 행이 몇 개나 되는지(그
+/// 열 개수가 1보다 클 때만) 점수로 매긴다. 열이 하나뿐이면(=이 구분자가 전혀 등장하지
+/// 않으면) 0점으로, 애초에 틀린 구분자를 고르지 않게 한다.
+fn delimiter_consistency(sample: &[&str], delimiter: u8) -> i64 {
+    let delimiter = delimiter as char;
+    let counts: Vec<usize> = sample
+        .iter()
+        .map(|line| line.matches(delimiter).count() + 1)
+        .collect();
+
+    let Some(&mode) = counts
+        .iter()
+        .max_by_key(|&&count| counts.iter().filter(|&&c| c == count).count())
+    else {
+        return i64::MIN;
+    };
+    if mode <= 1 {
+        return 0;
+    }
+
+    let matching = counts.iter().filter(|&&c| c == mode).count() as i64;
+    matching * mode as i64
+}
+
+/// 표본에 더 자주 등장하는 인용 문자를 고른다.
+fn detect_quote(sample: &[&str]) -> u8 {
+    QUOTE_CANDIDATES
+        .iter()
+        .copied()
+        .max_by_key(|&candidate| {
+            sample
+                .iter()
+                .filter(|line| line.as_bytes().contains(&candidate))
+                .count()
+        })
+        .unwrap_or(b'"')
+}
+
+/// 첫 행이 나머지 행들과 "모양"이 다르면(숫자로 파싱되는 열 비율이 눈에 띄게 낮으면)
+/// 헤더로 본다. 표본이 한 줄뿐이면 판단할 근거가 없으니 헤더가 있다고 가정한다.
+fn detect_has_headers(sample: &[&str], delimiter: u8) -> bool {
+    if sample.len() < 2 {
+        return true;
+    }
+
+    let delimiter = delimiter as char;
+    let numeric_ratio = |line: &str| -> f64 {
+        let fields: Vec<&str> = line.split(delimiter).collect();
+        if fields.is_empty() {
+            return 0.0;
+        }
+        let numeric = fields
+            .iter()
+            .filter(|field| field.trim().parse::<f64>().is_ok())
+            .count();
+        numeric as f64 / fields.len() as f64
+    };
+
+    let first_ratio = numeric_ratio(sample[0]);
+    let rest_ratio = sample[1..].iter().map(|line| numeric_ratio(line)).sum::<f64>()
+        / (sample.len() - 1) as f64;
+
+    first_ratio < rest_ratio
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_csv_path(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "eztrans_csv_dialect_test_{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.join(name)
+    }
+
+    #[test]
+    fn detect_delimiter_prefers_comma() {
+        let sample = ["a,b,c", "1,2,3", "4,5,6"];
+        assert_eq!(detect_delimiter(&sample), b',');
+    }
+
+    #[test]
+    fn detect_delimiter_picks_semicolon_when_consistent() {
+        let sample = ["a;b;c", "1;2;3", "4;5;6"];
+        assert_eq!(detect_delimiter(&sample), b';');
+    }
+
+    #[test]
+    fn detect_delimiter_picks_tab_over_comma_when_tab_is_consistent() {
+        let sample = ["a\tb,x\tc", "1\t2,y\t3", "4\t5,z\t6"];
+        assert_eq!(detect_delimiter(&sample), b'\t');
+    }
+
+    #[test]
+    fn detect_quote_prefers_double_quote() {
+        let sample = [r#""a","b""#, r#""c","d""#];
+        assert_eq!(detect_quote(&sample), b'"');
+    }
+
+    #[test]
+    fn detect_has_headers_true_when_first_row_is_not_numeric() {
+        let sample = ["name,age", "alice,30", "bob,40"];
+        assert!(detect_has_headers(&sample, b','));
+    }
+
+    #[test]
+    fn detect_has_headers_false_when_all_rows_look_alike() {
+        let sample = ["alice,30", "bob,40", "carol,50"];
+        assert!(!detect_has_headers(&sample, b','));
+    }
+
+    #[test]
+    fn detect_has_headers_defaults_true_for_single_row() {
+        let sample = ["alice,30"];
+        assert!(detect_has_headers(&sample, b','));
+    }
+
+    #[test]
+    fn detect_dialect_reads_utf8_csv_end_to_end() {
+        let path = temp_csv_path("utf8.csv");
+        std::fs::write(&path, "name,age\nfoo,30\nbar,40\n").unwrap();
+
+        let detected = detect_dialect(&path).unwrap();
+        assert_eq!(detected.report.delimiter, b',');
+        assert!(detected.report.has_headers);
+        assert_eq!(detected.report.encoding, encoding_rs::UTF_8);
+        assert!(detected.contents.contains("foo,30"));
+    }
+
+    #[test]
+    fn detect_dialect_transcodes_legacy_encoding() {
+        let path = temp_csv_path("sjis.csv");
+        let (bytes, _, _) = encoding_rs::SHIFT_JIS.encode("name,value\nこんにちは,世界\n");
+        std::fs::write(&path, bytes.as_ref()).unwrap();
+
+        let detected = detect_dialect(&path).unwrap();
+        assert_eq!(detected.report.encoding, encoding_rs::SHIFT_JIS);
+        assert!(detected.contents.contains("こんにちは"));
+    }
+
+    #[test]
+    fn detect_dialect_errors_on_empty_file() {
+        let path = temp_csv_path("empty.csv");
+        std::fs::write(&path, "").unwrap();
+        assert!(detect_dialect(&path).is_err());
+    }
+}
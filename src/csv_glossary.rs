@@ -0,0 +1,253 @@
+// CSV로 불러오는 용어집 — `examples/translate_csv.rs`의 `char_name`/`trans_name` 같은
+// 열처럼, 이미 "원문 -> 정답" 쌍으로 정리된 표 데이터를 그대로 용어집으로 쓰고 싶을 때를
+// 위한 것이다.
+//
+// `glossary::Glossary`는 긴 본문 안에서 탭 구분 용어 여러 개를 한 번에 찾아내는 데
+// 최적화된 Aho-Corasick 트라이를 쓴다. 이 타입이 다루는 입력은 그와 달리 이미 행 단위로
+// 쪼개져 있어 트라이가 필요 없는 대신, source/target이 서로 1:1로 맞는지를 로드 시점에
+// 검증하는 쪽이 더 중요하다. `BiMap`은 그 1:1 제약을 `insert_no_overwrite`로 자연스럽게
+// 강제해 주고, 보호 단계(source -> sentinel)와 복원 단계(sentinel -> target) 양쪽 조회를
+// 같은 구조 하나로 해결해 준다.
+
+use std::path::Path;
+
+use bimap::BiMap;
+
+use crate::{EzTransError, EzTransInner};
+
+/// 번역 엔진이 절대 생성하지 않을 전용 구역(Private Use Area) 문자로 센티넬을 감싼다.
+const SENTINEL_OPEN: char = '\u{E000}';
+const SENTINEL_CLOSE: char = '\u{E001}';
+
+/// `source,target` 두 열짜리 CSV로 불러온, 1:1로 맞는 용어집.
+#[derive(Debug, Clone, Default)]
+pub struct Glossary {
+    terms: BiMap<String, String>,
+    /// `terms`에 등록된 source를 로드 순서대로 담는다. 센티넬 토큰은 이 벡터의 인덱스를
+    /// 가리키므로(`glossary::Glossary`와 동일한 방식), 번역 중간 단계에서 살아남아야 할
+    /// 내용이 실제 source/target 텍스트가 아니라 숫자뿐이게 된다.
+    order: Vec<String>,
+}
+
+impl Glossary {
+    /// 헤더 없는 `source,target` 두 열짜리 CSV를 읽어 빌드한다. 빈 열이 있는 행은
+    /// 건너뛴다. 같은 source가 서로 다른 target과, 또는 같은 target이 서로 다른
+    /// source와 엮이려 하면 충돌로 보고 로드를 거부한다.
+    pub fn from_csv(reader: impl std::io::Read) -> Result<Self, EzTransError> {
+        let mut terms = BiMap::new();
+        let mut order = Vec::new();
+        let mut csv_reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .from_reader(reader);
+
+        for result in csv_reader.records() {
+            let record = result
+                .map_err(|e| EzTransError::FunctionLoadError(format!("용어집 CSV 파싱 실패: {e}")))?;
+            let source = record.get(0).unwrap_or("").trim();
+            let target = record.get(1).unwrap_or("").trim();
+            if source.is_empty() || target.is_empty() {
+                continue;
+            }
+
+            terms
+                .insert_no_overwrite(source.to_string(), target.to_string())
+                .map_err(|_| {
+                    EzTransError::FunctionLoadError(format!(
+                        "용어집 충돌: {source:?} <-> {target:?}는 이미 다른 쌍에 쓰이고 있습니다"
+                    ))
+                })?;
+            order.push(source.to_string());
+        }
+
+        Ok(Self { terms, order })
+    }
+
+    /// 경로에서 CSV 파일을 읽어 빌드한다.
+    pub fn from_csv_path(path: impl AsRef<Path>) -> Result<Self, EzTransError> {
+        let file = std::fs::File::open(path)?;
+        Self::from_csv(file)
+    }
+
+    /// 등록된 source에 대응하는 target을 찾는다.
+    pub fn target_for(&self, source: &str) -> Option<&str> {
+        self.terms.get_by_left(source).map(String::as_str)
+    }
+
+    /// 등록된 target에 대응하는 source를 찾는다.
+    pub fn source_for(&self, target: &str) -> Option<&str> {
+        self.terms.get_by_right(target).map(String::as_str)
+    }
+
+    /// 등록된 용어 쌍의 개수.
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// 등록된 용어가 하나도 없는지.
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// 입력에서 등록된 source 용어를 찾아 센티넬 토큰(`order`상의 인덱스를 감싼 것)으로
+    /// 바꾼다. (번역 전 단계) 긴 source가 짧은 source에 가려지지 않도록 긴 것부터
+    /// 시도한다.
+    ///
+    /// source 텍스트 자체가 아니라 숫자 인덱스만 센티넬 안에 남기는 것이 중요하다 —
+    /// 엔진에 그대로 넘어가는 구간이므로, 실제 텍스트를 넣으면 번역 단계에서 그 텍스트
+    /// 자체가 번역돼 버려 `restore`가 원래 source를 되찾지 못한다.
+    fn protect(&self, input: &str) -> String {
+        if self.order.is_empty() {
+            return input.to_string();
+        }
+
+        let mut sources: Vec<(usize, &str)> = self
+            .order
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (i, s.as_str()))
+            .collect();
+        sources.sort_unstable_by_key(|(_, s)| std::cmp::Reverse(s.chars().count()));
+
+        let chars: Vec<char> = input.chars().collect();
+        let mut out = String::with_capacity(input.len());
+        let mut i = 0;
+        while i < chars.len() {
+            let matched = sources.iter().find_map(|&(idx, source)| {
+                let source_chars: Vec<char> = source.chars().collect();
+                let len = source_chars.len();
+                (i + len <= chars.len() && chars[i..i + len] == source_chars[..])
+                    .then_some((idx, len))
+            });
+
+            match matched {
+                Some((idx, len)) => {
+                    out.push(SENTINEL_OPEN);
+                    out.push_str(&idx.to_string());
+                    out.push(SENTINEL_CLOSE);
+                    i += len;
+                }
+                None => {
+                    out.push(chars[i]);
+                    i += 1;
+                }
+            }
+        }
+
+        out
+    }
+
+    /// 센티넬 토큰을 원래 등록된 target으로 되돌린다. (번역 후 단계) 파싱할 수 없거나
+    /// 알 수 없는 인덱스를 담은 토큰은 원본 그대로 보존한다.
+    fn restore(&self, input: &str) -> String {
+        if self.order.is_empty() {
+            return input.to_string();
+        }
+
+        let mut out = String::with_capacity(input.len());
+        let mut chars = input.chars().peekable();
+
+        while let Some(ch) = chars.next() {
+            if ch != SENTINEL_OPEN {
+                out.push(ch);
+                continue;
+            }
+
+            let digits: String = chars.by_ref().take_while(|&c| c != SENTINEL_CLOSE).collect();
+            let target = digits
+                .parse::<usize>()
+                .ok()
+                .and_then(|idx| self.order.get(idx))
+                .and_then(|source| self.target_for(source));
+
+            match target {
+                Some(target) => out.push_str(target),
+                None => {
+                    out.push(SENTINEL_OPEN);
+                    out.push_str(&digits);
+                    out.push(SENTINEL_CLOSE);
+                }
+            }
+        }
+
+        out
+    }
+}
+
+/// `glossary`에 등록된 source 용어를 보호한 채 `input`을 번역하고, 번역 후 각 용어를
+/// 대응하는 target으로 되돌린다.
+pub fn translate_with_glossary(
+    engine: &EzTransInner,
+    input: &str,
+    glossary: &Glossary,
+) -> Result<String, EzTransError> {
+    let protected = glossary.protect(input);
+    let translated = engine.default_translate(&protected)?;
+    Ok(glossary.restore(&translated))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_glossary() -> Glossary {
+        Glossary::from_csv("猫,고양이\n犬,개\n".as_bytes()).unwrap()
+    }
+
+    /// `engine.default_translate`를 흉내 낸 가짜 번역: 일본어 글자를 하드코딩된 한국어로
+    /// 바꾼다. 센티넬이 source 텍스트 자체를 담고 있다면 이 변환에 휘말려 엉뚱한 결과가
+    /// 되돌아오고, 숫자 인덱스만 담고 있다면 영향을 받지 않는다.
+    fn fake_translate(text: &str) -> String {
+        text.chars()
+            .map(|c| match c {
+                '猫' => '묘',
+                '犬' => '구',
+                other => other,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn protect_restore_round_trip_survives_translation() {
+        let glossary = build_glossary();
+        let protected = glossary.protect("猫가 좋다");
+        let translated = fake_translate(&protected);
+        let restored = glossary.restore(&translated);
+        assert_eq!(restored, "고양이가 좋다");
+    }
+
+    #[test]
+    fn protect_wraps_index_not_source_text() {
+        let glossary = build_glossary();
+        let protected = glossary.protect("猫");
+        assert_eq!(protected, format!("{SENTINEL_OPEN}0{SENTINEL_CLOSE}"));
+    }
+
+    #[test]
+    fn protect_prefers_longest_match() {
+        let glossary = Glossary::from_csv("猫,고양이\n猫又,네코마타\n".as_bytes()).unwrap();
+        let protected = glossary.protect("猫又");
+        assert_eq!(protected, format!("{SENTINEL_OPEN}1{SENTINEL_CLOSE}"));
+    }
+
+    #[test]
+    fn restore_leaves_unknown_index_untouched() {
+        let glossary = build_glossary();
+        let input = format!("{SENTINEL_OPEN}99{SENTINEL_CLOSE}");
+        assert_eq!(glossary.restore(&input), input);
+    }
+
+    #[test]
+    fn from_csv_rejects_conflicting_pair() {
+        let result = Glossary::from_csv("猫,고양이\n犬,고양이\n".as_bytes());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn target_and_source_lookup() {
+        let glossary = build_glossary();
+        assert_eq!(glossary.target_for("猫"), Some("고양이"));
+        assert_eq!(glossary.source_for("개"), Some("犬"));
+        assert_eq!(glossary.len(), 2);
+        assert!(!glossary.is_empty());
+    }
+}
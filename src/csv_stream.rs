@@ -0,0 +1,223 @@
+// `examples/translate_csv.rs`는 입력 전체를 `Vec<InputRecord>`로 읽어들인 뒤 출력도
+// `Vec<OutputRecord>`로 모아 두고서야 디스크에 쓴다. 수백만 행짜리 덤프에서는 이 방식
+// 자체가 메모리 상한이 되어버린다. `translate_csv_stream`은 `csv-async`로 행을 한 번에
+// 하나씩만 들고 번역해 곧바로 써내려가므로, 파일 크기와 무관하게 메모리 사용량이
+// 평평하게 유지되고 표준입출력으로도 파이프할 수 있다.
+//
+// DLL은 스레드 안전하지 않으므로 번역 호출은 직접 하지 않고,
+// [`crate::async_engine::AsyncEzTransEngine`]이 이미 쥐고 있는 전담 워커 스레드로
+// 넘긴다 — 그쪽이 모든 FFI 호출을 한 스레드에 묶어 두는 역할을 한다.
+
+use std::future::Future;
+
+use tokio::io::{AsyncRead, AsyncWrite};
+
+use crate::async_engine::AsyncEzTransEngine;
+use crate::EzTransError;
+
+/// `translate_csv_stream`이 입력을 어떻게 읽고 쓸지 결정한다.
+#[derive(Debug, Clone)]
+pub struct CsvStreamConfig {
+    /// 입력에 헤더 행이 있는지. 있으면 출력 헤더 끝에 번역 결과 열 이름이 덧붙는다.
+    pub has_headers: bool,
+    /// 번역할 원문이 들어 있는 열의 인덱스(0부터).
+    pub source_column: usize,
+    /// 출력 헤더에 덧붙일 번역 결과 열 이름. `has_headers`가 `false`면 쓰이지 않는다.
+    pub translated_column_name: String,
+}
+
+impl Default for CsvStreamConfig {
+    fn default() -> Self {
+        Self {
+            has_headers: true,
+            source_column: 0,
+            translated_column_name: "eztrans_translation".to_string(),
+        }
+    }
+}
+
+/// 스트리밍 처리가 끝난 뒤의 집계.
+#[derive(Debug, Clone, Default)]
+pub struct CsvStreamStats {
+    pub total: u64,
+    pub translated: u64,
+    pub failed: u64,
+}
+
+/// `reader`에서 CSV를 한 행씩 읽어 `config.source_column` 열을 번역하고, 번역 결과를
+/// 덧붙인 행을 곧바로 `writer`에 써낸다. 입력 전체를 메모리에 모으지 않으므로 파일
+/// 크기와 무관하게 메모리 사용량이 평평하게 유지되고, `reader`/`writer`로 표준입출력
+/// 파이프를 그대로 넘길 수 있다.
+///
+/// 빈 원문이나 번역 실패는 건너뛰지 않고 번역 결과 열을 빈 문자열로 채운 채 그대로
+/// 써낸다 — 스트림 중간에 한 행 때문에 전체를 멈추지 않기 위함이다.
+pub async fn translate_csv_stream<R, W>(
+    engine: &AsyncEzTransEngine,
+    reader: R,
+    writer: W,
+    config: CsvStreamConfig,
+) -> Result<CsvStreamStats, EzTransError>
+where
+    R: AsyncRead + Unpin + Send,
+    W: AsyncWrite + Unpin + Send,
+{
+    stream_rows(reader, writer, config, |text| engine.default_translate(text)).await
+}
+
+/// `translate_csv_stream`의 실제 CSV 스트리밍 로직. 번역 자체를 `translate` 콜백으로
+/// 받아서, DLL을 쥐고 있는 [`AsyncEzTransEngine`]과 분리해 둔다 — 이렇게 하면 이 함수의
+/// 스트리밍/통계 동작을 실제 DLL 없이도 가짜 번역 콜백으로 테스트할 수 있다.
+async fn stream_rows<R, W, F, Fut>(
+    reader: R,
+    writer: W,
+    config: CsvStreamConfig,
+    translate: F,
+) -> Result<CsvStreamStats, EzTransError>
+where
+    R: AsyncRead + Unpin + Send,
+    W: AsyncWrite + Unpin + Send,
+    F: Fn(&str) -> Fut,
+    Fut: Future<Output = Result<String, EzTransError>>,
+{
+    use csv_async::{AsyncReaderBuilder, AsyncWriterBuilder, StringRecord};
+    use futures::stream::StreamExt;
+
+    let mut csv_reader = AsyncReaderBuilder::new()
+        .has_headers(config.has_headers)
+        .create_reader(reader);
+    let mut csv_writer = AsyncWriterBuilder::new()
+        .has_headers(false)
+        .create_writer(writer);
+
+    let csv_err = |e: csv_async::Error| {
+        EzTransError::FunctionCallFailed(format!("CSV 스트리밍 실패: {e}"))
+    };
+
+    if config.has_headers {
+        if let Some(headers) = csv_reader.headers().await.map_err(csv_err)?.cloned() {
+            let mut out_headers: StringRecord = headers;
+            out_headers.push_field(&config.translated_column_name);
+            csv_writer.write_record(out_headers.iter()).await.map_err(csv_err)?;
+        }
+    }
+
+    let mut stats = CsvStreamStats::default();
+    let mut records = csv_reader.into_records();
+
+    while let Some(result) = records.next().await {
+        let record = result.map_err(csv_err)?;
+        stats.total += 1;
+
+        let source = record.get(config.source_column).unwrap_or("");
+        let mut out_record = record.clone();
+
+        if source.is_empty() {
+            out_record.push_field("");
+        } else {
+            match translate(source).await {
+                Ok(translated) => {
+                    stats.translated += 1;
+                    out_record.push_field(&translated);
+                }
+                Err(_) => {
+                    stats.failed += 1;
+                    out_record.push_field("");
+                }
+            }
+        }
+
+        csv_writer.write_record(out_record.iter()).await.map_err(csv_err)?;
+    }
+
+    csv_writer.flush().await.map_err(|e| {
+        EzTransError::FunctionCallFailed(format!("CSV 출력 flush 실패: {e}"))
+    })?;
+
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn fake_translate(text: &str) -> Result<String, EzTransError> {
+        Ok(text.chars().rev().collect())
+    }
+
+    #[tokio::test]
+    async fn stream_rows_translates_source_column_and_appends_header() {
+        let input = "name,greeting\nalice,hello\nbob,world\n";
+        let mut output = Vec::new();
+
+        let stats = stream_rows(
+            input.as_bytes(),
+            &mut output,
+            CsvStreamConfig {
+                has_headers: true,
+                source_column: 1,
+                translated_column_name: "translated".to_string(),
+            },
+            fake_translate,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(stats.total, 2);
+        assert_eq!(stats.translated, 2);
+        assert_eq!(stats.failed, 0);
+
+        let output = String::from_utf8(output).unwrap();
+        let mut lines = output.lines();
+        assert_eq!(lines.next().unwrap(), "name,greeting,translated");
+        assert_eq!(lines.next().unwrap(), "alice,hello,olleh");
+        assert_eq!(lines.next().unwrap(), "bob,world,dlrow");
+    }
+
+    #[tokio::test]
+    async fn stream_rows_leaves_empty_source_untranslated() {
+        let input = "name,greeting\nalice,\n";
+        let mut output = Vec::new();
+
+        let stats = stream_rows(
+            input.as_bytes(),
+            &mut output,
+            CsvStreamConfig {
+                source_column: 1,
+                ..CsvStreamConfig::default()
+            },
+            fake_translate,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(stats.total, 1);
+        assert_eq!(stats.translated, 0);
+        assert_eq!(stats.failed, 0);
+
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.lines().nth(1).unwrap().ends_with(","));
+    }
+
+    #[tokio::test]
+    async fn stream_rows_counts_translation_failures() {
+        async fn always_fails(_text: &str) -> Result<String, EzTransError> {
+            Err(EzTransError::FunctionCallFailed("boom".to_string()))
+        }
+
+        let input = "name,greeting\nalice,hello\n";
+        let mut output = Vec::new();
+
+        let stats = stream_rows(
+            input.as_bytes(),
+            &mut output,
+            CsvStreamConfig::default(),
+            always_fails,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(stats.total, 1);
+        assert_eq!(stats.translated, 0);
+        assert_eq!(stats.failed, 1);
+    }
+}
@@ -0,0 +1,63 @@
+// `build.rs`가 `data/emoji-test.txt`로부터 생성한 이모지 조회 테이블.
+//
+// `grapheme_encode`의 클러스터 보호 판정은 코드포인트 개수만으로 "보호가 필요한지"는
+// 정확히 판별하지만, 그 클러스터가 실제로 *알려진* 이모지 시퀀스인지(우연히 옆에 붙은
+// 무관한 문자들이 아니라)는 알지 못한다. 이 모듈은 `is_emoji`/`is_emoji_modifier_base`와
+// `classify_sequence`를 제공해, 호출자가 클러스터를 알려진 이모지 시퀀스로 검증하거나
+// 그룹/서브그룹 메타데이터로 필터링할 수 있게 한다.
+
+include!(concat!(env!("OUT_DIR"), "/emoji_table_generated.rs"));
+
+/// `c`가 단일 코드포인트 이모지로 등록되어 있는지 확인한다.
+pub fn is_emoji(c: char) -> bool {
+    EMOJI_CODEPOINTS.binary_search(&(c as u32)).is_ok()
+}
+
+/// `c`가 피부톤 변경자가 뒤따를 수 있는 "변경자 기반 문자(emoji modifier base)"인지
+/// 확인한다.
+pub fn is_emoji_modifier_base(c: char) -> bool {
+    EMOJI_MODIFIER_BASES.binary_search(&(c as u32)).is_ok()
+}
+
+/// `codepoints`가 등록된 멀티 코드포인트 이모지 시퀀스(ZWJ 가족, 국기, 키캡, 변경자)와
+/// 정확히 일치하면 그 `(group, subgroup)` 메타데이터를 돌려준다.
+pub fn classify_sequence(codepoints: &[u32]) -> Option<(&'static str, &'static str)> {
+    EMOJI_SEQUENCES
+        .iter()
+        .find(|(seq, _, _)| *seq == codepoints)
+        .map(|(_, group, subgroup)| (*group, *subgroup))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_single_codepoint_emoji_is_recognized() {
+        assert!(is_emoji('😀'));
+    }
+
+    #[test]
+    fn test_ascii_is_not_emoji() {
+        assert!(!is_emoji('A'));
+    }
+
+    #[test]
+    fn test_waving_hand_is_a_modifier_base() {
+        assert!(is_emoji_modifier_base('👋'));
+    }
+
+    #[test]
+    fn test_classify_known_zwj_family_sequence() {
+        let codepoints: Vec<u32> = "👨‍👩‍👧".chars().map(|c| c as u32).collect();
+        let (group, subgroup) = classify_sequence(&codepoints).expect("알려진 시퀀스여야 합니다");
+        assert_eq!(group, "People & Body");
+        assert_eq!(subgroup, "family");
+    }
+
+    #[test]
+    fn test_classify_unknown_sequence_returns_none() {
+        let codepoints: Vec<u32> = vec!['a' as u32, 'b' as u32];
+        assert_eq!(classify_sequence(&codepoints), None);
+    }
+}
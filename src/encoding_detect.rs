@@ -0,0 +1,206 @@
+// 원문 바이트의 인코딩을 자동 감지하고, CP932(Shift-JIS)로 표현할 수 없는 문자를
+// 미리 걸러내는 보조 모듈.
+//
+// `translate_mmnt`는 내부적으로 `encoding_rs::SHIFT_JIS.encode`를 호출해 EzTrans
+// DLL이 기대하는 CP932 바이트열을 만든다. 이 변환은 손실 변환(lossy)이라, CP932에
+// 없는 코드포인트는 조용히 "?"로 뭉개진다. `detect_encoding`은 원본 바이트가 어떤
+// 인코딩으로 저장되었는지 chardetng류의 점수 채점으로 추정하고,
+// `EzTransEngine::translate_bytes`는 그렇게 디코딩한 텍스트에서 CP932로 옮길 수 없는
+// 문자를 `hangul_encode`와 같은 `+X{:04X}` 자리표시자로 미리 escape해, 번역 파이프라인을
+// 통과한 뒤 `hangul_decode`가 원래 문자로 복원하게 한다.
+
+use encoding_rs::Encoding;
+
+use crate::{EzTransError, EzTransInner};
+
+/// 감지 후보 인코딩들. `detect_encoding`은 이 중 점수가 가장 높은 것을 고른다.
+const CANDIDATES: &[&Encoding] = &[
+    encoding_rs::SHIFT_JIS,
+    encoding_rs::EUC_JP,
+    encoding_rs::EUC_KR,
+    encoding_rs::GBK,
+    encoding_rs::BIG5,
+    encoding_rs::UTF_8,
+];
+
+/// 흔한 문장 종결 부호. 디코딩 결과가 이런 문자로 끝나면 실제 문장일 가능성이 조금 더
+/// 높다고 본다(깨진 디코딩은 대개 구두점이 아닌 문자에서 어중간하게 끝난다).
+const SENTENCE_ENDINGS: &[char] = &['.', '。', '!', '?', '！', '？', '…', '」', '』'];
+
+/// `bytes`를 `candidate`로 디코딩해 봤을 때의 그럴듯함 점수를 매긴다. 점수가 높을수록
+/// 그 인코딩일 가능성이 크다고 본다.
+///
+/// - 치환 문자(`U+FFFD`)가 하나라도 나오면 디코딩 자체가 실패했다는 뜻이므로 큰 폭으로
+///   감점한다.
+/// - 가나/한자/한글 범위에 속하는 문자가 연속으로 나올수록(같은 스크립트가 이어지는
+///   전형적인 모양) 가점한다.
+/// - 라틴 문자 바로 다음에 CJK/한글 문자가 오는 전환은 흔치 않은 모양이므로 약하게
+///   감점한다.
+/// - 문장이 흔한 종결 부호로 끝나면 약간 가점한다.
+fn score_candidate(bytes: &[u8], candidate: &'static Encoding) -> i64 {
+    let (decoded, had_errors) = {
+        let (cow, _, had_errors) = candidate.decode(bytes);
+        (cow.into_owned(), had_errors)
+    };
+
+    if had_errors {
+        return i64::MIN;
+    }
+
+    let replacement_count = decoded.chars().filter(|&c| c == '\u{FFFD}').count();
+    if replacement_count > 0 {
+        return i64::MIN / 2;
+    }
+
+    let mut score: i64 = 0;
+    let mut prev_is_latin = false;
+    let mut prev_is_script = false;
+
+    for c in decoded.chars() {
+        let is_kana = matches!(c as u32, 0x3040..=0x309F | 0x30A0..=0x30FF);
+        let is_kanji = matches!(c as u32, 0x4E00..=0x9FFF);
+        let is_hangul = matches!(c as u32, 0xAC00..=0xD7A3 | 0x1100..=0x11FF);
+        let is_script = is_kana || is_kanji || is_hangul;
+        let is_latin = c.is_ascii_alphabetic();
+
+        if is_script {
+            score += 2;
+            if prev_is_script {
+                score += 1; // 연속된 같은 스크립트 구간은 자연스러운 텍스트로 본다.
+            }
+            if prev_is_latin {
+                score -= 1; // 라틴 문자 바로 다음에 CJK/한글이 오는 전환은 드물다.
+            }
+        }
+
+        prev_is_latin = is_latin;
+        prev_is_script = is_script;
+    }
+
+    if decoded.trim_end().ends_with(SENTENCE_ENDINGS) {
+        score += 1;
+    }
+
+    score
+}
+
+/// 원본 바이트의 인코딩을 추정한다.
+///
+/// 먼저 UTF-16 LE/BE BOM을 확인하고, 없으면 `CANDIDATES`(Shift-JIS, EUC-JP, EUC-KR, GBK,
+/// Big5, UTF-8)를 각각 디코딩해 [`score_candidate`]로 채점한 뒤 가장 높은 점수의 인코딩을
+/// 고른다. 점수가 같으면 `CANDIDATES`에서 뒤에 있는 인코딩이 골라진다. 모든
+/// 후보가 디코딩에 실패하면(`i64::MIN`) UTF-8로 수렴한다.
+pub fn detect_encoding(bytes: &[u8]) -> &'static Encoding {
+    if bytes.starts_with(&[0xFF, 0xFE]) {
+        return encoding_rs::UTF_16LE;
+    }
+    if bytes.starts_with(&[0xFE, 0xFF]) {
+        return encoding_rs::UTF_16BE;
+    }
+
+    CANDIDATES
+        .iter()
+        .copied()
+        .max_by_key(|candidate| score_candidate(bytes, candidate))
+        .unwrap_or(encoding_rs::UTF_8)
+}
+
+/// `c`가 CP932(Shift-JIS)로 표현 가능한지 확인한다. 인코더의 손실 여부(`had_errors`)로
+/// 판단하므로, CP932 코드 페이지에 없는 코드포인트는 모두 걸러진다.
+fn is_cp932_representable(c: char) -> bool {
+    let (_, _, had_errors) = encoding_rs::SHIFT_JIS.encode(&c.to_string());
+    !had_errors
+}
+
+/// CP932로 옮길 수 없는 문자를 `+X{:04X}` 자리표시자로 escape한다. `hangul_encode`가
+/// 쓰는 것과 같은 형식이므로, 번역 경로를 한 번 거치고 나면 `hangul_decode`가 그대로
+/// 복원해 준다.
+fn escape_cp932_unrepresentable(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    for c in text.chars() {
+        if is_cp932_representable(c) {
+            output.push(c);
+        } else {
+            use std::fmt::Write;
+            write!(&mut output, "+X{:04X}", c as u32).unwrap();
+        }
+    }
+    output
+}
+
+impl EzTransInner {
+    /// 원본 바이트의 인코딩을 `detect_encoding`으로 추정해 디코딩하고, CP932로
+    /// 표현할 수 없는 문자를 자리표시자로 escape한 뒤 [`default_translate`](Self::default_translate)로
+    /// 번역한다. 호출자가 로그를 남기거나 감지 결과를 확인할 수 있도록 번역 결과와
+    /// 함께 감지된 인코딩도 돌려준다.
+    pub fn translate_bytes(&self, bytes: &[u8]) -> Result<(String, &'static Encoding), EzTransError> {
+        let encoding = detect_encoding(bytes);
+        let (decoded, _, had_errors) = encoding.decode(bytes);
+        if had_errors {
+            return Err(EzTransError::FunctionCallFailed(format!(
+                "{}로 감지되었으나 디코딩에 실패했습니다.",
+                encoding.name()
+            )));
+        }
+
+        let escaped = escape_cp932_unrepresentable(&decoded);
+        let translated = self.default_translate(&escaped)?;
+        Ok((translated, encoding))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_utf16_le_bom() {
+        let bytes = [0xFF, 0xFE, 0x41, 0x00];
+        assert_eq!(detect_encoding(&bytes), encoding_rs::UTF_16LE);
+    }
+
+    #[test]
+    fn test_detects_utf16_be_bom() {
+        let bytes = [0xFE, 0xFF, 0x00, 0x41];
+        assert_eq!(detect_encoding(&bytes), encoding_rs::UTF_16BE);
+    }
+
+    #[test]
+    fn test_detects_shift_jis_text() {
+        let bytes = encoding_rs::SHIFT_JIS.encode("こんにちは").0.into_owned();
+        assert_eq!(detect_encoding(&bytes), encoding_rs::SHIFT_JIS);
+    }
+
+    #[test]
+    fn test_detects_plain_ascii_as_utf8() {
+        assert_eq!(detect_encoding(b"hello world"), encoding_rs::UTF_8);
+    }
+
+    #[test]
+    fn test_detects_euc_kr_text() {
+        let bytes = encoding_rs::EUC_KR.encode("안녕하세요").0.into_owned();
+        assert_eq!(detect_encoding(&bytes), encoding_rs::EUC_KR);
+    }
+
+    #[test]
+    fn test_sentence_ending_bonus_breaks_ties_toward_punctuated_text() {
+        let with_ending = encoding_rs::EUC_JP.encode("こんにちは。").0.into_owned();
+        let without_ending = encoding_rs::EUC_JP.encode("こんにちは").0.into_owned();
+        assert!(
+            score_candidate(&with_ending, encoding_rs::EUC_JP)
+                > score_candidate(&without_ending, encoding_rs::EUC_JP)
+        );
+    }
+
+    #[test]
+    fn test_cp932_representable_ascii() {
+        assert!(is_cp932_representable('A'));
+    }
+
+    #[test]
+    fn test_cp932_unrepresentable_emoji_is_escaped() {
+        assert!(!is_cp932_representable('😀'));
+        let escaped = escape_cp932_unrepresentable("hi😀");
+        assert_eq!(escaped, format!("hi+X{:04X}", '😀' as u32));
+    }
+}
@@ -0,0 +1,431 @@
+// 전용 워커 스레드마다 자신의 `EzTransEngine`을 직접 로드해 그 스레드에 고정시키고,
+// 공유 작업 큐에서 번역 요청을 꺼내 처리하는 멀티스레드 번역 풀.
+//
+// `translate_mmnt`/`translate_mmntw`는 "No Thread" 모드, 즉 여러 엔진 인스턴스를
+// 동시에 돌리기 위한 진입점이다(`pool::EzTransPool`처럼 별도 프로세스를 띄우는 대신,
+// 엔진을 스레드 하나에 묶어 두는 쪽). 이전 버전의 `EzTransPool`은 호출마다
+// `thread::scope`로 스레드 묶음을 새로 만들어 입력을 정적으로 나눠 줬는데, 요청이
+// 끊임없이 들어오는 서버 상황에서는 매번 스레드를 새로 만드는 비용과, 텍스트 길이가
+// 들쑥날쑥할 때 정적 분할이 부하를 고르게 나누지 못한다는 문제가 있었다. rayon-core의
+// registry처럼 워커 스레드를 풀의 수명 동안 장수시키고, 작업을 공유 큐로 넘겨 쉬고
+// 있는 워커가 꺼내 가게 한다 — 엔진은 자신을 만든 스레드를 벗어나지 않으므로, DLL의
+// TLS 상태도 항상 그 스레드에서 해제된다.
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::mpsc;
+use std::sync::{Arc, Barrier, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread::{self, JoinHandle};
+
+use crate::engine_status::EngineStatus;
+use crate::output_validator::OutputValidator;
+use crate::{EzTransEngine, EzTransError};
+
+/// 작업이 끝났을 때 결과를 어디로 돌려줄지. `translate`는 `Reply::Blocking`으로
+/// mpsc 채널에 바로 실어 보내고, `translate_async`는 `Reply::Handle`로
+/// [`TranslationHandle`]이 공유하는 상태에 써 넣은 뒤 기다리는 쪽을 깨운다.
+enum Reply {
+    Blocking(mpsc::Sender<Result<String, EzTransError>>),
+    Handle(Arc<(Mutex<HandleState>, Condvar)>),
+}
+
+impl Reply {
+    fn fulfill(self, result: Result<String, EzTransError>) {
+        match self {
+            Reply::Blocking(sender) => {
+                let _ = sender.send(result);
+            }
+            Reply::Handle(shared) => {
+                let (lock, condvar) = &*shared;
+                let waker = {
+                    let mut state = lock.lock().unwrap();
+                    state.result = Some(result);
+                    state.waker.take()
+                };
+                condvar.notify_one();
+                if let Some(waker) = waker {
+                    waker.wake();
+                }
+            }
+        }
+    }
+}
+
+/// 공유 큐에 쌓이는 번역 작업 하나. 워커가 처리를 마치면 `reply`로 결과를 돌려보낸다.
+struct TranslateJob {
+    text: String,
+    reply: Reply,
+}
+
+/// 특정 워커 하나를 콕 집어 실행시키는 [`EzTransPool::broadcast`] 작업. 쉬고 있는
+/// 아무 워커나 가져가는 `TranslateJob`과 달리, 자신이 지목된 워커가 깨어날 때까지
+/// 전용 슬롯에서 기다린다.
+type BroadcastJob = Box<dyn FnOnce(&EzTransEngine) + Send>;
+
+/// 워커가 `pop`에서 돌려받는 작업 한 건.
+enum Job {
+    Translate(TranslateJob),
+    Broadcast(BroadcastJob),
+}
+
+/// 큐와 종료 플래그, 워커별 방송 슬롯을 한 락 안에 묶어 서로 레이스 나지 않게 한다.
+struct QueueState {
+    jobs: VecDeque<TranslateJob>,
+    /// 워커 인덱스로 찾는 1인용 방송 슬롯. `broadcast`는 워커 수만큼 슬롯을 채우고,
+    /// 각 워커는 자기 슬롯만 들여다본다.
+    broadcast_slots: Vec<Option<BroadcastJob>>,
+    shutting_down: bool,
+}
+
+/// 워커들이 공유하는 큐와, 쉬고 있는 워커를 깨우는 조건 변수.
+struct Shared {
+    state: Mutex<QueueState>,
+    condvar: Condvar,
+}
+
+impl Shared {
+    fn push(&self, job: TranslateJob) {
+        self.state.lock().unwrap().jobs.push_back(job);
+        self.condvar.notify_one();
+    }
+
+    /// `worker_id` 슬롯에 방송 작업을 꽂아 두고, 파킹되어 있는 모든 워커를 깨운다 —
+    /// 특정 워커 하나만 깨워야 하는 건 아니지만(다른 워커들도 깨어나 자기 슬롯이
+    /// 비어 있음을 확인하고 다시 잠든다), `notify_one`으로는 엉뚱한 워커가 깨어나
+    /// 정작 지목된 워커는 계속 잠들어 있을 수 있다.
+    fn push_broadcast(&self, worker_id: usize, job: BroadcastJob) {
+        self.state.lock().unwrap().broadcast_slots[worker_id] = Some(job);
+        self.condvar.notify_all();
+    }
+
+    /// `worker_id`의 방송 슬롯을 우선 확인한 뒤, 없으면 공유 큐에서 번역 작업을
+    /// 꺼낸다. 둘 다 비어 있고 종료 신호도 없으면, 뭔가 들어오거나 종료 신호가
+    /// 올 때까지 스레드를 파킹한다.
+    fn pop(&self, worker_id: usize) -> Option<Job> {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            if let Some(job) = state.broadcast_slots[worker_id].take() {
+                return Some(Job::Broadcast(job));
+            }
+            if let Some(job) = state.jobs.pop_front() {
+                return Some(Job::Translate(job));
+            }
+            if state.shutting_down {
+                return None;
+            }
+            state = self.condvar.wait(state).unwrap();
+        }
+    }
+
+    /// 종료 플래그를 세우고 파킹되어 있는 모든 워커를 깨운다. 큐/슬롯에 남아 있던
+    /// 작업은 워커가 `pop`에서 먼저 소진한 뒤에야 종료한다.
+    fn shutdown(&self) {
+        self.state.lock().unwrap().shutting_down = true;
+        self.condvar.notify_all();
+    }
+}
+
+/// `worker_count`개의 전용 워커 스레드를 띄우고, 각 스레드가 독립적으로 로드한
+/// `EzTransEngine`을 평생 소유하는 풀. `translate`/`translate_batch`로 들어온 작업은
+/// 공유 큐를 거쳐 쉬고 있는 워커에게 전달된다.
+///
+/// 풀이 드롭되면 모든 워커에게 종료 신호를 보내고, 밀려 있던 작업을 마저 처리하게 한
+/// 뒤 각자 자신의 엔진을 드롭하고 끝내는 것까지 기다린다 — 엔진은 생성한 스레드에서만
+/// 버려지므로, DLL의 TLS 상태도 거기서 그대로 해제된다.
+pub struct EzTransPool {
+    shared: Arc<Shared>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl EzTransPool {
+    /// `worker_count`개의 엔진을 각각 자신의 워커 스레드에서 로드하고 확장 초기화한다.
+    /// 어느 한 워커라도 초기화에 실패하면 이미 떠 있던 워커들도 모두 종료시키고 그
+    /// 오류를 돌려준다.
+    pub fn new<P: AsRef<Path>, Q: AsRef<Path>>(
+        worker_count: usize,
+        dll_path: P,
+        dat_path: Q,
+    ) -> Result<Self, EzTransError> {
+        let dll_path: PathBuf = dll_path.as_ref().to_path_buf();
+        let dat_path_str = dat_path
+            .as_ref()
+            .to_str()
+            .ok_or(EzTransError::InvalidPath)?
+            .to_string();
+
+        let shared = Arc::new(Shared {
+            state: Mutex::new(QueueState {
+                jobs: VecDeque::new(),
+                broadcast_slots: (0..worker_count).map(|_| None).collect(),
+                shutting_down: false,
+            }),
+            condvar: Condvar::new(),
+        });
+
+        let mut workers = Vec::with_capacity(worker_count);
+        for worker_id in 0..worker_count {
+            let worker_shared = Arc::clone(&shared);
+            let dll_path = dll_path.clone();
+            let dat_path_str = dat_path_str.clone();
+            let (init_tx, init_rx) = mpsc::channel();
+
+            let handle = thread::spawn(move || {
+                let engine = match EzTransEngine::new(&dll_path)
+                    .and_then(|engine| engine.initialize_ex("CSUSER123455", &dat_path_str).map(|_| engine))
+                {
+                    Ok(engine) => {
+                        let _ = init_tx.send(Ok(()));
+                        engine
+                    }
+                    Err(e) => {
+                        let _ = init_tx.send(Err(e));
+                        return;
+                    }
+                };
+
+                while let Some(job) = worker_shared.pop(worker_id) {
+                    match job {
+                        Job::Translate(job) => {
+                            let result = engine.translate_mmnt(&job.text);
+                            job.reply.fulfill(result);
+                        }
+                        Job::Broadcast(run) => run(&engine),
+                    }
+                }
+                // `engine`은 여기서 바로 드롭된다 — 생성한 이 스레드에서 DLL의 TLS
+                // 상태가 해제된다.
+            });
+
+            match init_rx.recv() {
+                Ok(Ok(())) => workers.push(handle),
+                Ok(Err(e)) => {
+                    Self::shutdown_workers(&shared, workers);
+                    return Err(e);
+                }
+                Err(_) => {
+                    Self::shutdown_workers(&shared, workers);
+                    return Err(EzTransError::FunctionCallFailed(
+                        "워커 스레드가 초기화 중 panic했습니다".to_string(),
+                    ));
+                }
+            }
+        }
+
+        Ok(Self { shared, workers })
+    }
+
+    fn shutdown_workers(shared: &Shared, workers: Vec<JoinHandle<()>>) {
+        shared.shutdown();
+        for worker in workers {
+            let _ = worker.join();
+        }
+    }
+
+    /// 풀에 떠 있는 워커 스레드 수.
+    pub fn worker_count(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// 작업을 큐에 넣고, 쉬고 있는 워커가 처리할 때까지 기다린다.
+    pub fn translate(&self, text: impl Into<String>) -> Result<String, EzTransError> {
+        let (reply, reply_rx) = mpsc::channel();
+        self.shared.push(TranslateJob {
+            text: text.into(),
+            reply: Reply::Blocking(reply),
+        });
+        reply_rx
+            .recv()
+            .map_err(|_| EzTransError::FunctionCallFailed("워커 스레드가 응답 없이 종료되었습니다".to_string()))?
+    }
+
+    /// `translate`를 실행하고, [`OutputValidator`]가 출력이 손상되었다고 판정하면
+    /// 최대 `retries`번까지 다시 큐에 제출한다. 재제출된 작업은 쉬고 있는 아무
+    /// 워커에게나 배분되므로, 자연스럽게 처음과 다른(= 방금 깨졌던 워커가 아닌)
+    /// 워커가 맡을 수도 있다. `retries`를 다 써도 손상된 채면 마지막 출력을 담아
+    /// [`EzTransError::CorruptedOutput`]을 돌려준다.
+    pub fn translate_validated(
+        &self,
+        validator: &OutputValidator,
+        text: impl Into<String>,
+        retries: usize,
+    ) -> Result<String, EzTransError> {
+        let text = text.into();
+        let mut last_output = String::new();
+
+        for _ in 0..=retries {
+            let output = self.translate(text.clone())?;
+            if !validator.is_corrupted(&text, &output) {
+                return Ok(output);
+            }
+            last_output = output;
+        }
+
+        Err(EzTransError::CorruptedOutput {
+            input: text,
+            output: last_output,
+        })
+    }
+
+    /// `inputs` 전체를 큐에 한 번에 넣고, 입력 순서를 보존한 결과로 모아 돌려준다.
+    /// 작업이 쉬고 있는 아무 워커에게나 배분되므로, 입력 길이가 들쑥날쑥해도 예전의
+    /// 정적 분할 방식보다 부하가 고르게 퍼진다.
+    pub fn translate_batch(&self, inputs: &[String]) -> Vec<Result<String, EzTransError>> {
+        let receivers: Vec<mpsc::Receiver<Result<String, EzTransError>>> = inputs
+            .iter()
+            .map(|text| {
+                let (reply, reply_rx) = mpsc::channel();
+                self.shared.push(TranslateJob {
+                    text: text.clone(),
+                    reply: Reply::Blocking(reply),
+                });
+                reply_rx
+            })
+            .collect();
+
+        receivers
+            .into_iter()
+            .map(|reply_rx| {
+                reply_rx.recv().map_err(|_| {
+                    EzTransError::FunctionCallFailed("워커 스레드가 응답 없이 종료되었습니다".to_string())
+                })?
+            })
+            .collect()
+    }
+
+    /// 작업을 큐에 넣기만 하고 블로킹 없이 바로 [`TranslationHandle`]을 돌려준다.
+    /// 핸들은 `wait`로 블로킹 대기하거나, `try_recv`로 논블로킹 폴링하거나, 그대로
+    /// `.await`에 넘겨 비동기 런타임에 맡길 수 있다.
+    pub fn translate_async(&self, text: impl Into<String>) -> TranslationHandle {
+        let shared = Arc::new((
+            Mutex::new(HandleState {
+                result: None,
+                waker: None,
+            }),
+            Condvar::new(),
+        ));
+
+        self.shared.push(TranslateJob {
+            text: text.into(),
+            reply: Reply::Handle(Arc::clone(&shared)),
+        });
+
+        TranslationHandle { shared }
+    }
+
+    /// `f`를 워커 수만큼 복제해 모든 워커 스레드에서 한 번씩, 그 스레드가 소유한
+    /// 엔진에 대해 실행하고, 워커 순서대로 모은 결과를 돌려준다. 사전/옵션을 풀
+    /// 전체 엔진에 동일하게 설정하거나, 모든 엔진이 일관된 출력을 내는지 워밍업
+    /// 번역으로 확인하는 용도로 쓴다.
+    ///
+    /// barrier로 호출자와 워커 전부를 동기화하므로, 어느 한 워커가 느리게 끝나도
+    /// 결과 벡터는 항상 전체가 채워진 뒤에야 돌아온다.
+    pub fn broadcast<T: Send + 'static>(
+        &self,
+        f: impl Fn(&EzTransEngine) -> T + Sync + Send + 'static,
+    ) -> Vec<T> {
+        let worker_count = self.workers.len();
+        if worker_count == 0 {
+            return Vec::new();
+        }
+
+        let barrier = Arc::new(Barrier::new(worker_count + 1));
+        let results: Arc<Mutex<Vec<Option<T>>>> =
+            Arc::new(Mutex::new((0..worker_count).map(|_| None).collect()));
+        let f = Arc::new(f);
+
+        for worker_id in 0..worker_count {
+            let f = Arc::clone(&f);
+            let results = Arc::clone(&results);
+            let barrier = Arc::clone(&barrier);
+            self.shared.push_broadcast(
+                worker_id,
+                Box::new(move |engine: &EzTransEngine| {
+                    let value = f(engine);
+                    results.lock().unwrap()[worker_id] = Some(value);
+                    barrier.wait();
+                }),
+            );
+        }
+
+        barrier.wait();
+
+        results
+            .lock()
+            .unwrap()
+            .drain(..)
+            .map(|slot| slot.expect("barrier를 통과한 시점엔 모든 워커 슬롯이 채워져 있다"))
+            .collect()
+    }
+
+    /// 풀에 속한 모든 워커 엔진의 현재 상태 스냅샷을 모아서 돌려준다. RocksDB의
+    /// 스레드 상태 테이블을 `SHOW ENGINE`처럼 한 번에 질의하는 용도로 쓴다.
+    pub fn thread_list(&self) -> Vec<EngineStatus> {
+        self.broadcast(|engine| engine.status())
+    }
+}
+
+impl Drop for EzTransPool {
+    /// 종료 신호를 보내 밀려 있던 작업을 마저 처리하게 한 뒤, 각 워커가 자신의 엔진을
+    /// 스스로 드롭할 때까지 기다린다.
+    fn drop(&mut self) {
+        self.shared.shutdown();
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+/// [`TranslationHandle`]이 `Mutex`로 감싸 공유하는 상태. 워커가 결과를 채우고
+/// `waker`를 깨우면 `wait`/`try_recv`/`poll` 중 먼저 온 쪽이 가져간다.
+struct HandleState {
+    result: Option<Result<String, EzTransError>>,
+    waker: Option<Waker>,
+}
+
+/// `EzTransPool::translate_async`가 제출과 동시에 돌려주는 핸들. `translation_engine`의
+/// `JobHandle`과 같은 모양이지만, 블로킹 대기(`wait`)와 논블로킹 폴링(`try_recv`)도
+/// 함께 제공한다 — 둘 다 `Future` 구현과 상태를 공유하므로 어느 쪽으로 먼저 결과를
+/// 받아가든 나머지는 다시 값을 돌려받지 못한다.
+///
+/// `Future`는 표준 라이브러리 트레이트만으로 구현되어 있어(런타임 종속 없음) 피처
+/// 플래그 없이도 tokio/async-std 등 어떤 executor의 `.await`와도 바로 맞물린다 —
+/// `translation_engine::JobHandle`도 같은 이유로 피처 게이트를 두지 않는다.
+pub struct TranslationHandle {
+    shared: Arc<(Mutex<HandleState>, Condvar)>,
+}
+
+impl TranslationHandle {
+    /// 결과가 준비될 때까지 현재 스레드를 블로킹한다.
+    pub fn wait(self) -> Result<String, EzTransError> {
+        let (lock, condvar) = &*self.shared;
+        let mut state = lock.lock().unwrap();
+        loop {
+            if let Some(result) = state.result.take() {
+                return result;
+            }
+            state = condvar.wait(state).unwrap();
+        }
+    }
+
+    /// 결과가 아직 없으면 기다리지 않고 바로 `None`을 돌려준다.
+    pub fn try_recv(&self) -> Option<Result<String, EzTransError>> {
+        self.shared.0.lock().unwrap().result.take()
+    }
+}
+
+impl Future for TranslationHandle {
+    type Output = Result<String, EzTransError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.shared.0.lock().unwrap();
+        if let Some(result) = state.result.take() {
+            Poll::Ready(result)
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
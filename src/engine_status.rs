@@ -0,0 +1,130 @@
+// `EzTransInner`/풀 워커가 지금 뭘 하고 있는지 관찰할 수 있게, RocksDB의 스레드
+// 상태 추적기(thread-status tracker)를 본떠 만든 가벼운 런타임 상태 레지스트리.
+//
+// 테스트 코드들은 `success_count`/`error_count` 같은 원자 카운터를 손으로 스레드마다
+// 들고 다니며 합산해 왔다(`thread_local_test.rs`, `thread_safety_test.rs`). 이 장부를
+// 라이브러리 안으로 끌어들여, 서버가 지금 모든 워커가 뭘 하고 있는지, 지금까지 몇 번
+// 번역에 성공/실패했는지를 질의 한 번으로 바로 확인할 수 있게 한다.
+
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+use std::sync::Mutex;
+use std::thread::{self, ThreadId};
+use std::time::{Duration, Instant};
+
+use crate::EzTransError;
+
+/// 엔진이 지금 수행 중인 연산.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EngineOperation {
+    Idle,
+    Initializing,
+    Translating,
+}
+
+impl EngineOperation {
+    fn to_u8(self) -> u8 {
+        match self {
+            EngineOperation::Idle => 0,
+            EngineOperation::Initializing => 1,
+            EngineOperation::Translating => 2,
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            1 => EngineOperation::Initializing,
+            2 => EngineOperation::Translating,
+            _ => EngineOperation::Idle,
+        }
+    }
+}
+
+/// 특정 시점에 찍은 엔진 상태 스냅샷. [`EngineStatusTracker::snapshot`]과
+/// `engine_pool::EzTransPool::thread_list`가 돌려준다.
+#[derive(Debug, Clone)]
+pub struct EngineStatus {
+    pub thread_id: Option<ThreadId>,
+    pub operation: EngineOperation,
+    pub translations_completed: u64,
+    pub errors: u64,
+    pub corruptions: u64,
+    pub busy_time: Duration,
+}
+
+/// `EzTransInner`가 들고 있는 원자적 상태 장부. 호출 하나하나가 `track`을 거치면서
+/// 연산 종류/스레드 id를 갱신하고, 끝나면 경과 시간과 성공/실패를 누적한다.
+#[derive(Debug)]
+pub struct EngineStatusTracker {
+    operation: AtomicU8,
+    thread_id: Mutex<Option<ThreadId>>,
+    translations_completed: AtomicU64,
+    errors: AtomicU64,
+    corruptions: AtomicU64,
+    busy_nanos: AtomicU64,
+}
+
+impl Default for EngineStatusTracker {
+    fn default() -> Self {
+        Self {
+            operation: AtomicU8::new(EngineOperation::Idle.to_u8()),
+            thread_id: Mutex::new(None),
+            translations_completed: AtomicU64::new(0),
+            errors: AtomicU64::new(0),
+            corruptions: AtomicU64::new(0),
+            busy_nanos: AtomicU64::new(0),
+        }
+    }
+}
+
+impl EngineStatusTracker {
+    /// `op`을 실행하는 동안 상태를 갱신하며 `f`를 실행한다. 성공하고 `op`이
+    /// [`EngineOperation::Translating`]이면 `translations_completed`를, 실패하면
+    /// `errors`를 늘린다. 끝나면 경과 시간을 `busy_time`에 더하고 연산을 다시
+    /// `Idle`로 되돌린다.
+    pub(crate) fn track<T>(
+        &self,
+        op: EngineOperation,
+        f: impl FnOnce() -> Result<T, EzTransError>,
+    ) -> Result<T, EzTransError> {
+        *self.thread_id.lock().unwrap() = Some(thread::current().id());
+        self.operation.store(op.to_u8(), Ordering::Relaxed);
+
+        let start = Instant::now();
+        let result = f();
+        let elapsed = start.elapsed();
+
+        self.busy_nanos
+            .fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+        match &result {
+            Ok(_) if op == EngineOperation::Translating => {
+                self.translations_completed.fetch_add(1, Ordering::Relaxed);
+            }
+            Ok(_) => {}
+            Err(_) => {
+                self.errors.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        self.operation
+            .store(EngineOperation::Idle.to_u8(), Ordering::Relaxed);
+
+        result
+    }
+
+    /// 출력이 깨졌는지 판정하는 건(`is_corrupted` 같은 휴리스틱) 이 라이브러리가 알 수
+    /// 없는 영역이므로, 호출자가 직접 발견했을 때 이 장부에 반영하도록 공개해 둔다.
+    pub fn record_corruption(&self) {
+        self.corruptions.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 지금 이 순간의 상태를 읽기 전용 스냅샷으로 복사해 돌려준다.
+    pub fn snapshot(&self) -> EngineStatus {
+        EngineStatus {
+            thread_id: *self.thread_id.lock().unwrap(),
+            operation: EngineOperation::from_u8(self.operation.load(Ordering::Relaxed)),
+            translations_completed: self.translations_completed.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+            corruptions: self.corruptions.load(Ordering::Relaxed),
+            busy_time: Duration::from_nanos(self.busy_nanos.load(Ordering::Relaxed)),
+        }
+    }
+}
@@ -10,6 +10,8 @@ pub enum EzTransError {
     InvalidString(#[from] NulError),
     #[error("utf16 error {0}")]
     Utf16Error(#[from] std::string::FromUtf16Error),
+    #[error("Failed to transcode: {0}")]
+    TranscodeError(#[from] TranscodeError),
     #[error("Invalid dll path")]
     InvalidPath,
     #[error("Failed to load dll: {0}")]
@@ -18,6 +20,10 @@ pub enum EzTransError {
     FunctionLoadError(String),
     #[error("Failed to call function: {0}")]
     FunctionCallFailed(String),
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("translation output looks corrupted for input {input:?}: {output:?}")]
+    CorruptedOutput { input: String, output: String },
 }
 
 #[derive(Error, Debug, Clone)]
@@ -26,15 +32,72 @@ pub enum TransErr {
     NullPointer,
     ///Translation failed
     Failed,
-    ///EUC-KR decoding failed
-    EucKrDecodeFailed,
+    ///EUC-KR byte sequence is structurally invalid (lead/trail byte out of range)
+    InvalidByteSequence { bytes: Vec<u8>, offset: usize },
+    ///EUC-KR byte sequence is structurally valid but maps to no character
+    UndefinedConversion { offset: usize },
+    ///input ended in the middle of a multi-byte EUC-KR character
+    IncompleteInput,
 }
 impl fmt::Display for TransErr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             TransErr::NullPointer => write!(f, "TRANSLATE func returned a null pointer"),
             TransErr::Failed => write!(f, "Translation failed"),
-            TransErr::EucKrDecodeFailed => write!(f, "EUC-KR decoding failed"),
+            TransErr::InvalidByteSequence { bytes, offset } => {
+                write!(f, "invalid EUC-KR byte sequence at offset {offset}:")?;
+                for b in bytes {
+                    write!(f, " {b:02X}")?;
+                }
+                Ok(())
+            }
+            TransErr::UndefinedConversion { offset } => {
+                write!(f, "undefined EUC-KR conversion at offset {offset}")
+            }
+            TransErr::IncompleteInput => write!(f, "incomplete EUC-KR input"),
         }
     }
 }
+
+/// `translate_mmntw`가 돌려받은 UTF-16 출력을 디코딩하다 실패했을 때의 상세 정보.
+///
+/// `std::string::FromUtf16Error`는 실패했다는 사실만 알려줄 뿐 어디서 실패했는지는
+/// 알려주지 않는다. 이 타입은 `std::string::FromUtf8Error`의 `valid_up_to()`/원본
+/// 바이트 노출 방식을 그대로 본떠, 실패 지점(코드 단위 오프셋)과 짝이 맞지 않는 서로게이트
+/// 코드 단위, 그리고 그 앞까지 이미 디코딩에 성공한 부분 문자열을 함께 들고 있는다.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub struct TranscodeError {
+    pub(crate) valid_prefix: String,
+    pub(crate) valid_up_to: usize,
+    pub(crate) invalid_unit: u16,
+}
+impl TranscodeError {
+    /// 디코딩에 성공한 마지막 코드 단위 바로 다음 오프셋(= 실패 지점).
+    pub fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+
+    /// 실패를 일으킨, 짝이 맞지 않는 서로게이트 코드 단위.
+    pub fn invalid_unit(&self) -> u16 {
+        self.invalid_unit
+    }
+
+    /// 실패 지점 이전까지 이미 성공적으로 디코딩된 부분 문자열.
+    pub fn valid_prefix(&self) -> &str {
+        &self.valid_prefix
+    }
+
+    /// 부분 문자열 소유권을 가져간다.
+    pub fn into_valid_prefix(self) -> String {
+        self.valid_prefix
+    }
+}
+impl fmt::Display for TranscodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid UTF-16 at unit offset {}: unpaired surrogate {:#06X}",
+            self.valid_up_to, self.invalid_unit
+        )
+    }
+}
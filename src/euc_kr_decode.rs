@@ -0,0 +1,230 @@
+// EUC-KR 바이트 스트림을 엄격하게 검증하거나(strict), 깨진 바이트를 U+FFFD로
+// 치환하며 관대하게(lossy) 디코딩한다.
+//
+// `encoding_rs::Encoding::decode`는 디코딩 실패를 "교체 문자를 넣었는지" 여부
+// (`had_errors: bool`) 하나로만 알려줘서, 실패가 구조적으로 잘못된 바이트 시퀀스인지
+// (리드/트레일 바이트가 EUC-KR 범위를 벗어남), 구조는 맞지만 대응하는 문자가 없는
+// 건지, 아니면 멀티바이트 문자 도중 입력이 끝난 건지 구분할 수 없다. Ruby의 transcode
+// 계층이 이 셋을 구분하는 것을 본떠, 리드/트레일 바이트를 직접 검사해 실패 지점과
+// 원인을 함께 돌려준다.
+
+use crate::{EzTransInner, TransErr};
+
+/// `translate_mmnt`의 출력을 어느 표로 디코딩할지 고른다.
+///
+/// `encoding_rs::EUC_KR`은 WHATWG 인코딩 표준에 맞춰 이미 windows-949(통합 완성형,
+/// CP949/UHC)의 전체 매핑을 담고 있어, 두 모드 모두 실제 문자 매핑은 이 테이블 하나로
+/// 처리한다 — 차이는 리드/트레일 바이트가 "구조적으로 유효한 범위"로 인정되는지
+/// 뿐이다. [`OutputEncoding::Cp949`]는 그 구조적 범위를 확장형 행(0x81~0xA0)까지
+/// 넓혀, 원래 EUC-KR에서는 잘못된 시퀀스로 거부되던 확장 한글 음절/기호를 받아들인다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputEncoding {
+    /// 원래의 EUC-KR. 리드 바이트를 0xA1~0xFE로만 인정한다.
+    EucKr,
+    /// CP949(통합 완성형). EUC-KR의 구조적 상위 호환으로, 리드 바이트 0x81~0xFE를
+    /// 모두 인정한다. `J2KEngine`이 실제로 돌려주는 확장 한글 음절/기호가 여기 속해,
+    /// 기본값으로 쓴다.
+    #[default]
+    Cp949,
+}
+
+impl OutputEncoding {
+    fn lead_byte_range(self) -> std::ops::RangeInclusive<u8> {
+        match self {
+            OutputEncoding::EucKr => 0xA1..=0xFE,
+            OutputEncoding::Cp949 => 0x81..=0xFE,
+        }
+    }
+
+    /// `lead`로 시작하는 두 바이트 문자의 트레일 바이트로 구조적으로 유효한 범위.
+    /// CP949 확장 행(리드 0x81~0xA0)은 원래 EUC-KR 행보다 트레일 바이트 범위가 넓다.
+    fn trail_byte_range(self, lead: u8) -> std::ops::RangeInclusive<u8> {
+        match self {
+            OutputEncoding::EucKr => 0xA1..=0xFE,
+            OutputEncoding::Cp949 if lead < 0xA1 => 0x41..=0xFE,
+            OutputEncoding::Cp949 => 0xA1..=0xFE,
+        }
+    }
+}
+
+impl EzTransInner {
+    /// `output_encoding` 설정을 바꾼다. 이후 모든 `translate_mmnt`/`translate_mmnt_lossy`
+    /// 호출이 이 표로 DLL 출력을 디코딩한다.
+    pub fn set_output_encoding(&self, encoding: OutputEncoding) {
+        *self.output_encoding.lock().unwrap() = encoding;
+    }
+
+    /// 현재 `translate_mmnt`/`translate_mmnt_lossy`가 쓰고 있는 출력 인코딩.
+    pub fn output_encoding(&self) -> OutputEncoding {
+        *self.output_encoding.lock().unwrap()
+    }
+}
+
+/// `bytes`를 `encoding`이 고른 표로 엄격하게 디코딩한다. 첫 실패 지점에서 멈추고,
+/// 구조적으로 잘못된 바이트 시퀀스/대응하는 문자가 없는 시퀀스/멀티바이트 문자 도중
+/// 끝난 입력을 각각 구분해 보고한다.
+pub fn decode_strict(bytes: &[u8], encoding: OutputEncoding) -> Result<String, TransErr> {
+    let mut output = String::with_capacity(bytes.len());
+    let mut offset = 0;
+
+    while offset < bytes.len() {
+        let lead = bytes[offset];
+
+        if lead < 0x80 {
+            output.push(lead as char);
+            offset += 1;
+            continue;
+        }
+
+        if !encoding.lead_byte_range().contains(&lead) {
+            return Err(TransErr::InvalidByteSequence {
+                bytes: vec![lead],
+                offset,
+            });
+        }
+
+        let Some(&trail) = bytes.get(offset + 1) else {
+            return Err(TransErr::IncompleteInput);
+        };
+
+        if !encoding.trail_byte_range(lead).contains(&trail) {
+            return Err(TransErr::InvalidByteSequence {
+                bytes: vec![lead, trail],
+                offset,
+            });
+        }
+
+        let (decoded, _, had_errors) = encoding_rs::EUC_KR.decode(&bytes[offset..offset + 2]);
+        if had_errors {
+            return Err(TransErr::UndefinedConversion { offset });
+        }
+
+        output.push_str(&decoded);
+        offset += 2;
+    }
+
+    Ok(output)
+}
+
+/// `decode_strict`과 같은 규칙으로 훑되, 실패한 자리마다 멈추지 않고 U+FFFD로
+/// 치환한 뒤 계속 진행한다. 돌려주는 `Vec<usize>`는 치환이 일어난 바이트 오프셋들이다.
+pub fn decode_lossy(bytes: &[u8], encoding: OutputEncoding) -> (String, Vec<usize>) {
+    let mut output = String::with_capacity(bytes.len());
+    let mut replaced = Vec::new();
+    let mut offset = 0;
+
+    while offset < bytes.len() {
+        let lead = bytes[offset];
+
+        if lead < 0x80 {
+            output.push(lead as char);
+            offset += 1;
+            continue;
+        }
+
+        if !encoding.lead_byte_range().contains(&lead) {
+            output.push('\u{FFFD}');
+            replaced.push(offset);
+            offset += 1;
+            continue;
+        }
+
+        let Some(&trail) = bytes.get(offset + 1) else {
+            output.push('\u{FFFD}');
+            replaced.push(offset);
+            break;
+        };
+
+        if !encoding.trail_byte_range(lead).contains(&trail) {
+            output.push('\u{FFFD}');
+            replaced.push(offset);
+            offset += 1;
+            continue;
+        }
+
+        let (decoded, _, had_errors) = encoding_rs::EUC_KR.decode(&bytes[offset..offset + 2]);
+        if had_errors {
+            output.push('\u{FFFD}');
+            replaced.push(offset);
+        } else {
+            output.push_str(&decoded);
+        }
+        offset += 2;
+    }
+
+    (output, replaced)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_strict_round_trips_valid_euc_kr() {
+        let (encoded, _, _) = encoding_rs::EUC_KR.encode("안녕하세요");
+        assert_eq!(
+            decode_strict(&encoded, OutputEncoding::Cp949).unwrap(),
+            "안녕하세요"
+        );
+    }
+
+    #[test]
+    fn test_decode_strict_reports_incomplete_input() {
+        let bytes = [0xB0]; // 리드 바이트만 있고 트레일 바이트가 없음
+        assert!(matches!(
+            decode_strict(&bytes, OutputEncoding::Cp949),
+            Err(TransErr::IncompleteInput)
+        ));
+    }
+
+    #[test]
+    fn test_decode_strict_reports_invalid_byte_sequence() {
+        let bytes = [0xB0, 0x20]; // 트레일 바이트가 범위 밖(공백)
+        let err = decode_strict(&bytes, OutputEncoding::Cp949).unwrap_err();
+        assert!(matches!(err, TransErr::InvalidByteSequence { offset: 0, .. }));
+    }
+
+    #[test]
+    fn test_decode_strict_rejects_cp949_extended_lead_byte_under_euc_kr() {
+        // 0x8C는 CP949 확장 행(0x81~0xA0)의 리드 바이트로, 원래 EUC-KR 범위(0xA1~0xFE)
+        // 밖이라 구조적으로 거부되어야 한다.
+        let bytes = [0x8C, 0x41];
+        assert!(matches!(
+            decode_strict(&bytes, OutputEncoding::EucKr),
+            Err(TransErr::InvalidByteSequence { offset: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_decode_strict_accepts_cp949_extended_lead_byte() {
+        // "뷁"은 옛 KS X 1001(EUC-KR)에는 없고 CP949 확장 행에서만 조합 가능한 음절이다.
+        let (encoded, _, had_errors) = encoding_rs::EUC_KR.encode("뷁");
+        assert!(!had_errors);
+        assert!(
+            encoded[0] < 0xA1,
+            "\"뷁\" should encode to a CP949 extended-row lead byte, got {:#04X}",
+            encoded[0]
+        );
+        assert_eq!(
+            decode_strict(&encoded, OutputEncoding::Cp949).unwrap(),
+            "뷁"
+        );
+    }
+
+    #[test]
+    fn test_decode_lossy_substitutes_replacement_char_and_records_offset() {
+        let bytes = [b'a', 0xB0, 0x20, b'b'];
+        let (decoded, replaced) = decode_lossy(&bytes, OutputEncoding::Cp949);
+        // 0xB0(리드)은 대체되고, 뒤따르던 0x20(공백)은 ASCII로 정상 디코딩된다.
+        assert_eq!(decoded, "a\u{FFFD} b");
+        assert_eq!(replaced, vec![1]);
+    }
+
+    #[test]
+    fn test_decode_lossy_handles_incomplete_trailing_lead_byte() {
+        let bytes = [b'a', 0xB0];
+        let (decoded, replaced) = decode_lossy(&bytes, OutputEncoding::Cp949);
+        assert_eq!(decoded, "a\u{FFFD}");
+        assert_eq!(replaced, vec![1]);
+    }
+}
@@ -0,0 +1,341 @@
+// 인코딩을 자동으로 감지해 파일/스트림을 줄 단위로 번역하는 API.
+//
+// 게임 덤프 텍스트는 BOM이 붙은 UTF-16/UTF-8이거나, BOM 없이 `translate_mmnt`가
+// 기대하는 Shift-JIS 그대로인 경우가 흔하다. 호출자가 미리 인코딩을 맞춰 오게 하는
+// 대신, 여기서 선두 바이트를 살펴 알아서 디코딩한다.
+
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::Path;
+
+use crate::{EzTransError, EzTransInner};
+
+/// `translate_reader`/`translate_file`이 번역 결과를 쓸 때 쓰는 출력 인코딩.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputEncoding {
+    #[default]
+    Utf8,
+    EucKr,
+}
+
+/// 선두 바이트를 보고 UTF-16 LE BOM, UTF-8 BOM, 혹은 (BOM이 없다면) Shift-JIS로
+/// 간주해 디코딩한다.
+fn decode_with_bom(bytes: &[u8]) -> String {
+    if let Some(rest) = bytes.strip_prefix(&[0xFF, 0xFE]) {
+        encoding_rs::UTF_16LE.decode(rest).0.into_owned()
+    } else if let Some(rest) = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]) {
+        encoding_rs::UTF_8.decode(rest).0.into_owned()
+    } else {
+        encoding_rs::SHIFT_JIS.decode(bytes).0.into_owned()
+    }
+}
+
+/// [`EzTransInner::translate_file_detected`]가 감지해 보고하는 원본 인코딩.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetectedEncoding {
+    /// 선두에 `0xFF 0xFE`가 있었다.
+    Utf16Le,
+    /// 선두에 `0xFE 0xFF`가 있었다.
+    Utf16Be,
+    /// 선두에 `0xEF 0xBB 0xBF`가 있었다.
+    Utf8Bom,
+    /// BOM은 없었지만 엄격한 UTF-8 디코딩에 성공했다.
+    Utf8,
+    /// BOM도 없고 UTF-8로도 읽을 수 없어 Shift-JIS(CP932)로 대체 디코딩했다.
+    ShiftJis,
+}
+
+/// BOM을 우선 확인하고, 없으면 엄격한 UTF-8을 시도한 뒤 실패할 때만 Shift-JIS로
+/// 넘어간다. `decode_with_bom`과 달리 BOM이 없는 파일을 곧바로 Shift-JIS로 단정하지
+/// 않는다.
+fn decode_detecting_encoding(bytes: &[u8]) -> (String, DetectedEncoding) {
+    if let Some(rest) = bytes.strip_prefix(&[0xFF, 0xFE]) {
+        (
+            encoding_rs::UTF_16LE.decode(rest).0.into_owned(),
+            DetectedEncoding::Utf16Le,
+        )
+    } else if let Some(rest) = bytes.strip_prefix(&[0xFE, 0xFF]) {
+        (
+            encoding_rs::UTF_16BE.decode(rest).0.into_owned(),
+            DetectedEncoding::Utf16Be,
+        )
+    } else if let Some(rest) = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]) {
+        (
+            encoding_rs::UTF_8.decode(rest).0.into_owned(),
+            DetectedEncoding::Utf8Bom,
+        )
+    } else if let Ok(text) = std::str::from_utf8(bytes) {
+        (text.to_string(), DetectedEncoding::Utf8)
+    } else {
+        (
+            encoding_rs::SHIFT_JIS.decode(bytes).0.into_owned(),
+            DetectedEncoding::ShiftJis,
+        )
+    }
+}
+
+/// [`EzTransInner::translate_file_detected`] 한 번 호출의 요약.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileTranslationSummary {
+    pub detected_encoding: DetectedEncoding,
+    /// 치환 문자(`U+FFFD`) 없이 깨끗하게 디코딩된 줄 수.
+    pub clean_lines: usize,
+    /// 치환 문자가 섞여 들어가 디코딩이 깨졌을 가능성이 있는 줄 수.
+    pub fallback_lines: usize,
+}
+
+impl EzTransInner {
+    /// `reader` 전체를 읽어 인코딩을 감지한 뒤, 줄 단위로 `default_translate`를 돌려
+    /// `writer`에 `output_encoding`으로 기록합니다.
+    pub fn translate_reader<R: Read, W: Write>(
+        &self,
+        mut reader: R,
+        mut writer: W,
+        output_encoding: OutputEncoding,
+    ) -> Result<(), EzTransError> {
+        let mut raw = Vec::new();
+        reader.read_to_end(&mut raw)?;
+        let text = decode_with_bom(&raw);
+
+        for line in text.lines() {
+            let translated = self.default_translate(line)?;
+            match output_encoding {
+                OutputEncoding::Utf8 => writer.write_all(translated.as_bytes())?,
+                OutputEncoding::EucKr => {
+                    let (encoded, _, _) = encoding_rs::EUC_KR.encode(&translated);
+                    writer.write_all(&encoded)?;
+                }
+            }
+            writer.write_all(b"\n")?;
+        }
+
+        Ok(())
+    }
+
+    /// `input_path`를 읽어 번역한 뒤 `output_path`에 씁니다. 인코딩 처리는
+    /// [`translate_reader`](Self::translate_reader)와 동일합니다.
+    pub fn translate_file<P: AsRef<Path>, Q: AsRef<Path>>(
+        &self,
+        input_path: P,
+        output_path: Q,
+        output_encoding: OutputEncoding,
+    ) -> Result<(), EzTransError> {
+        let input = File::open(input_path)?;
+        let output = File::create(output_path)?;
+        self.translate_reader(input, output, output_encoding)
+    }
+
+    /// `input_path`의 인코딩을 선두 바이트로 자동 감지해 읽고, 줄 단위로
+    /// `default_translate`를 돌려 `output_path`에 BOM이 붙은 UTF-8로 쓴다.
+    ///
+    /// [`translate_file`](Self::translate_file)은 BOM이 없으면 곧바로 Shift-JIS로
+    /// 단정하지만, 이 함수는 UTF-16 BE도 감지하고 BOM이 없을 때는 엄격한 UTF-8을 먼저
+    /// 시도한다. 치환 문자가 섞인 줄 수를 세어 돌려주므로 호출자가 깨진 디코딩(모지바케)
+    /// 위험을 감지할 수 있다.
+    pub fn translate_file_detected<P: AsRef<Path>, Q: AsRef<Path>>(
+        &self,
+        input_path: P,
+        output_path: Q,
+    ) -> Result<FileTranslationSummary, EzTransError> {
+        let raw = std::fs::read(input_path)?;
+        let (text, detected_encoding) = decode_detecting_encoding(&raw);
+
+        let mut output = File::create(output_path)?;
+        output.write_all("\u{FEFF}".as_bytes())?;
+
+        let mut clean_lines = 0;
+        let mut fallback_lines = 0;
+
+        for line in text.lines() {
+            if line.contains('\u{FFFD}') {
+                fallback_lines += 1;
+            } else {
+                clean_lines += 1;
+            }
+
+            let translated = self.default_translate(line)?;
+            output.write_all(translated.as_bytes())?;
+            output.write_all(b"\n")?;
+        }
+
+        Ok(FileTranslationSummary {
+            detected_encoding,
+            clean_lines,
+            fallback_lines,
+        })
+    }
+
+    /// 입력 파일의 인코딩을 [`decode_detecting_encoding`]으로 자동 감지해 디코딩하고,
+    /// [`MAX_SEGMENT_CODE_UNITS`] UTF-16 코드 단위를 넘지 않는 줄 단위 세그먼트로 나눠
+    /// `translate_mmntw`로 번역한 뒤 `output_encoding`으로 다시 쓴다.
+    ///
+    /// `translate_file_detected`가 한 줄씩 호출을 보내는 것과 달리, 여러 줄을 한도
+    /// 안에서 최대한 묶어 한 번의 호출로 보낸다 — 자막/스크립트처럼 짧은 줄이 아주
+    /// 많은 파일에서 왕복 횟수를 크게 줄인다.
+    pub fn translate_file_auto<P: AsRef<Path>, Q: AsRef<Path>>(
+        &self,
+        input_path: P,
+        output_path: Q,
+        output_encoding: OutputEncoding,
+    ) -> Result<(), EzTransError> {
+        let raw = std::fs::read(input_path)?;
+        let (text, _detected_encoding) = decode_detecting_encoding(&raw);
+
+        let mut output = File::create(output_path)?;
+        for segment in split_into_segments(&text, MAX_SEGMENT_CODE_UNITS) {
+            let translated = self.translate_mmntw(&segment)?;
+            match output_encoding {
+                OutputEncoding::Utf8 => output.write_all(translated.as_bytes())?,
+                OutputEncoding::EucKr => {
+                    let (encoded, _, _) = encoding_rs::EUC_KR.encode(&translated);
+                    output.write_all(&encoded)?;
+                }
+            }
+            output.write_all(b"\n")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// [`EzTransInner::translate_file_auto`]가 세그먼트 하나에 담을 수 있는 최대 UTF-16
+/// 코드 단위 수. 과거 고정 크기 버퍼 한도를 넘지 않도록 줄 경계에서 이보다 작은
+/// 세그먼트로 나눈다.
+const MAX_SEGMENT_CODE_UNITS: usize = 4096;
+
+fn utf16_len(s: &str) -> usize {
+    s.encode_utf16().count()
+}
+
+/// `text`를 줄 경계에서 최대한 묶어, 각 세그먼트가 `max_units` UTF-16 코드 단위를
+/// 넘지 않게 나눈다. 한 줄 자체가 `max_units`를 넘으면 그 줄만 따로
+/// [`split_long_line`]로 더 쪼갠다.
+fn split_into_segments(text: &str, max_units: usize) -> Vec<String> {
+    let mut segments = Vec::new();
+    let mut current = String::new();
+    let mut current_units = 0usize;
+
+    for line in text.lines() {
+        let line_units = utf16_len(line);
+
+        if line_units > max_units {
+            if !current.is_empty() {
+                segments.push(std::mem::take(&mut current));
+                current_units = 0;
+            }
+            segments.extend(split_long_line(line, max_units));
+            continue;
+        }
+
+        // 이미 담긴 줄 뒤에 붙일 때는 그 사이에 다시 붙일 개행 1개 몫이 더 든다.
+        if !current.is_empty() && current_units + 1 + line_units > max_units {
+            segments.push(std::mem::take(&mut current));
+            current_units = 0;
+        }
+
+        if !current.is_empty() {
+            current.push('\n');
+            current_units += 1;
+        }
+        current.push_str(line);
+        current_units += line_units;
+    }
+
+    if !current.is_empty() {
+        segments.push(current);
+    }
+
+    segments
+}
+
+/// 한 줄만으로도 `max_units`를 넘는 드문 경우, UTF-16 코드 단위 경계에서 그대로
+/// 잘라 낸다. 서로게이트 쌍 한가운데를 자를 수 있다는 점은 감수한다 — 이 정도로 긴
+/// 단일 줄은 애초에 사람이 읽는 자막/스크립트에서는 나오지 않는다고 보기 때문이다.
+fn split_long_line(line: &str, max_units: usize) -> Vec<String> {
+    let utf16: Vec<u16> = line.encode_utf16().collect();
+    utf16
+        .chunks(max_units)
+        .map(String::from_utf16_lossy)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_utf16_le_bom() {
+        let (text, encoding) = decode_detecting_encoding(&[0xFF, 0xFE, 0x41, 0x00]);
+        assert_eq!(text, "A");
+        assert_eq!(encoding, DetectedEncoding::Utf16Le);
+    }
+
+    #[test]
+    fn test_detects_utf16_be_bom() {
+        let (text, encoding) = decode_detecting_encoding(&[0xFE, 0xFF, 0x00, 0x41]);
+        assert_eq!(text, "A");
+        assert_eq!(encoding, DetectedEncoding::Utf16Be);
+    }
+
+    #[test]
+    fn test_detects_utf8_bom() {
+        let mut bytes = vec![0xEF, 0xBB, 0xBF];
+        bytes.extend_from_slice("안녕".as_bytes());
+        let (text, encoding) = decode_detecting_encoding(&bytes);
+        assert_eq!(text, "안녕");
+        assert_eq!(encoding, DetectedEncoding::Utf8Bom);
+    }
+
+    #[test]
+    fn test_bomless_valid_utf8_is_not_treated_as_shift_jis() {
+        let (text, encoding) = decode_detecting_encoding("こんにちは".as_bytes());
+        assert_eq!(text, "こんにちは");
+        assert_eq!(encoding, DetectedEncoding::Utf8);
+    }
+
+    #[test]
+    fn test_bomless_invalid_utf8_falls_back_to_shift_jis() {
+        // "こ" in Shift-JIS is 0x82 0xB1, which is not valid UTF-8.
+        let sjis_bytes = encoding_rs::SHIFT_JIS.encode("こんにちは").0.into_owned();
+        assert!(std::str::from_utf8(&sjis_bytes).is_err());
+
+        let (text, encoding) = decode_detecting_encoding(&sjis_bytes);
+        assert_eq!(text, "こんにちは");
+        assert_eq!(encoding, DetectedEncoding::ShiftJis);
+    }
+
+    #[test]
+    fn test_split_into_segments_packs_short_lines_together() {
+        let text = "one\ntwo\nthree";
+        let segments = split_into_segments(text, 4096);
+        assert_eq!(segments, vec!["one\ntwo\nthree".to_string()]);
+    }
+
+    #[test]
+    fn test_split_into_segments_breaks_at_line_boundary_when_limit_exceeded() {
+        let text = "aaaa\nbbbb\ncccc";
+        // "aaaa\n" (5) + "bbbb" (4) = 9 units fits in a 9-unit budget, but adding
+        // "cccc" would exceed it, so it starts a new segment.
+        let segments = split_into_segments(text, 9);
+        assert_eq!(segments, vec!["aaaa\nbbbb".to_string(), "cccc".to_string()]);
+    }
+
+    #[test]
+    fn test_split_into_segments_splits_a_single_overlong_line() {
+        let text = "abcdefgh";
+        let segments = split_into_segments(text, 3);
+        assert_eq!(segments, vec!["abc".to_string(), "def".to_string(), "gh".to_string()]);
+    }
+
+    #[test]
+    fn test_split_long_line_respects_max_units() {
+        let pieces = split_long_line("abcdefgh", 3);
+        assert_eq!(pieces, vec!["abc".to_string(), "def".to_string(), "gh".to_string()]);
+    }
+
+    #[test]
+    fn test_utf16_len_counts_surrogate_pairs_as_two_units() {
+        assert_eq!(utf16_len("a"), 1);
+        assert_eq!(utf16_len("😀"), 2);
+    }
+}
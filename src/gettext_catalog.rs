@@ -0,0 +1,358 @@
+// gettext MO 카탈로그(.mo)를 읽어 각 msgid를 엔진으로 번역하고, msgstr을 채운 새
+// 카탈로그를 .po/.mo로 내보낸다. `file_translate.rs`가 자유 형식 텍스트를 다루는 것과
+// 달리, 이쪽은 소프트웨어/게임 리소스 문자열 테이블처럼 이미 키-값으로 정리된 catalog를
+// 현지화하는 용도다.
+//
+// MO 포맷: 4바이트 매직으로 나머지 전부를 읽을 엔디안을 정하고(`0x950412de`면
+// 리틀 엔디안, 그 값을 바이트 스왑한 `0xde120495`면 빅 엔디안), 버전 워드가 0인지
+// 확인한 뒤, 문자열 개수와 원본/번역 테이블의 오프셋을 읽는다. 각 테이블은
+// `(length: u32, offset: u32)` 쌍이 `count`개 있고, 그 오프셋이 가리키는 자리에
+// NUL로 끝나는 UTF-8 문자열이 있다. 복수형 항목은 한 레코드 안에 NUL로 구분된 여러
+// 형태가 그대로 들어 있다 — 이 모듈은 그 NUL을 들여다보지 않고 레코드 전체를 하나의
+// 문자열로 다룬다.
+
+use crate::{EzTransError, EzTransInner};
+
+const MAGIC_LE: u32 = 0x9504_12de;
+const MAGIC_BE: u32 = 0xde12_0495;
+const HEADER_LEN: u32 = 28;
+
+fn catalog_error(msg: impl std::fmt::Display) -> EzTransError {
+    EzTransError::FunctionLoadError(format!("잘못된 MO 카탈로그: {msg}"))
+}
+
+/// 카탈로그에 실린 원문/번역문 한 쌍. 복수형 항목은 `msgid`/`msgstr` 안에 NUL(`\0`)로
+/// 구분된 여러 형태가 그대로 담겨 있다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CatalogEntry {
+    pub msgid: String,
+    pub msgstr: String,
+}
+
+/// 파싱된 MO 카탈로그. [`MoCatalog::entries`]로 `(msgid, msgstr)` 쌍을 순회한다.
+#[derive(Debug, Clone, Default)]
+pub struct MoCatalog {
+    entries: Vec<CatalogEntry>,
+}
+
+impl MoCatalog {
+    /// MO 바이너리를 파싱한다. 매직으로 엔디안을 판정하고, 버전 워드가 0인지, 원본/번역
+    /// 테이블이 파일 길이 안에 들어오는지 확인한다.
+    pub fn parse(data: &[u8]) -> Result<Self, EzTransError> {
+        if (data.len() as u64) < HEADER_LEN as u64 {
+            return Err(catalog_error("헤더를 담기에 파일이 너무 짧습니다"));
+        }
+
+        // 항상 리틀 엔디안으로 먼저 읽어 본다. 파일이 실제로 빅 엔디안이면 이 값은
+        // 매직을 바이트 스왑한 값과 같게 나온다.
+        let magic_le = u32::from_le_bytes(data[0..4].try_into().unwrap());
+        let little = if magic_le == MAGIC_LE {
+            true
+        } else if magic_le == MAGIC_BE {
+            false
+        } else {
+            return Err(catalog_error(format!("매직 넘버가 일치하지 않습니다: {magic_le:#x}")));
+        };
+
+        let read_u32 = |offset: usize| -> u32 {
+            let bytes: [u8; 4] = data[offset..offset + 4].try_into().unwrap();
+            if little { u32::from_le_bytes(bytes) } else { u32::from_be_bytes(bytes) }
+        };
+
+        let version = read_u32(4);
+        if version != 0 {
+            return Err(catalog_error(format!("지원하지 않는 버전입니다: {version}")));
+        }
+
+        let count = read_u32(8) as usize;
+        let orig_pos = read_u32(12) as usize;
+        let trans_pos = read_u32(16) as usize;
+
+        let table_len = count
+            .checked_mul(8)
+            .ok_or_else(|| catalog_error("문자열 개수가 너무 큽니다"))?;
+        let max_table_end = orig_pos
+            .max(trans_pos)
+            .checked_add(table_len)
+            .ok_or_else(|| catalog_error("테이블 오프셋 계산이 넘칩니다"))?;
+        if data.len() < max_table_end {
+            return Err(catalog_error("원본/번역 테이블이 파일 길이를 벗어납니다"));
+        }
+
+        let mut entries = Vec::with_capacity(count);
+        for i in 0..count {
+            let msgid = Self::read_record(data, read_u32, orig_pos, i)?;
+            let msgstr = Self::read_record(data, read_u32, trans_pos, i)?;
+            entries.push(CatalogEntry { msgid, msgstr });
+        }
+
+        Ok(Self { entries })
+    }
+
+    /// `table_pos`에서 `index`번째 `(length, offset)` 쌍을 읽고, 그 오프셋이 가리키는
+    /// `length`바이트를 UTF-8 문자열로 디코딩한다.
+    fn read_record(
+        data: &[u8],
+        read_u32: impl Fn(usize) -> u32,
+        table_pos: usize,
+        index: usize,
+    ) -> Result<String, EzTransError> {
+        let entry_pos = table_pos + index * 8;
+        let len = read_u32(entry_pos) as usize;
+        let offset = read_u32(entry_pos + 4) as usize;
+
+        let end = offset
+            .checked_add(len)
+            .ok_or_else(|| catalog_error("문자열 오프셋 계산이 넘칩니다"))?;
+        let bytes = data
+            .get(offset..end)
+            .ok_or_else(|| catalog_error("문자열이 파일 길이를 벗어납니다"))?;
+
+        std::str::from_utf8(bytes)
+            .map(str::to_string)
+            .map_err(|e| catalog_error(format!("UTF-8 디코딩 실패: {e}")))
+    }
+
+    /// `(msgid, msgstr)` 쌍을 순서대로 순회한다.
+    pub fn entries(&self) -> impl Iterator<Item = &CatalogEntry> {
+        self.entries.iter()
+    }
+
+    /// 등록된 항목 개수.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// 항목이 하나도 없는지.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// gettext PO 텍스트 포맷으로 직렬화한다.
+    pub fn to_po(&self) -> String {
+        let mut out = String::new();
+        for entry in &self.entries {
+            out.push_str("msgid ");
+            out.push_str(&po_quote(&entry.msgid));
+            out.push('\n');
+            out.push_str("msgstr ");
+            out.push_str(&po_quote(&entry.msgstr));
+            out.push_str("\n\n");
+        }
+        out
+    }
+
+    /// gettext MO 바이너리 포맷(리틀 엔디안)으로 직렬화한다.
+    ///
+    /// 원문을 정렬하지 않고 파싱 때 읽은 순서 그대로 내보내고, 해시 테이블은 크기
+    /// 0으로 생략한다 — `Self::parse`가 순차 테이블만으로 읽어 들이므로 이 모듈이 쓴
+    /// 카탈로그는 이 모듈로 다시 읽는 용도에는 문제가 없지만, 이진 탐색/해시 조회에
+    /// 의존하는 gettext 런타임 구현에서는 원문이 정렬돼 있어야 할 수 있다.
+    pub fn to_mo(&self) -> Vec<u8> {
+        let count = self.entries.len() as u32;
+        let orig_table_pos = HEADER_LEN;
+        let trans_table_pos = orig_table_pos + count * 8;
+        let strings_pos = trans_table_pos + count * 8;
+
+        let mut orig_table = Vec::new();
+        let mut trans_table = Vec::new();
+        let mut orig_strings = Vec::new();
+        let mut trans_strings = Vec::new();
+
+        let mut offset = strings_pos;
+        for entry in &self.entries {
+            let bytes = entry.msgid.as_bytes();
+            orig_table.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+            orig_table.extend_from_slice(&offset.to_le_bytes());
+            orig_strings.extend_from_slice(bytes);
+            orig_strings.push(0);
+            offset += bytes.len() as u32 + 1;
+        }
+        for entry in &self.entries {
+            let bytes = entry.msgstr.as_bytes();
+            trans_table.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+            trans_table.extend_from_slice(&offset.to_le_bytes());
+            trans_strings.extend_from_slice(bytes);
+            trans_strings.push(0);
+            offset += bytes.len() as u32 + 1;
+        }
+
+        let mut out = Vec::with_capacity(offset as usize);
+        out.extend_from_slice(&MAGIC_LE.to_le_bytes());
+        out.extend_from_slice(&0u32.to_le_bytes()); // version
+        out.extend_from_slice(&count.to_le_bytes());
+        out.extend_from_slice(&orig_table_pos.to_le_bytes());
+        out.extend_from_slice(&trans_table_pos.to_le_bytes());
+        out.extend_from_slice(&0u32.to_le_bytes()); // hash table size
+        out.extend_from_slice(&strings_pos.to_le_bytes()); // hash table offset (미사용, 크기 0)
+        out.extend_from_slice(&orig_table);
+        out.extend_from_slice(&trans_table);
+        out.extend_from_slice(&orig_strings);
+        out.extend_from_slice(&trans_strings);
+        out
+    }
+}
+
+/// PO 포맷의 큰따옴표로 감싼 문자열 리터럴로 이스케이프한다.
+fn po_quote(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            // PO는 문자열 리터럴 안에 실제 NUL을 허용하지 않으므로, 복수형 레코드의
+            // 구분자를 8진 이스케이프로 남겨 둔다.
+            '\0' => out.push_str("\\000"),
+            _ => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// `catalog`의 각 항목을 번역해 `msgstr`을 채운 새 카탈로그를 만든다. 빈 `msgid`(관례상
+/// 카탈로그 메타데이터를 담는 헤더 항목)는 번역하지 않고 그대로 둔다.
+pub fn translate_catalog(
+    engine: &EzTransInner,
+    catalog: &MoCatalog,
+) -> Result<MoCatalog, EzTransError> {
+    let mut entries = Vec::with_capacity(catalog.entries.len());
+    for entry in catalog.entries() {
+        if entry.msgid.is_empty() {
+            entries.push(entry.clone());
+            continue;
+        }
+
+        let msgstr = engine.default_translate(&entry.msgid)?;
+        entries.push(CatalogEntry {
+            msgid: entry.msgid.clone(),
+            msgstr,
+        });
+    }
+
+    Ok(MoCatalog { entries })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 주어진 엔디안으로 최소한의 MO 바이너리를 직접 조립한다(해시 테이블은 크기 0).
+    /// `Self::to_mo`는 리틀 엔디안만 만들어 내므로, 빅 엔디안 분기를 exercise하려면
+    /// 직접 바이트를 구성해야 한다.
+    fn build_mo_bytes(entries: &[(&str, &str)], little: bool) -> Vec<u8> {
+        let write_u32 = |v: u32| -> [u8; 4] {
+            if little { v.to_le_bytes() } else { v.to_be_bytes() }
+        };
+
+        let count = entries.len() as u32;
+        let orig_table_pos = HEADER_LEN;
+        let trans_table_pos = orig_table_pos + count * 8;
+        let strings_pos = trans_table_pos + count * 8;
+
+        let mut orig_table = Vec::new();
+        let mut trans_table = Vec::new();
+        let mut orig_strings = Vec::new();
+        let mut trans_strings = Vec::new();
+        let mut offset = strings_pos;
+
+        for (msgid, _) in entries {
+            let bytes = msgid.as_bytes();
+            orig_table.extend_from_slice(&write_u32(bytes.len() as u32));
+            orig_table.extend_from_slice(&write_u32(offset));
+            orig_strings.extend_from_slice(bytes);
+            orig_strings.push(0);
+            offset += bytes.len() as u32 + 1;
+        }
+        for (_, msgstr) in entries {
+            let bytes = msgstr.as_bytes();
+            trans_table.extend_from_slice(&write_u32(bytes.len() as u32));
+            trans_table.extend_from_slice(&write_u32(offset));
+            trans_strings.extend_from_slice(bytes);
+            trans_strings.push(0);
+            offset += bytes.len() as u32 + 1;
+        }
+
+        let magic = if little { MAGIC_LE } else { MAGIC_BE };
+        let mut out = Vec::new();
+        out.extend_from_slice(&magic.to_le_bytes());
+        out.extend_from_slice(&write_u32(0)); // version
+        out.extend_from_slice(&write_u32(count));
+        out.extend_from_slice(&write_u32(orig_table_pos));
+        out.extend_from_slice(&write_u32(trans_table_pos));
+        out.extend_from_slice(&write_u32(0)); // hash table size
+        out.extend_from_slice(&write_u32(strings_pos)); // hash table offset (unused)
+        out.extend_from_slice(&orig_table);
+        out.extend_from_slice(&trans_table);
+        out.extend_from_slice(&orig_strings);
+        out.extend_from_slice(&trans_strings);
+        out
+    }
+
+    #[test]
+    fn parse_little_endian_mo() {
+        let data = build_mo_bytes(&[("", "header"), ("猫", "고양이")], true);
+        let catalog = MoCatalog::parse(&data).unwrap();
+        assert_eq!(catalog.len(), 2);
+        assert_eq!(catalog.entries().nth(1).unwrap().msgstr, "고양이");
+    }
+
+    #[test]
+    fn parse_big_endian_mo() {
+        let data = build_mo_bytes(&[("猫", "고양이"), ("犬", "개")], false);
+        let catalog = MoCatalog::parse(&data).unwrap();
+        assert_eq!(catalog.len(), 2);
+        assert_eq!(catalog.entries().next().unwrap().msgid, "猫");
+        assert_eq!(catalog.entries().nth(1).unwrap().msgstr, "개");
+    }
+
+    #[test]
+    fn parse_rejects_bad_magic() {
+        let mut data = build_mo_bytes(&[("猫", "고양이")], true);
+        data[0] = 0x00;
+        assert!(MoCatalog::parse(&data).is_err());
+    }
+
+    #[test]
+    fn parse_rejects_unsupported_version() {
+        let mut data = build_mo_bytes(&[("猫", "고양이")], true);
+        data[4..8].copy_from_slice(&1u32.to_le_bytes());
+        assert!(MoCatalog::parse(&data).is_err());
+    }
+
+    #[test]
+    fn parse_rejects_truncated_file() {
+        let data = build_mo_bytes(&[("猫", "고양이"), ("犬", "개")], true);
+        let truncated = &data[..data.len() / 2];
+        assert!(MoCatalog::parse(truncated).is_err());
+    }
+
+    #[test]
+    fn to_po_escapes_special_characters() {
+        let catalog = MoCatalog {
+            entries: vec![CatalogEntry {
+                msgid: "a\"b\\c\nd\te\0f".to_string(),
+                msgstr: "번역".to_string(),
+            }],
+        };
+        let po = catalog.to_po();
+        assert!(po.contains(r#"msgid "a\"b\\c\nd\te\000f""#));
+        assert!(po.contains(r#"msgstr "번역""#));
+    }
+
+    #[test]
+    fn to_mo_round_trips_through_parse() {
+        let catalog = MoCatalog {
+            entries: vec![
+                CatalogEntry { msgid: "".to_string(), msgstr: "".to_string() },
+                CatalogEntry { msgid: "猫".to_string(), msgstr: "고양이".to_string() },
+            ],
+        };
+        let bytes = catalog.to_mo();
+        let parsed = MoCatalog::parse(&bytes).unwrap();
+        assert_eq!(parsed.entries, catalog.entries);
+    }
+}
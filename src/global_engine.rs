@@ -0,0 +1,79 @@
+// 프로세스 전역 싱글턴 엔진.
+//
+// 테스트 6/7이 확인했듯 J2K DLL은 프로세스 전역 상태를 갖는다: `LoadLibrary`는 두 번째
+// 호출에도 같은 `HMODULE`을 돌려주고, 두 번째 `initialize_ex`는 첫 번째 인스턴스를
+// 덮어쓴다. 이 모듈은 그 사실과 싸우는 대신 받아들인다 — 프로세스 안에 엔진이 딱 하나만
+// 살아있게 강제하는 싱글턴을 둔다.
+//
+// `bmp_set::BMP_SET`처럼 `OnceLock`으로 전역 슬롯을 한 번만 만들되, 그 슬롯 안에는
+// `Weak` 참조를 넣어 둔다. 이렇게 하면 첫 `get()` 호출(또는 마지막 핸들이 사라진 뒤의
+// 다음 `get()` 호출)이 `LoadLibrary` + `initialize_ex`를 수행하는 동안 잠금을 쥐고
+// 있으므로 동시에 들어온 다른 호출은 그 잠금에서 자연히 블록되고, 초기화가 끝나면 모두
+// 같은 `Arc`를 공유한다. 마지막 핸들이 떨어질 때만 `terminate`가 호출된다.
+
+use std::path::Path;
+use std::sync::{Arc, Mutex, OnceLock, Weak};
+
+use crate::{EzTransEngine, EzTransError};
+
+/// 마지막 클론이 드롭될 때 DLL의 `terminate`를 한 번만 호출하기 위한 래퍼.
+struct TerminatingEngine(EzTransEngine);
+
+impl Drop for TerminatingEngine {
+    fn drop(&mut self) {
+        let _ = self.0.terminate();
+    }
+}
+
+struct GlobalState {
+    slot: Mutex<Weak<TerminatingEngine>>,
+}
+
+static GLOBAL: OnceLock<GlobalState> = OnceLock::new();
+
+fn global() -> &'static GlobalState {
+    GLOBAL.get_or_init(|| GlobalState {
+        slot: Mutex::new(Weak::new()),
+    })
+}
+
+/// 프로세스 전역 엔진을 가리키는, 값싸게 `Clone`할 수 있는 핸들.
+#[derive(Clone)]
+pub struct GlobalEngine {
+    engine: Arc<TerminatingEngine>,
+}
+
+impl GlobalEngine {
+    /// 전역 엔진을 얻는다. 이미 살아있는 인스턴스가 있으면 그 핸들을 복제해 돌려주고,
+    /// 없으면(최초 호출이거나 마지막 핸들이 드롭된 뒤) `dll_path`/`dat_path`로 새로
+    /// 로드 + 초기화한 뒤 돌려준다. 초기화가 끝날 때까지 이 잠금을 쥐고 있으므로,
+    /// 동시에 호출한 다른 스레드는 그 동안 자연히 블록된다.
+    pub fn get<P: AsRef<Path>, Q: AsRef<Path>>(
+        dll_path: P,
+        dat_path: Q,
+    ) -> Result<Self, EzTransError> {
+        let mut slot = global().slot.lock().unwrap();
+
+        if let Some(engine) = slot.upgrade() {
+            return Ok(Self { engine });
+        }
+
+        let engine = EzTransEngine::new(dll_path.as_ref())?;
+        let dat_path_str = dat_path.as_ref().to_str().ok_or(EzTransError::InvalidPath)?;
+        engine.initialize_ex("CSUSER123455", dat_path_str)?;
+
+        let engine = Arc::new(TerminatingEngine(engine));
+        *slot = Arc::downgrade(&engine);
+
+        Ok(Self { engine })
+    }
+
+    /// 현재 살아있는 핸들 수(이 전역 엔진을 참조하고 있는 소유자 수)를 돌려준다.
+    pub fn handle_count(&self) -> usize {
+        Arc::strong_count(&self.engine)
+    }
+
+    pub fn translate(&self, text: &str) -> Result<String, EzTransError> {
+        self.engine.0.translate_mmnt(text)
+    }
+}
@@ -0,0 +1,212 @@
+// 용어집(Glossary) 기반 번역 전/후 치환
+//
+// `J2KEngine`은 고유 명사나 UI 문자열을 엔진 사전에 등록된 뜻대로 의역/오역해버리는 경우가
+// 많다. 이를 막기 위해 번역 전 단계에서 원문 용어를 센티넬 토큰으로 바꿔 엔진이 건드리지
+// 못하게 보호하고, 번역 후 단계에서 센티넬을 지정된 한국어 치환어로 되돌린다.
+
+use std::collections::{HashMap, VecDeque};
+
+/// 용어집에 등록된 단일 치환 규칙 (원문 -> 치환어)
+#[derive(Debug, Clone)]
+struct Term {
+    source: Vec<char>,
+    replacement: String,
+}
+
+#[derive(Debug, Default)]
+struct TrieNode {
+    children: HashMap<char, usize>,
+    fail: usize,
+    /// 이 노드에서 끝나는 용어의 인덱스 (해당 노드가 정확히 어떤 용어의 끝일 때만 Some).
+    output: Option<usize>,
+    /// fail 체인을 따라가며 만나는 가장 가까운 출력 노드. 없으면 None.
+    output_link: Option<usize>,
+}
+
+/// 용어집을 하나의 Aho-Corasick 오토마톤으로 빌드하여 단일 패스로 매칭한다.
+pub struct Glossary {
+    terms: Vec<Term>,
+    nodes: Vec<TrieNode>,
+}
+
+/// 번역 엔진이 절대 생성하지 않을 전용 구역(Private Use Area) 문자로 센티넬을 감싼다.
+const SENTINEL_OPEN: char = '\u{E000}';
+const SENTINEL_CLOSE: char = '\u{E001}';
+
+impl Glossary {
+    /// `source<TAB>replacement` 형식의 줄들로 이루어진 용어집 본문을 파싱하여 빌드한다.
+    /// 빈 줄이나 탭이 없는 줄, 원문이 비어있는 줄은 무시한다.
+    pub fn parse(body: &str) -> Self {
+        let terms: Vec<Term> = body
+            .lines()
+            .filter_map(|line| {
+                let mut parts = line.splitn(2, '\t');
+                let source = parts.next()?.trim();
+                let replacement = parts.next()?.trim();
+                if source.is_empty() {
+                    return None;
+                }
+                Some(Term {
+                    source: source.chars().collect(),
+                    replacement: replacement.to_string(),
+                })
+            })
+            .collect();
+
+        Self::build(terms)
+    }
+
+    fn build(terms: Vec<Term>) -> Self {
+        let mut nodes = vec![TrieNode::default()]; // root = 0
+
+        for (idx, term) in terms.iter().enumerate() {
+            let mut cur = 0;
+            for &ch in &term.source {
+                cur = *nodes[cur].children.entry(ch).or_insert_with(|| {
+                    nodes.push(TrieNode::default());
+                    nodes.len() - 1
+                });
+            }
+            nodes[cur].output = Some(idx);
+        }
+
+        // BFS로 실패 링크(fail)와 출력 링크(output_link)를 구성한다.
+        let mut queue = VecDeque::new();
+        for &child in nodes[0].children.values() {
+            nodes[child].fail = 0;
+            queue.push_back(child);
+        }
+
+        while let Some(cur) = queue.pop_front() {
+            let children: Vec<(char, usize)> =
+                nodes[cur].children.iter().map(|(&c, &n)| (c, n)).collect();
+
+            for (ch, child) in children {
+                let mut fail = nodes[cur].fail;
+                while fail != 0 && !nodes[fail].children.contains_key(&ch) {
+                    fail = nodes[fail].fail;
+                }
+                let fail = nodes[fail]
+                    .children
+                    .get(&ch)
+                    .copied()
+                    .filter(|&n| n != child)
+                    .unwrap_or(0);
+
+                nodes[child].fail = fail;
+                nodes[child].output_link = if nodes[fail].output.is_some() {
+                    Some(fail)
+                } else {
+                    nodes[fail].output_link
+                };
+
+                queue.push_back(child);
+            }
+        }
+
+        Self { terms, nodes }
+    }
+
+    /// 입력을 한 번 훑으면서 등록된 용어를 찾아 센티넬 토큰으로 치환한다. (번역 전 단계)
+    ///
+    /// 매 위치에서 가장 긴 매치를 우선하고(leftmost-longest), 매치가 끝난 지점부터 스캔을
+    /// 재개하여 치환 결과끼리 겹치지 않게 한다.
+    pub fn protect(&self, input: &str) -> String {
+        if self.terms.is_empty() {
+            return input.to_string();
+        }
+
+        let chars: Vec<char> = input.chars().collect();
+        let mut out = String::with_capacity(input.len());
+        let mut flushed = 0usize; // chars[flushed..] 중 아직 out에 쓰이지 않은 구간의 시작
+        let mut state = 0usize;
+
+        let mut matches = Vec::new(); // (시작, 끝, 용어 인덱스)
+        for (i, &ch) in chars.iter().enumerate() {
+            state = self.goto(state, ch);
+            let mut node = Some(state);
+            while let Some(n) = node {
+                if let Some(idx) = self.nodes[n].output {
+                    matches.push((i + 1 - self.terms[idx].source.len(), i + 1, idx));
+                }
+                node = self.nodes[n].output_link;
+            }
+        }
+
+        // 시작이 가장 앞선 매치부터, 시작이 같으면 가장 긴 매치부터 고르고, 이미 고른
+        // 매치와 겹치는 것은 건너뛴다.
+        matches.sort_by_key(|&(start, end, _)| (start, std::cmp::Reverse(end)));
+        for (start, end, idx) in matches {
+            if start >= flushed {
+                out.extend(&chars[flushed..start]);
+                out.push(SENTINEL_OPEN);
+                out.push_str(&idx.to_string());
+                out.push(SENTINEL_CLOSE);
+                flushed = end;
+            }
+        }
+
+        out.extend(&chars[flushed..]);
+        out
+    }
+
+    /// 센티넬 토큰을 원래 등록된 치환어로 되돌린다. (번역 후 단계)
+    pub fn restore(&self, input: &str) -> String {
+        if self.terms.is_empty() {
+            return input.to_string();
+        }
+
+        let mut out = String::with_capacity(input.len());
+        let mut chars = input.chars().peekable();
+
+        while let Some(ch) = chars.next() {
+            if ch != SENTINEL_OPEN {
+                out.push(ch);
+                continue;
+            }
+
+            let digits: String = chars
+                .by_ref()
+                .take_while(|&c| c != SENTINEL_CLOSE)
+                .collect();
+            match digits.parse::<usize>().ok().and_then(|i| self.terms.get(i)) {
+                Some(term) => out.push_str(&term.replacement),
+                None => {
+                    // 파싱할 수 없는 토큰이면 원본 그대로 보존한다.
+                    out.push(SENTINEL_OPEN);
+                    out.push_str(&digits);
+                    out.push(SENTINEL_CLOSE);
+                }
+            }
+        }
+
+        out
+    }
+
+    fn goto(&self, mut state: usize, ch: char) -> usize {
+        loop {
+            if let Some(&next) = self.nodes[state].children.get(&ch) {
+                return next;
+            }
+            if state == 0 {
+                return 0;
+            }
+            state = self.nodes[state].fail;
+        }
+    }
+
+    /// `state`에서 끝나는 매치 중 가장 긴 것(자기 자신의 출력 우선)을 반환한다.
+    fn longest_match_at(&self, state: usize) -> Option<usize> {
+        let node = &self.nodes[state];
+        node.output.or_else(|| {
+            let mut link = node.output_link;
+            while let Some(n) = link {
+                if let Some(idx) = self.nodes[n].output {
+                    return Some(idx);
+                }
+                link = self.nodes[n].output_link;
+            }
+            None
+        })
+    }
+}
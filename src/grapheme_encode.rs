@@ -0,0 +1,188 @@
+// 이모지 등 여러 코드포인트로 이뤄진 자소 클러스터(grapheme cluster)를 통째로
+// 보호하는 인코더.
+//
+// 기존 `hangul_encode`/`safe_translate`의 `+Xhhhhhh`류 이스케이프는 코드포인트
+// 하나씩 독립적으로 치환한다. ZWJ로 묶인 이모지(`👨‍👩‍👧`)나 국기(`🇰🇷`), 피부톤
+// 변경자(`👋🏻`)처럼 여러 코드포인트가 한 덩어리로 붙어 있어야 하는 시퀀스를
+// 코드포인트 단위로 쪼개 이스케이프하면, 엔진이 그 사이에 공백을 넣거나 순서를 바꿔
+// 클러스터가 깨질 위험이 있다(UAX #29). 이 모듈은 확장 자소 클러스터 단위로 나눠,
+// 여러 코드포인트로 이뤄졌거나 `is_safe_chars`를 통과하지 못하는 클러스터 전체를
+// 고정폭 ASCII 센티널(`QZ0001QZ` 형태)로 치환해 두고, 번역이 끝난 뒤 원래 클러스터로
+// 복원한다.
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::char_ranges::is_safe_chars;
+use crate::emoji_table;
+use crate::{EzTransError, EzTransInner};
+
+const SENTINEL_PREFIX: &str = "QZ";
+const SENTINEL_SUFFIX: &str = "QZ";
+/// 센티널 안의 숫자 자릿수. `QZ0001QZ`처럼 4자리 0-패딩 고정폭으로 맞춘다.
+const SENTINEL_DIGITS: usize = 4;
+
+/// 클러스터 하나를 통째로 보호해야 하는지 판정한다: 코드포인트가 둘 이상이면 무조건
+/// 보호 대상이고, 코드포인트가 하나뿐이라도 `is_safe_chars`를 통과하지 못하면 역시
+/// 보호 대상이다.
+fn needs_protection(cluster: &str) -> bool {
+    let mut chars = cluster.chars();
+    match (chars.next(), chars.next()) {
+        (Some(_), Some(_)) => true,
+        (Some(c), None) => !is_safe_chars(c),
+        (None, _) => false,
+    }
+}
+
+fn format_sentinel(index: usize) -> String {
+    format!("{SENTINEL_PREFIX}{:0width$}{SENTINEL_SUFFIX}", index, width = SENTINEL_DIGITS)
+}
+
+/// 보호된 클러스터가 `emoji_table`(build.rs가 `data/emoji-test.txt`로부터 생성)에
+/// 등록된 *알려진* 이모지 시퀀스인지 확인해, 그렇다면 그 그룹/서브그룹 메타데이터를
+/// 돌려준다. 여러 코드포인트로 이뤄진 클러스터라고 해서 전부 의미 있는 이모지
+/// 시퀀스인 것은 아니므로(우연히 옆에 붙은 결합 문자 등), 이 함수는 호출자가 "진짜
+/// 이모지 시퀀스"와 "그 외 보호 대상"을 구분하거나 카테고리로 걸러내고 싶을 때 쓴다.
+/// `encode`/`decode`의 보호·복원 동작 자체는 이 판정과 무관하게 그대로 동작한다.
+pub fn classify_protected_cluster(cluster: &str) -> Option<(&'static str, &'static str)> {
+    let codepoints: Vec<u32> = cluster.chars().map(|c| c as u32).collect();
+    emoji_table::classify_sequence(&codepoints)
+}
+
+/// `encode`의 결과: 센티널로 치환된 텍스트와, 인덱스로 복원할 수 있는 원본 클러스터
+/// 표.
+pub struct GraphemeEncoded {
+    pub text: String,
+    clusters: Vec<String>,
+}
+
+/// `input`을 확장 자소 클러스터 단위로 나눠, 보호가 필요한 클러스터를 센티널로
+/// 바꾼다. 입력 안에 센티널 알파벳(`QZ`)이 리터럴로 들어 있는 클러스터도 보호
+/// 대상으로 취급되어, 복원 시 다른 센티널과 충돌하지 않고 고유한 슬롯에서 그대로
+/// 되돌아온다.
+pub fn encode(input: &str) -> GraphemeEncoded {
+    let mut clusters = Vec::new();
+    let mut text = String::with_capacity(input.len());
+
+    for grapheme in input.graphemes(true) {
+        if needs_protection(grapheme) || grapheme.contains(SENTINEL_PREFIX) {
+            let index = clusters.len();
+            clusters.push(grapheme.to_string());
+            text.push_str(&format_sentinel(index));
+        } else {
+            text.push_str(grapheme);
+        }
+    }
+
+    GraphemeEncoded { text, clusters }
+}
+
+impl GraphemeEncoded {
+    /// 번역된 텍스트에서 센티널을 찾아 원래 클러스터로 되돌린다. 숫자가 알려진
+    /// 클러스터 개수를 벗어나거나 형식이 깨진 `QZ...QZ`는 손대지 않고 그대로 둔다.
+    pub fn decode(&self, translated: &str) -> String {
+        let mut result = String::with_capacity(translated.len());
+        let mut rest = translated;
+
+        while let Some(start) = rest.find(SENTINEL_PREFIX) {
+            result.push_str(&rest[..start]);
+            let after_prefix = &rest[start + SENTINEL_PREFIX.len()..];
+            let digits_end = SENTINEL_DIGITS;
+            let suffix_end = digits_end + SENTINEL_SUFFIX.len();
+
+            let parsed = after_prefix
+                .get(..suffix_end)
+                .filter(|s| s[..digits_end].bytes().all(|b| b.is_ascii_digit()))
+                .filter(|s| &s[digits_end..] == SENTINEL_SUFFIX)
+                .and_then(|s| s[..digits_end].parse::<usize>().ok());
+
+            match parsed.and_then(|index| self.clusters.get(index)) {
+                Some(cluster) => {
+                    result.push_str(cluster);
+                    rest = &after_prefix[suffix_end..];
+                }
+                None => {
+                    result.push_str(SENTINEL_PREFIX);
+                    rest = after_prefix;
+                }
+            }
+        }
+        result.push_str(rest);
+        result
+    }
+}
+
+impl EzTransInner {
+    /// 자소 클러스터를 쪼개지 않도록 보호한 채 번역한다. 이모지 ZWJ 시퀀스, 국기,
+    /// 피부톤 변경자 등 여러 코드포인트로 이뤄진 클러스터가 번역 중간에 분리되지
+    /// 않도록 보장하고 싶을 때 [`default_translate`](Self::default_translate) 대신
+    /// 쓴다.
+    pub fn translate_grapheme_safe(&self, input: &str) -> Result<String, EzTransError> {
+        let encoded = encode(input);
+        let translated = self.default_translate(&encoded.text)?;
+        Ok(encoded.decode(&translated))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zwj_family_emoji_round_trips_through_identity_translation() {
+        let input = format!("앞{}뒤", "👨‍👩‍👧");
+        let encoded = encode(&input);
+        assert_eq!(encoded.clusters.len(), 1);
+        // 엔진을 거치지 않고 센티널이 그대로 통과했다고 가정한 번역을 흉내 낸다.
+        assert_eq!(encoded.decode(&encoded.text), input);
+    }
+
+    #[test]
+    fn test_flag_sequence_round_trips() {
+        let input = "🇰🇷";
+        let encoded = encode(input);
+        assert_eq!(encoded.clusters.len(), 1);
+        assert_eq!(encoded.decode(&encoded.text), input);
+    }
+
+    #[test]
+    fn test_skin_tone_modifier_round_trips() {
+        let input = "👋🏻";
+        let encoded = encode(input);
+        assert_eq!(encoded.clusters.len(), 1);
+        assert_eq!(encoded.decode(&encoded.text), input);
+    }
+
+    #[test]
+    fn test_sentinel_emitted_is_one_contiguous_ascii_token() {
+        let encoded = encode("👨‍👩‍👧");
+        assert_eq!(encoded.text, "QZ0000QZ");
+    }
+
+    #[test]
+    fn test_literal_sentinel_like_text_in_input_is_protected_too() {
+        let input = "QZ0000QZ";
+        let encoded = encode(input);
+        // 리터럴 "QZ0000QZ"도 그 자체로 보호 대상이 되어 고유한 슬롯을 받는다.
+        assert_eq!(encoded.clusters.len(), input.chars().count());
+        assert_eq!(encoded.decode(&encoded.text), input);
+    }
+
+    #[test]
+    fn test_classify_protected_cluster_recognizes_known_zwj_family() {
+        let (group, subgroup) = classify_protected_cluster("👨‍👩‍👧").expect("알려진 시퀀스여야 합니다");
+        assert_eq!(group, "People & Body");
+        assert_eq!(subgroup, "family");
+    }
+
+    #[test]
+    fn test_classify_protected_cluster_unknown_sequence_is_none() {
+        assert_eq!(classify_protected_cluster("ab"), None);
+    }
+
+    #[test]
+    fn test_plain_ascii_is_left_untouched() {
+        let encoded = encode("hello world");
+        assert!(encoded.clusters.is_empty());
+        assert_eq!(encoded.text, "hello world");
+    }
+}
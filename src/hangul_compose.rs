@@ -0,0 +1,127 @@
+// 분해된 한글 자모(U+1100~11FF 범위의 초성/중성/종성) 시퀀스를 완성형 음절
+// (U+AC00~U+D7A3)로 조합하는, 유니코드 표준 한글 합성 알고리즘의 자체 구현.
+//
+// `unicode_normalization` 크레이트의 NFC도 이 조합을 포함하지만, 한글 합성만 따로
+// 떼어내 검증 가능한 형태로 둬야 `tests/hangul_*` 류 스캔 테스트가 "왜 분해된 자모가
+// `translate_mmntw`를 깨뜨리는지"와 "그 조합 알고리즘이 정확히 무엇을 하는지"를 각각
+// 독립적으로 확인할 수 있다. Latin 쪽 NFC/NFKC는 계속 `normalize::NormalizationMode`가
+// 크레이트에 위임한다 — 이 모듈은 한글 조합이라는 좁은 문제만 self-contained하게
+// 다룬다.
+
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT; // 588
+const S_COUNT: u32 = L_COUNT * N_COUNT; // 11172
+
+fn is_leading(cp: u32) -> bool {
+    (L_BASE..L_BASE + L_COUNT).contains(&cp)
+}
+
+fn is_vowel(cp: u32) -> bool {
+    (V_BASE..V_BASE + V_COUNT).contains(&cp)
+}
+
+/// `T_BASE`(0x11A7) 자체는 "종성 없음"을 뜻하므로 실제 종성 범위에서는 제외한다.
+fn is_trailing(cp: u32) -> bool {
+    cp > T_BASE && cp < T_BASE + T_COUNT
+}
+
+/// 이미 조합된 음절 `s`가 종성 없이 초성+중성만으로 이뤄진 LV 음절인지(그래서 뒤따르는
+/// 종성 하나를 더 흡수할 수 있는지) 확인한다.
+fn is_lv_syllable(s: u32) -> bool {
+    (S_BASE..S_BASE + S_COUNT).contains(&s) && (s - S_BASE) % T_COUNT == 0
+}
+
+/// `input`을 훑어 L+V, L+V+T, (이미 조합된) LV+T 시퀀스를 완성형 음절로 합성한다.
+/// 한글 자모가 아닌 코드포인트는 그대로 통과시킨다.
+pub fn compose(input: &str) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let mut output = String::with_capacity(input.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        let cp = chars[i] as u32;
+
+        if is_leading(cp) {
+            if let Some(&next) = chars.get(i + 1) {
+                let v_cp = next as u32;
+                if is_vowel(v_cp) {
+                    let syllable =
+                        S_BASE + (cp - L_BASE) * N_COUNT + (v_cp - V_BASE) * T_COUNT;
+
+                    if let Some(&maybe_t) = chars.get(i + 2) {
+                        let t_cp = maybe_t as u32;
+                        if is_trailing(t_cp) {
+                            output.push(char::from_u32(syllable + (t_cp - T_BASE)).unwrap());
+                            i += 3;
+                            continue;
+                        }
+                    }
+
+                    output.push(char::from_u32(syllable).unwrap());
+                    i += 2;
+                    continue;
+                }
+            }
+        } else if is_lv_syllable(cp) {
+            if let Some(&next) = chars.get(i + 1) {
+                let t_cp = next as u32;
+                if is_trailing(t_cp) {
+                    output.push(char::from_u32(cp + (t_cp - T_BASE)).unwrap());
+                    i += 2;
+                    continue;
+                }
+            }
+        }
+
+        output.push(chars[i]);
+        i += 1;
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_composes_leading_plus_vowel() {
+        // ㄱ(U+1100) + ㅏ(U+1161) -> 가(U+AC00)
+        assert_eq!(compose("\u{1100}\u{1161}"), "가");
+    }
+
+    #[test]
+    fn test_composes_leading_vowel_trailing() {
+        // ㄱ(U+1100) + ㅏ(U+1161) + ㄴ(U+11AB) -> 간
+        assert_eq!(compose("\u{1100}\u{1161}\u{11AB}"), "간");
+    }
+
+    #[test]
+    fn test_already_composed_lv_absorbs_trailing_consonant() {
+        // 가(U+AC00, 종성 없음) + ㄴ(U+11AB) -> 간
+        assert_eq!(compose("가\u{11AB}"), "간");
+    }
+
+    #[test]
+    fn test_non_jamo_codepoints_pass_through() {
+        assert_eq!(compose("hello ½ world"), "hello ½ world");
+    }
+
+    #[test]
+    fn test_leading_without_following_vowel_passes_through() {
+        // 뒤에 모음이 없는 단독 초성은 조합하지 않고 그대로 둔다.
+        assert_eq!(compose("\u{1100}a"), "\u{1100}a");
+    }
+
+    #[test]
+    fn test_composed_syllable_with_existing_trailing_is_left_alone() {
+        // 이미 종성까지 있는 완성형 음절(간) 뒤에 또 종성이 와도 흡수하지 않는다.
+        assert_eq!(compose("간\u{11AB}"), "간\u{11AB}");
+    }
+}
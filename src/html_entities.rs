@@ -0,0 +1,211 @@
+// 웹에서 긁어온 콘텐츠를 번역할 때 이름/숫자 문자 참조(`&amp;`, `&#39;`, `&lrm;` 등)가
+// DLL을 거치며 그냥 평범한 텍스트("&", "amp", ";")로 흩어지는 문제를 막는 전/후처리.
+//
+// 번역 전에 참조를 실제 코드포인트로 풀어 둬야 DLL이 마크업 구문을 문장 취급해 깨뜨리지
+// 않는다. 풀어낸 코드포인트가 엔진 저장소 밖(예: `&hearts;` -> ♥)이면 [`protection`]으로
+// 보호해 둔 채 번역하고, 호출자가 원하면 번역 결과를 다시 엔티티로 이스케이프해 안전하게
+// 마크업에 되묻을 수 있게 한다.
+//
+// 알려진 이름을 찾을 때 접두사만 보고 섣불리 엔티티로 단정하면(`"&notit;"`을 `&not;`
+// + `it;`로 잘못 끊는 등) 범위를 벗어나 잘못 읽을 위험이 있으므로, 몸통 끝에 반드시
+// `;`가 있고 그 `;`가 [`MAX_ENTITY_BODY_LEN`] 안에 있을 때만 엔티티로 인정한다.
+
+use crate::{EzTransError, EzTransInner};
+use crate::protection::{protect, ProtectionConfig};
+
+/// 번들로 들고 있는 HTML 명명 문자 참조 표(이름, 치환 문자열). 전체 HTML5 명세의
+/// 수천 개 항목이 아니라, 웹 스크랩 콘텐츠에서 실제로 자주 보이는 것만 추렸다.
+const NAMED_ENTITIES: &[(&str, &str)] = &[
+    ("amp", "&"),
+    ("lt", "<"),
+    ("gt", ">"),
+    ("quot", "\""),
+    ("apos", "'"),
+    ("nbsp", "\u{00A0}"),
+    ("lrm", "\u{200E}"),
+    ("rlm", "\u{200F}"),
+    ("zwnj", "\u{200C}"),
+    ("zwj", "\u{200D}"),
+    ("hellip", "…"),
+    ("mdash", "—"),
+    ("ndash", "–"),
+    ("copy", "©"),
+    ("reg", "®"),
+    ("trade", "™"),
+    ("times", "×"),
+    ("divide", "÷"),
+    ("deg", "°"),
+    ("plusmn", "±"),
+    ("sect", "§"),
+    ("para", "¶"),
+    ("middot", "·"),
+    ("laquo", "«"),
+    ("raquo", "»"),
+    ("euro", "€"),
+    ("pound", "£"),
+    ("yen", "¥"),
+    ("cent", "¢"),
+    ("hearts", "♥"),
+];
+
+/// `&`와 `;` 사이에 올 수 있는 몸통의 최대 문자 수. 이 길이 안에 `;`가 없으면 그냥
+/// 텍스트 안의 `&`로 취급하고, 실제로 이 길이 안에서 찾아낸 `;`라 해도 명명 참조 표에
+/// 없으면 역시 손대지 않는다.
+const MAX_ENTITY_BODY_LEN: usize = 10;
+
+/// `body_and_rest`(첫 `&` 바로 다음부터)의 맨 앞에서 문자 참조 하나를 읽어, (치환
+/// 문자열, `&` 다음부터 `;`까지 소비한 바이트 수)를 돌려준다. `;`가
+/// [`MAX_ENTITY_BODY_LEN`] 안에 없거나 몸통을 해석할 수 없으면 `None`.
+fn decode_one_entity(body_and_rest: &str) -> Option<(String, usize)> {
+    let mut semi_idx = None;
+    for (idx, c) in body_and_rest.char_indices() {
+        if idx > MAX_ENTITY_BODY_LEN {
+            break;
+        }
+        if c == ';' {
+            semi_idx = Some(idx);
+            break;
+        }
+    }
+    let semi_idx = semi_idx?;
+    let body = &body_and_rest[..semi_idx];
+    let consumed = semi_idx + 1;
+
+    if let Some(hex) = body.strip_prefix('#').and_then(|s| s.strip_prefix('x').or_else(|| s.strip_prefix('X'))) {
+        let code = u32::from_str_radix(hex, 16).ok()?;
+        let c = char::from_u32(code)?;
+        return Some((c.to_string(), consumed));
+    }
+    if let Some(dec) = body.strip_prefix('#') {
+        let code: u32 = dec.parse().ok()?;
+        let c = char::from_u32(code)?;
+        return Some((c.to_string(), consumed));
+    }
+
+    NAMED_ENTITIES
+        .iter()
+        .find(|(name, _)| *name == body)
+        .map(|(_, value)| (value.to_string(), consumed))
+}
+
+/// `input`의 명명/숫자 문자 참조를 실제 코드포인트로 풀어낸다. 인식하지 못한 `&`는
+/// 그대로 둔다.
+pub fn decode_entities(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(amp_at) = rest.find('&') {
+        result.push_str(&rest[..amp_at]);
+        let after_amp = &rest[amp_at + '&'.len_utf8()..];
+
+        match decode_one_entity(after_amp) {
+            Some((decoded, consumed)) => {
+                result.push_str(&decoded);
+                rest = &after_amp[consumed..];
+            }
+            None => {
+                result.push('&');
+                rest = after_amp;
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// 마크업에 다시 안전하게 묻을 수 있도록 `&`, `<`, `>`, `"`, `'`만 엔티티로 되돌린다.
+/// 번역이 원문을 완전히 새로운 텍스트로 바꿔 버리므로, 입력에 쓰였던 모든 이름 참조를
+/// 똑같은 이름으로 되살리는 대칭적 복원은 의미가 없다 — 여기서는 그 결과를 마크업에
+/// 다시 끼워 넣어도 구문이 깨지지 않는다는 것만 보장한다.
+pub fn encode_entities(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&apos;"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+impl EzTransInner {
+    /// HTML/XML 문자 참조를 인식해 가며 번역한다: 참조를 실제 코드포인트로 풀고,
+    /// 엔진 저장소 밖 문자는 [`protection`]으로 보호한 채 번역한 뒤, `reencode`가
+    /// 참이면 결과를 다시 마크업에 안전한 형태로 이스케이프한다.
+    pub fn translate_html_entity_aware(
+        &self,
+        input: &str,
+        reencode: bool,
+    ) -> Result<String, EzTransError> {
+        let decoded = decode_entities(input);
+        let (protected, map) = protect(&decoded, ProtectionConfig::default());
+        let translated = self.default_translate(&protected)?;
+        let restored = map.restore(&translated);
+        Ok(if reencode {
+            encode_entities(&restored)
+        } else {
+            restored
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decodes_named_entity() {
+        assert_eq!(decode_entities("Tom &amp; Jerry"), "Tom & Jerry");
+    }
+
+    #[test]
+    fn test_decodes_decimal_numeric_entity() {
+        assert_eq!(decode_entities("it&#39;s"), "it's");
+    }
+
+    #[test]
+    fn test_decodes_hex_numeric_entity_both_cases() {
+        assert_eq!(decode_entities("&#x27;&#X3042;"), "'あ");
+    }
+
+    #[test]
+    fn test_unterminated_ampersand_is_left_as_is() {
+        assert_eq!(decode_entities("AT&T"), "AT&T");
+    }
+
+    #[test]
+    fn test_unknown_named_entity_with_terminator_is_left_as_is() {
+        assert_eq!(decode_entities("&notarealentity;"), "&notarealentity;");
+    }
+
+    #[test]
+    fn test_semicolon_far_beyond_max_body_len_is_not_treated_as_entity() {
+        let long_run = "&".to_string() + &"x".repeat(MAX_ENTITY_BODY_LEN + 5) + ";";
+        assert_eq!(decode_entities(&long_run), long_run);
+    }
+
+    #[test]
+    fn test_prefix_like_run_does_not_get_misread_as_a_shorter_known_entity() {
+        // "&notit;" starts with the bytes of "&not" but is not `&not;` followed by
+        // literal text — the whole run up to `;` must fail as an unknown entity.
+        assert_eq!(decode_entities("&notit;"), "&notit;");
+    }
+
+    #[test]
+    fn test_encode_entities_escapes_markup_significant_chars() {
+        assert_eq!(
+            encode_entities("<a href=\"x\">Tom & Jerry's</a>"),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&apos;s&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn test_plain_text_round_trips_through_decode_and_encode() {
+        let input = "hello world";
+        assert_eq!(encode_entities(&decode_entities(input)), input);
+    }
+}
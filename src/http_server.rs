@@ -0,0 +1,280 @@
+// HTTP/JSON 번역 프론트엔드
+//
+// 이름있는 파이프(Named Pipe) 대신 localhost HTTP로 번역 엔진에 접근할 수 있게 하는 보조
+// 트랜스포트. 파이프의 고정 크기 `MessageHeader`/구조체 와이어 포맷을 구현할 필요 없이,
+// 비-Windows 클라이언트나 스크립트 환경에서도 엔진을 사용할 수 있다.
+//
+// 최소한의 요청만 처리하는 블로킹 단일 스레드 라우터이다: `POST /translate`,
+// `POST /reload-dict`, `GET /health`.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+
+use crate::EzTransEngine;
+
+/// `?charset=`로 지정 가능한 응답 본문 인코딩.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputCharset {
+    Utf8,
+    EucKr,
+}
+
+impl OutputCharset {
+    fn from_query(query: &str) -> Self {
+        for pair in query.split('&') {
+            if let Some(value) = pair.strip_prefix("charset=") {
+                if value.eq_ignore_ascii_case("euc-kr") {
+                    return OutputCharset::EucKr;
+                }
+            }
+        }
+        OutputCharset::Utf8
+    }
+
+    fn encode(self, text: &str) -> Vec<u8> {
+        match self {
+            OutputCharset::Utf8 => text.as_bytes().to_vec(),
+            OutputCharset::EucKr => encoding_rs::EUC_KR.encode(text).0.into_owned(),
+        }
+    }
+}
+
+/// 엔진을 감싸는 HTTP 프론트엔드. `TransProxyServer`와 마찬가지로 하나의 엔진 인스턴스를
+/// 여러 요청이 공유한다.
+pub struct HttpTranslationServer {
+    engine: Arc<Mutex<Option<EzTransEngine>>>,
+}
+
+impl HttpTranslationServer {
+    pub fn new() -> Self {
+        Self {
+            engine: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// 이미 초기화된 엔진을 가져와 HTTP 요청이 공유하도록 설정한다.
+    pub fn with_engine(engine: EzTransEngine) -> Self {
+        Self {
+            engine: Arc::new(Mutex::new(Some(engine))),
+        }
+    }
+
+    /// `addr`에서 들어오는 연결을 블로킹으로 하나씩 받아 처리한다.
+    pub fn serve(&self, addr: &str) -> std::io::Result<()> {
+        let listener = TcpListener::bind(addr)?;
+        for stream in listener.incoming() {
+            match stream {
+                Ok(stream) => self.handle_connection(stream),
+                Err(e) => eprintln!("HTTP connection failed: {}", e),
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_connection(&self, mut stream: TcpStream) {
+        let request = match read_request(&mut stream) {
+            Ok(r) => r,
+            Err(e) => {
+                eprintln!("Failed to read HTTP request: {}", e);
+                return;
+            }
+        };
+
+        let (status, body) = self.route(&request);
+        let _ = write_response(&mut stream, status, &body);
+    }
+
+    fn route(&self, request: &HttpRequest) -> (u16, Vec<u8>) {
+        let (path, query) = request
+            .path
+            .split_once('?')
+            .unwrap_or((request.path.as_str(), ""));
+
+        match (request.method.as_str(), path) {
+            ("GET", "/health") => (200, br#"{"status":"ok"}"#.to_vec()),
+            ("POST", "/translate") => self.handle_translate(request, query),
+            ("POST", "/reload-dict") => self.handle_reload_dict(),
+            _ => (404, br#"{"error":"not found"}"#.to_vec()),
+        }
+    }
+
+    fn handle_translate(&self, request: &HttpRequest, query: &str) -> (u16, Vec<u8>) {
+        let text = match json_string_field(&request.body, "text") {
+            Some(t) => t,
+            None => return (400, br#"{"error":"missing 'text'"}"#.to_vec()),
+        };
+        let mode = json_string_field(&request.body, "mode").unwrap_or_else(|| "mmntw".into());
+
+        let guard = self.engine.lock().unwrap();
+        let Some(engine) = guard.as_ref() else {
+            return (503, br#"{"error":"engine not initialized"}"#.to_vec());
+        };
+
+        let result = match mode.as_str() {
+            "mmnt" => engine.translate_mmnt(&text),
+            _ => engine.default_translate(&text),
+        };
+
+        match result {
+            Ok(translated) => {
+                let charset = OutputCharset::from_query(query);
+                match charset {
+                    // JSON은 UTF-8 문자열만 안전하게 담을 수 있으므로 기본 응답은 UTF-8로 감싼다.
+                    OutputCharset::Utf8 => (
+                        200,
+                        format!(r#"{{"translated":{}}}"#, json_escape(&translated)).into_bytes(),
+                    ),
+                    // euc-kr이 명시적으로 요청된 경우에는 JSON으로 감싸지 않고 인코딩된
+                    // 바이트를 그대로 본문으로 돌려준다.
+                    OutputCharset::EucKr => (200, charset.encode(&translated)),
+                }
+            }
+            Err(e) => (
+                500,
+                format!(r#"{{"error":{}}}"#, json_escape(&e.to_string())).into_bytes(),
+            ),
+        }
+    }
+
+    fn handle_reload_dict(&self) -> (u16, Vec<u8>) {
+        let guard = self.engine.lock().unwrap();
+        match guard.as_ref() {
+            Some(engine) => match engine.reload_user_dict() {
+                Ok(_) => (200, br#"{"status":"ok"}"#.to_vec()),
+                Err(e) => (
+                    500,
+                    format!(r#"{{"error":{}}}"#, json_escape(&e.to_string())).into_bytes(),
+                ),
+            },
+            None => (503, br#"{"error":"engine not initialized"}"#.to_vec()),
+        }
+    }
+}
+
+struct HttpRequest {
+    method: String,
+    path: String,
+    body: String,
+}
+
+fn read_request(stream: &mut TcpStream) -> std::io::Result<HttpRequest> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or("").to_string();
+    let path = parts.next().unwrap_or("/").to_string();
+
+    let mut content_length = 0usize;
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            }
+        }
+    }
+
+    let mut body = vec![0u8; content_length];
+    reader.read_exact(&mut body)?;
+
+    Ok(HttpRequest {
+        method,
+        path,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+fn write_response(stream: &mut TcpStream, status: u16, body: &[u8]) -> std::io::Result<()> {
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        reason,
+        body.len()
+    )?;
+    stream.write_all(body)?;
+    stream.flush()
+}
+
+/// `{"key": "value", ...}` 형태의 아주 단순한 JSON 본문에서 문자열 필드 하나만 뽑아낸다.
+/// 전체 JSON 파서를 두지 않고 이 서버가 실제로 받는 두 필드(`text`, `mode`)만 지원한다.
+fn json_string_field(body: &str, key: &str) -> Option<String> {
+    let key_pattern = format!("\"{}\"", key);
+    let start = body.find(&key_pattern)? + key_pattern.len();
+    let rest = &body[start..];
+    let colon = rest.find(':')?;
+    let rest = rest[colon + 1..].trim_start();
+    let rest = rest.strip_prefix('"')?;
+
+    let mut value = String::new();
+    let mut chars = rest.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return Some(value),
+            '\\' => {
+                if let Some(escaped) = chars.next() {
+                    value.push(escaped);
+                }
+            }
+            _ => value.push(c),
+        }
+    }
+    None
+}
+
+fn json_escape(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_string_field_extracts_value() {
+        let body = r#"{"text": "こんにちは", "mode": "mmntw"}"#;
+        assert_eq!(json_string_field(body, "text").as_deref(), Some("こんにちは"));
+        assert_eq!(json_string_field(body, "mode").as_deref(), Some("mmntw"));
+    }
+
+    #[test]
+    fn test_json_string_field_missing_key() {
+        let body = r#"{"text": "hi"}"#;
+        assert_eq!(json_string_field(body, "mode"), None);
+    }
+
+    #[test]
+    fn test_json_string_field_handles_escapes() {
+        let body = r#"{"text": "line1\nline2 \"quoted\""}"#;
+        assert_eq!(
+            json_string_field(body, "text").as_deref(),
+            Some("line1nline2 \"quoted\"")
+        );
+    }
+
+    #[test]
+    fn test_output_charset_from_query() {
+        assert_eq!(OutputCharset::from_query(""), OutputCharset::Utf8);
+        assert_eq!(OutputCharset::from_query("charset=euc-kr"), OutputCharset::EucKr);
+        assert_eq!(
+            OutputCharset::from_query("foo=bar&charset=EUC-KR"),
+            OutputCharset::EucKr
+        );
+    }
+}
@@ -15,6 +15,8 @@ pub enum Command {
     SetProperty = 6,
     Shutdown = 7,
     Ping = 8,
+    LoadGlossary = 9,
+    TranslateBatch = 10,
 }
 
 impl TryFrom<u32> for Command {
@@ -30,6 +32,8 @@ impl TryFrom<u32> for Command {
             6 => Ok(Command::SetProperty),
             7 => Ok(Command::Shutdown),
             8 => Ok(Command::Ping),
+            9 => Ok(Command::LoadGlossary),
+            10 => Ok(Command::TranslateBatch),
             _ => Err(crate::EzTransError::InvalidCommand(value)),
         }
     }
@@ -45,6 +49,10 @@ pub enum Status {
     InvalidParameter = 3,
 }
 
+/// 모든 메시지 앞에 붙는 고정 크기 헤더. `payload_size`는 헤더 바로 뒤에 이어지는 바디의
+/// 바이트 수이다. `Initialize`/`SetProperty`처럼 고정 크기 구조체를 주고받는 명령은 바디가
+/// 항상 `size_of::<T>()` 바이트이고, `TranslateMMNT(W)`처럼 가변 길이 텍스트를 주고받는
+/// 명령은 `payload_size`를 실제로 읽어 텍스트 길이를 판단한다.
 #[repr(C, packed(8))]
 #[derive(Debug, Clone, Copy)]
 pub struct MessageHeader {
@@ -66,30 +74,22 @@ pub struct InitializeResponse {
     pub success: bool,
 }
 
+/// 번역 요청 바디의 고정 크기 앞부분. 뒤따르는 텍스트는 `MessageHeader::payload_size`에서
+/// `size_of::<TranslateRequestHeader>()`를 뺀 만큼의 가변 길이 바이트(MMNT는 Shift-JIS,
+/// MMNTW는 UTF-16)이다. 이전의 `[u8; 4096]`/`[u16; 4096]` 고정 배열을 대체해 4096 코드
+/// 단위 한도를 없앤다.
 #[repr(C, packed(8))]
-pub struct TranslateMMNTRequest {
-    pub data0: u32,
-    pub text: [u8; 4096],
-}
-
-#[repr(C, packed(8))]
-pub struct TranslateMMNTResponse {
-    pub status: Status,
-    pub result_code: i32,
-    pub translated: [u8; 4096],
-}
-
-#[repr(C, packed(8))]
-pub struct TranslateMMNTWRequest {
+#[derive(Debug, Clone, Copy)]
+pub struct TranslateRequestHeader {
     pub data0: u32,
-    pub text: [u16; 4096],
 }
 
+/// 번역 응답 바디의 고정 크기 앞부분. 뒤따르는 번역 결과 역시 `payload_size` 기준 가변 길이다.
 #[repr(C, packed(8))]
-pub struct TranslateMMNTWResponse {
+#[derive(Debug, Clone, Copy)]
+pub struct TranslateResponseHeader {
     pub status: Status,
     pub result_code: i32,
-    pub translated: [u16; 4096],
 }
 
 #[repr(C, packed(8))]
@@ -105,6 +105,32 @@ pub struct SetPropertyRequest {
     pub value: i32,
 }
 
+/// 용어집 본문. `source<TAB>replacement` 쌍을 줄바꿈으로 구분해 UTF-16으로 담는다.
+#[repr(C, packed(8))]
+pub struct LoadGlossaryRequest {
+    pub size: u32,
+    pub data: [u16; 16384],
+}
+
+// `Command::TranslateBatch` 요청 바디의 레이아웃 (`MessageHeader::payload_size` 바이트):
+//
+//   segment_count: u32
+//   segment_count 번 반복:
+//       len: u32       // 다음에 오는 UTF-16 코드 단위 개수
+//       data: [u16; len]
+//
+// 응답 바디는 같은 순서로 세그먼트별 결과를 담는다:
+//
+//   segment_count: u32
+//   segment_count 번 반복:
+//       status: u32    // Status(Success/Error 등) 값. Success가 아니면 해당 세그먼트만 실패
+//       len: u32
+//       data: [u16; len]   // 실패한 세그먼트는 len == 0
+//
+// 전체 요청이 `server::MAX_PAYLOAD_SIZE`를 넘으면 세그먼트 단위로 쪼개지 않고 바디를
+// 아예 읽지 않은 채 `TranslateResponseHeader { status: Status::InvalidParameter }`만
+// 돌려준다 (`server::reject_if_payload_too_large` 참고).
+
 // Safety checks for struct sizes
 const _: () = {
     assert!(size_of::<MessageHeader>() == 16);
@@ -1,14 +1,117 @@
 #![allow(non_camel_case_types)]
+// `tracing` 피처를 켜면 속성/중지/전문 번역 계열 FFI 호출이 `tracing::instrument`로
+// 계측되어, 각 호출의 인자/반환 코드/소요 시간을 span으로 남긴다. 기본값은 꺼짐이며,
+// 켜지 않으면 이 계측 코드는 전부 컴파일에서 빠진다.
 mod error;
-pub use error::{EzTransError, TransErr};
+pub mod astral_protect;
+pub mod async_engine;
+pub mod batch_translate;
+pub mod bmp_set;
+pub mod cached_translator;
+pub mod char_ranges;
+pub mod char_safety_probe;
+pub mod chunk_iterator;
+pub mod client;
+pub mod confined_engine;
+pub mod coverage;
+pub mod csv_dialect;
+pub mod csv_glossary;
+pub mod csv_stream;
+pub mod emoji_table;
+pub mod encoding_detect;
+pub mod engine_pool;
+pub mod engine_status;
+pub mod euc_kr_decode;
+pub mod file_translate;
+pub mod gettext_catalog;
+pub mod global_engine;
+pub mod glossary;
+pub mod grapheme_encode;
+pub mod hangul_compose;
+pub mod html_entities;
+pub mod http_server;
+pub mod ipc_protocol;
+pub mod normalize;
+pub mod output_validator;
+pub mod pool;
+pub mod problematic_char_set;
+pub mod protection;
+pub mod report;
+pub mod safe_translate;
+pub mod sanitizer_map;
+pub mod server;
+// 의존성도, 워커 스레드도 늘리지 않는 나머지 모듈과 달리 스레드 풀 전체를 계속
+// 두드려 보는 용도라 기본 빌드에서는 빼 두고, 필요할 때만 켠다.
+#[cfg(feature = "stress-harness")]
+pub mod stress_harness;
+pub mod term_glossary;
+pub mod translation_cache;
+pub mod translation_chunker;
+pub mod translation_engine;
+pub mod translation_guard;
+pub mod translation_pool;
+pub mod translation_server;
+pub mod translation_service;
+pub mod translator;
+pub mod utf16_decode;
+pub use error::{EzTransError, TransErr, TranscodeError};
 
 use std::collections::HashSet;
 use std::ffi::{CStr, CString, c_char, c_int, c_void};
 use std::fmt::Write;
 use std::path::Path;
-use windows::Win32::Foundation::{FreeLibrary, HMODULE};
-use windows::Win32::System::LibraryLoader::{GetProcAddress, LoadLibraryA};
-use windows::core::{Error as WindowsError, PCSTR};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder, MatchKind};
+use libloading::Library;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::translation_chunker::TranslationChunker;
+
+/// 등록된 용어집 항목 하나가 매칭됐을 때 복원 단계에서 무엇으로 되돌릴지.
+enum GlossaryEntry {
+    /// 번역을 거치지 않고 바로 이 텍스트로 치환한다 (용어집에 등록된 번역).
+    Translated(String),
+    /// 원문을 그대로 보존한다 (고유명사, 게임 제어 코드, HTML/루비 태그 등).
+    Protected(String),
+}
+
+/// `default_translate`가 번역 전/후에 한 번씩만 훑도록 미리 빌드해 둔 용어집 자동자.
+struct UserGlossary {
+    automaton: AhoCorasick,
+    /// 자동자의 패턴 id로 인덱싱된다.
+    entries: Vec<GlossaryEntry>,
+}
+
+/// 번역이 끝난 한국어 출력 텍스트를 직접 훑어 교정하는 자동자. `UserGlossary`와 달리
+/// 원문이 아니라 번역 결과 문자열이 스캔 대상이고, 모든 매치가 곧바로 치환되므로
+/// "원문 보존"이라는 개념(`GlossaryEntry::Protected`에 해당)이 없다.
+struct PostGlossary {
+    automaton: AhoCorasick,
+    /// 자동자의 패턴 id로 인덱싱되는 교정문.
+    replacements: Vec<String>,
+}
+
+/// [`EzTransInner::add_term`]/[`EzTransInner::add_terms`]가 용어를 적용할 시점을
+/// 고르는 모드.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlossaryMode {
+    /// 번역 전에 원문에서 찾아 치환/보호한다. `set_glossary`와 같은 경로([`UserGlossary`])를
+    /// 쓴다.
+    Pre,
+    /// 번역 후 출력 텍스트에서 직접 찾아 교정한다. 엔진이 특정 구절을 매번 똑같이
+    /// 잘못 번역할 때, 원문은 그대로 보내고 결과만 고치고 싶을 때 쓴다. `target`이
+    /// 필수다 — "원문 보존" 개념이 없으므로 `None`은 오류다.
+    Post,
+}
+
+/// 용어집 치환에 쓰는 사설 영역(Private Use Area) 스칼라 값의 시작점.
+///
+/// `hangul_encode`/`hangul_decode`가 쓰는 진짜 한글 범위, `special_chars`의 그 어느
+/// 문자와도 겹치지 않으므로, 이 범위로 만든 `+xXXXX` 형태의 자리표시자는 복원 단계에서
+/// 실제로 인코딩된 한글/특수문자와 절대 혼동되지 않는다.
+const GLOSSARY_SENTINEL_BASE: u32 = 0xE000;
 
 // Type definitions for all EzTrans engine functions
 pub type J2K_FreeMem = unsafe extern "stdcall" fn(*mut c_void);
@@ -35,11 +138,91 @@ pub type J2K_TranslateMMNT = unsafe extern "stdcall" fn(c_int, *const c_char) ->
 /// EHND를 사용하는 번역 함수
 pub type J2K_TranslateMMNTW = unsafe extern "stdcall" fn(c_int, *const u16) -> *mut u16;
 
-/// EzTrans 엔진을 관리하는 구조체
+/// EzTrans 엔진을 관리하는 구조체. 내부적으로 [`EzTransInner`]를 `Arc`로 감싸고 있어
+/// 값을 `Clone`해도 DLL은 한 번만 로드된 채로 여러 스레드/소유자가 공유합니다.
+///
+/// 필드와 메소드는 `Deref<Target = EzTransInner>`를 통해 그대로 노출되므로, 기존에
+/// `EzTransEngine`을 직접 쓰던 코드는 변경 없이 동작합니다.
+#[derive(Clone)]
 pub struct EzTransEngine {
-    pub module: HMODULE,
+    inner: Arc<EzTransInner>,
+}
+
+impl EzTransEngine {
+    /// EzTrans 엔진을 로드하고 초기화합니다. 반환된 값은 값싸게 `Clone`할 수 있으며,
+    /// 마지막 복제본이 사라질 때만 DLL이 언로드됩니다.
+    pub fn new<P: AsRef<Path>>(dll_path: P) -> Result<Self, EzTransError> {
+        Ok(Self {
+            inner: Arc::new(EzTransInner::new(dll_path)?),
+        })
+    }
+
+    /// 별도 스레드에서 `translate_mmnt`를 실행하고, 그 진행 상황을 대표하는
+    /// [`crate::translation_guard::TranslationGuard`]를 돌려준다. 가드를 그냥
+    /// 드롭하면 번역이 끝날 때까지 블록하고, `join()`으로 결과를 받거나
+    /// `detach()`로 기다리지 않고 손을 뗄 수 있다.
+    pub fn translate_guarded(
+        &self,
+        text: impl Into<String>,
+    ) -> crate::translation_guard::TranslationGuard {
+        let engine = self.clone();
+        let text = text.into();
+        let handle = thread::spawn(move || engine.translate_mmnt(&text));
+        crate::translation_guard::TranslationGuard::new(handle)
+    }
+}
+
+impl std::ops::Deref for EzTransEngine {
+    type Target = EzTransInner;
+
+    fn deref(&self) -> &EzTransInner {
+        &self.inner
+    }
+}
+
+/// `EzTransEngine`이 공유하는 실제 DLL 핸들과 함수 포인터들.
+///
+/// `libloading::Library`가 심볼 메모리의 수명을 쥐고 있으므로, 이 구조체가 살아 있는
+/// 동안에는 아래 함수 포인터들이 항상 유효하다.
+pub struct EzTransInner {
+    /// 로드된 DLL. 값을 들고 있는 것만으로 아래 함수 포인터들의 유효성이 보장된다.
+    library: Library,
     /// 이지트랜스 엔진이 처리할 수 없는 문자가 문자열에 들어있는지 확인하는 역할.
     pub special_chars: HashSet<char>,
+    /// `set_glossary`로 등록된 사용자 용어집. `default_translate`가 매 호출마다 다시
+    /// 빌드하지 않도록 한 번 빌드해 캐시해 둔다.
+    glossary: Mutex<Option<UserGlossary>>,
+    /// `glossary`를 빌드한 원본 (원문, 치환어) 쌍들. `add_term`/`add_terms`가 자동자를
+    /// 통째로 다시 빌드할 때 이전까지 등록된 항목 위에 이어서 쌓을 수 있도록 보관한다.
+    glossary_terms: Mutex<Vec<(String, Option<String>)>>,
+    /// `add_term`/`add_terms`에 [`GlossaryMode::Post`]로 등록된, 번역이 끝난 한국어
+    /// 출력 텍스트에서 직접 찾아 고치는 교정 항목의 자동자.
+    post_glossary: Mutex<Option<PostGlossary>>,
+    /// `post_glossary`를 빌드한 원본 (잘못된 출력, 교정문) 쌍들.
+    post_glossary_terms: Mutex<Vec<(String, String)>>,
+    /// `hangul_encode`가 `special_chars`/한글 범위 말고도 추가로 이스케이프할 코드포인트
+    /// 집합. 기본은 [`crate::problematic_char_set::ProblematicCharSet::bundled`]이며,
+    /// `load_problematic_chars`로 현재 DLL/Dat을 상대로 다시 실측한 테이블로 바꿔 끼울
+    /// 수 있다.
+    problematic_chars: Mutex<crate::problematic_char_set::ProblematicCharSet>,
+    /// `set_sanitizer_map`으로 교체할 수 있는, □/물음표로 깨지는 문자의 대체 표.
+    /// `translate_sanitized`가 번역 전에 이 표로 입력을 치환한다.
+    sanitizer: Mutex<crate::sanitizer_map::SanitizerMap>,
+    /// `set_normalize_compat`으로 켤 수 있는 호환 정규화 플래그. 켜져 있으면
+    /// `default_translate`가 인코딩 전에 NFKC와 단위 기호 교정 표를 적용한다.
+    normalize_compat: Mutex<bool>,
+    /// `set_output_encoding`으로 고를 수 있는, `translate_mmnt`/`translate_mmnt_lossy`가
+    /// 출력 바이트를 디코딩할 때 쓰는 표. 기본은 EUC-KR의 상위 호환인 CP949.
+    output_encoding: Mutex<crate::euc_kr_decode::OutputEncoding>,
+    /// `enable_translation_cache`로 켤 수 있는 `translate_mmntw`/`translate_mmnt`
+    /// 메모이제이션 캐시. 기본은 비활성 상태다.
+    translation_cache: crate::translation_cache::TranslationCache,
+    /// DLL 호출 전체를 직렬화하는 락. J2K DLL은 스레드 안전하지 않으므로, `EzTransEngine`
+    /// 클론이 여러 스레드에 퍼져 있어도 실제 FFI 호출은 한 번에 하나만 일어나야 한다.
+    call_lock: Mutex<()>,
+    /// 이 엔진이 지금 뭘 하고 있는지, 지금까지 번역/오류/깨짐이 몇 번 있었는지 추적하는
+    /// 장부. `status()`로 스냅샷을 읽을 수 있다.
+    status: crate::engine_status::EngineStatusTracker,
 
     // 함수 포인터들
     pub free_mem: Option<J2K_FreeMem>,
@@ -70,22 +253,25 @@ pub struct EzTransEngine {
     pub translate_mmntw: Option<J2K_TranslateMMNTW>,
 }
 
-impl EzTransEngine {
-    /// EzTrans 엔진을 초기화합니다.
-    pub fn new<P: AsRef<Path>>(dll_path: P) -> Result<Self, EzTransError> {
+// 함수 포인터와 `Library`는 그 자체로 스레드 간에 공유해도 안전하다(값이 가리키는 DLL
+// 코드/심볼 테이블은 불변). 실제 동시 호출에 대한 안전성은 DLL 자체가 보장하지 않으므로,
+// 아래 각 메소드가 `call_lock`으로 FFI 호출을 직렬화한다.
+unsafe impl Send for EzTransInner {}
+unsafe impl Sync for EzTransInner {}
+
+impl EzTransInner {
+    /// DLL을 로드하고 함수 포인터들을 채운 `EzTransInner`를 만듭니다. 공개 진입점은
+    /// `EzTransEngine::new`이며, 이 함수는 그 안에서 `Arc`로 감싸진다.
+    fn new<P: AsRef<Path>>(dll_path: P) -> Result<Self, EzTransError> {
         // DLL 경로를 문자열로 변환
         let path_str = dll_path
             .as_ref()
             .to_str()
             .ok_or(EzTransError::InvalidPath)?;
 
-        // CString으로 변환 (null 종료 문자열)
-        let c_path = CString::new(path_str)?;
-
         // DLL 로드
-        let module = unsafe {
-            LoadLibraryA(PCSTR(c_path.as_ptr() as *const u8))
-                .map_err(|e: WindowsError| EzTransError::DllLoadError(e.to_string()))?
+        let library = unsafe {
+            Library::new(path_str).map_err(|e| EzTransError::DllLoadError(e.to_string()))?
         };
 
         let special_chars: HashSet<char> = [
@@ -118,8 +304,19 @@ impl EzTransEngine {
 
         // 엔진 인스턴스 생성
         let mut engine = Self {
-            module,
+            library,
             special_chars,
+            glossary: Mutex::new(None),
+            glossary_terms: Mutex::new(Vec::new()),
+            post_glossary: Mutex::new(None),
+            post_glossary_terms: Mutex::new(Vec::new()),
+            problematic_chars: Mutex::new(crate::problematic_char_set::ProblematicCharSet::bundled()),
+            sanitizer: Mutex::new(crate::sanitizer_map::SanitizerMap::default_map()),
+            normalize_compat: Mutex::new(false),
+            output_encoding: Mutex::new(crate::euc_kr_decode::OutputEncoding::default()),
+            translation_cache: Self::new_translation_cache(),
+            call_lock: Mutex::new(()),
+            status: crate::engine_status::EngineStatusTracker::default(),
             free_mem: None,
             get_prior_dict: None,
             get_property: None,
@@ -149,142 +346,47 @@ impl EzTransEngine {
         Ok(engine)
     }
 
-    /// 공통 함수: 프로시저 주소를 가져오는 함수
-    fn get_proc_address(&self, name: &str) -> Result<*const (), EzTransError> {
-        let c_name = CString::new(name)?;
+    /// `libloading::Library`에서 주어진 이름의 심볼을 함수 포인터로 읽어옵니다.
+    ///
+    /// `T`는 항상 `J2K_*` 함수 포인터 타입(복사 가능한 `extern "stdcall" fn`)이어야
+    /// 합니다. 반환된 포인터는 `self.library`가 살아 있는 동안에만 유효합니다.
+    fn load_symbol<T: Copy>(&self, name: &[u8]) -> Result<T, EzTransError> {
         unsafe {
-            GetProcAddress(self.module, PCSTR(c_name.as_ptr() as *const u8))
-                .map(|p| p as *const ())
-                .ok_or_else(|| {
-                    EzTransError::FunctionLoadError(format!("함수를 찾을 수 없음: {}", name))
+            self.library
+                .get::<T>(name)
+                .map(|symbol| *symbol)
+                .map_err(|e| {
+                    EzTransError::FunctionLoadError(format!(
+                        "함수를 찾을 수 없음: {} ({e})",
+                        String::from_utf8_lossy(name)
+                    ))
                 })
         }
     }
 
-    /// 각 함수별 로드 메소드들
-    fn load_free_mem(&self) -> Result<J2K_FreeMem, EzTransError> {
-        let proc_addr = self.get_proc_address("J2K_FreeMem")?;
-        Ok(unsafe { std::mem::transmute(proc_addr) })
-    }
-
-    fn load_get_prior_dict(&self) -> Result<J2K_GetPriorDict, EzTransError> {
-        let proc_addr = self.get_proc_address("J2K_GetPriorDict")?;
-        Ok(unsafe { std::mem::transmute(proc_addr) })
-    }
-
-    fn load_get_property(&self) -> Result<J2K_GetProperty, EzTransError> {
-        let proc_addr = self.get_proc_address("J2K_GetProperty")?;
-        Ok(unsafe { std::mem::transmute(proc_addr) })
-    }
-
-    fn load_initialize(&self) -> Result<J2K_Initialize, EzTransError> {
-        let proc_addr = self.get_proc_address("J2K_Initialize")?;
-        Ok(unsafe { std::mem::transmute(proc_addr) })
-    }
-
-    fn load_initialize_ex(&self) -> Result<J2K_InitializeEx, EzTransError> {
-        let proc_addr = self.get_proc_address("J2K_InitializeEx")?;
-        Ok(unsafe { std::mem::transmute(proc_addr) })
-    }
-
-    fn load_reload_user_dict(&self) -> Result<J2K_ReloadUserDict, EzTransError> {
-        let proc_addr = self.get_proc_address("J2K_ReloadUserDict")?;
-        Ok(unsafe { std::mem::transmute(proc_addr) })
-    }
-
-    fn load_set_del_jpn(&self) -> Result<J2K_SetDelJPN, EzTransError> {
-        let proc_addr = self.get_proc_address("J2K_SetDelJPN")?;
-        Ok(unsafe { std::mem::transmute(proc_addr) })
-    }
-
-    fn load_set_field(&self) -> Result<J2K_SetField, EzTransError> {
-        let proc_addr = self.get_proc_address("J2K_SetField")?;
-        Ok(unsafe { std::mem::transmute(proc_addr) })
-    }
-
-    fn load_set_hnj2han(&self) -> Result<J2K_SetHnj2han, EzTransError> {
-        let proc_addr = self.get_proc_address("J2K_SetHnj2han")?;
-        Ok(unsafe { std::mem::transmute(proc_addr) })
-    }
-
-    fn load_set_jwin(&self) -> Result<J2K_SetJWin, EzTransError> {
-        let proc_addr = self.get_proc_address("J2K_SetJWin")?;
-        Ok(unsafe { std::mem::transmute(proc_addr) })
-    }
-
-    fn load_set_prior_dict(&self) -> Result<J2K_SetPriorDict, EzTransError> {
-        let proc_addr = self.get_proc_address("J2K_SetPriorDict")?;
-        Ok(unsafe { std::mem::transmute(proc_addr) })
-    }
-
-    fn load_set_property(&self) -> Result<J2K_SetProperty, EzTransError> {
-        let proc_addr = self.get_proc_address("J2K_SetProperty")?;
-        Ok(unsafe { std::mem::transmute(proc_addr) })
-    }
-
-    fn load_stop_translation(&self) -> Result<J2K_StopTranslation, EzTransError> {
-        let proc_addr = self.get_proc_address("J2K_StopTranslation")?;
-        Ok(unsafe { std::mem::transmute(proc_addr) })
-    }
-
-    fn load_terminate(&self) -> Result<J2K_Terminate, EzTransError> {
-        let proc_addr = self.get_proc_address("J2K_Terminate")?;
-        Ok(unsafe { std::mem::transmute(proc_addr) })
-    }
-
-    fn load_translate_chat(&self) -> Result<J2K_TranslateChat, EzTransError> {
-        let proc_addr = self.get_proc_address("J2K_TranslateChat")?;
-        Ok(unsafe { std::mem::transmute(proc_addr) })
-    }
-
-    fn load_translate_fm(&self) -> Result<J2K_TranslateFM, EzTransError> {
-        let proc_addr = self.get_proc_address("J2K_TranslateFM")?;
-        Ok(unsafe { std::mem::transmute(proc_addr) })
-    }
-
-    fn load_translate_mm(&self) -> Result<J2K_TranslateMM, EzTransError> {
-        let proc_addr = self.get_proc_address("J2K_TranslateMM")?;
-        Ok(unsafe { std::mem::transmute(proc_addr) })
-    }
-
-    fn load_translate_mmex(&self) -> Result<J2K_TranslateMMEx, EzTransError> {
-        let proc_addr = self.get_proc_address("J2K_TranslateMMEx")?;
-        Ok(unsafe { std::mem::transmute(proc_addr) })
-    }
-
-    fn load_translate_mmnt(&self) -> Result<J2K_TranslateMMNT, EzTransError> {
-        let proc_addr = self.get_proc_address("J2K_TranslateMMNT")?;
-        Ok(unsafe { std::mem::transmute(proc_addr) })
-    }
-
-    fn load_translate_mmntw(&self) -> Result<J2K_TranslateMMNTW, EzTransError> {
-        let proc_addr = self.get_proc_address("J2K_TranslateMMNTW")?;
-        Ok(unsafe { std::mem::transmute(proc_addr) })
-    }
-
     /// DLL에서 함수 포인터들을 로드합니다.
     fn load_functions(&mut self) -> Result<(), EzTransError> {
         // 각 함수 포인터 로드 (필요한 것만 선택적으로)
-        self.free_mem = self.load_free_mem().ok();
-        self.get_prior_dict = self.load_get_prior_dict().ok();
-        self.get_property = self.load_get_property().ok();
-        self.initialize = self.load_initialize().ok();
-        self.initialize_ex = self.load_initialize_ex().ok();
-        self.reload_user_dict = self.load_reload_user_dict().ok();
-        self.set_del_jpn = self.load_set_del_jpn().ok();
-        self.set_field = self.load_set_field().ok();
-        self.set_hnj2han = self.load_set_hnj2han().ok();
-        self.set_jwin = self.load_set_jwin().ok();
-        self.set_prior_dict = self.load_set_prior_dict().ok();
-        self.set_property = self.load_set_property().ok();
-        self.stop_translation = self.load_stop_translation().ok();
-        self.terminate = self.load_terminate().ok();
-        self.translate_chat = self.load_translate_chat().ok();
-        self.translate_fm = self.load_translate_fm().ok();
-        self.translate_mm = self.load_translate_mm().ok();
-        self.translate_mmex = self.load_translate_mmex().ok();
-        self.translate_mmnt = self.load_translate_mmnt().ok();
-        self.translate_mmntw = self.load_translate_mmntw().ok();
+        self.free_mem = self.load_symbol(b"J2K_FreeMem\0").ok();
+        self.get_prior_dict = self.load_symbol(b"J2K_GetPriorDict\0").ok();
+        self.get_property = self.load_symbol(b"J2K_GetProperty\0").ok();
+        self.initialize = self.load_symbol(b"J2K_Initialize\0").ok();
+        self.initialize_ex = self.load_symbol(b"J2K_InitializeEx\0").ok();
+        self.reload_user_dict = self.load_symbol(b"J2K_ReloadUserDict\0").ok();
+        self.set_del_jpn = self.load_symbol(b"J2K_SetDelJPN\0").ok();
+        self.set_field = self.load_symbol(b"J2K_SetField\0").ok();
+        self.set_hnj2han = self.load_symbol(b"J2K_SetHnj2han\0").ok();
+        self.set_jwin = self.load_symbol(b"J2K_SetJWin\0").ok();
+        self.set_prior_dict = self.load_symbol(b"J2K_SetPriorDict\0").ok();
+        self.set_property = self.load_symbol(b"J2K_SetProperty\0").ok();
+        self.stop_translation = self.load_symbol(b"J2K_StopTranslation\0").ok();
+        self.terminate = self.load_symbol(b"J2K_Terminate\0").ok();
+        self.translate_chat = self.load_symbol(b"J2K_TranslateChat\0").ok();
+        self.translate_fm = self.load_symbol(b"J2K_TranslateFM\0").ok();
+        self.translate_mm = self.load_symbol(b"J2K_TranslateMM\0").ok();
+        self.translate_mmex = self.load_symbol(b"J2K_TranslateMMEx\0").ok();
+        self.translate_mmnt = self.load_symbol(b"J2K_TranslateMMNT\0").ok();
+        self.translate_mmntw = self.load_symbol(b"J2K_TranslateMMNTW\0").ok();
 
         // 필수 함수들이 로드되었는지 확인
         if self.initialize.is_none() && self.initialize_ex.is_none() {
@@ -308,6 +410,7 @@ impl EzTransEngine {
             EzTransError::FunctionLoadError("초기화 함수가 로드되지 않았습니다.".to_string())
         })?;
 
+        let _guard = self.call_lock.lock().unwrap();
         let result = unsafe { initialize_fn() };
         if result != 0 {
             return Err(EzTransError::FunctionCallFailed(format!(
@@ -321,22 +424,43 @@ impl EzTransEngine {
 
     /// EzTrans 엔진을 사용자 정의 설정으로 초기화합니다.
     pub fn initialize_ex(&self, path1: &str, path2: &str) -> Result<(), EzTransError> {
-        let initialize_ex_fn = self.initialize_ex.ok_or_else(|| {
-            EzTransError::FunctionLoadError("확장 초기화 함수가 로드되지 않았습니다.".to_string())
-        })?;
+        self.status
+            .track(crate::engine_status::EngineOperation::Initializing, || {
+                let initialize_ex_fn = self.initialize_ex.ok_or_else(|| {
+                    EzTransError::FunctionLoadError(
+                        "확장 초기화 함수가 로드되지 않았습니다.".to_string(),
+                    )
+                })?;
+
+                let c_path1 = CString::new(path1)?;
+                let c_path2 = CString::new(path2)?;
+
+                let _guard = self.call_lock.lock().unwrap();
+                let result = unsafe { initialize_ex_fn(c_path1.as_ptr(), c_path2.as_ptr()) };
+                if result != 1 {
+                    return Err(EzTransError::FunctionCallFailed(format!(
+                        "initialize_ex 함수가 실패했습니다. (코드: {})",
+                        result
+                    )));
+                }
 
-        let c_path1 = CString::new(path1)?;
-        let c_path2 = CString::new(path2)?;
+                Ok(())
+            })
+    }
 
-        let result = unsafe { initialize_ex_fn(c_path1.as_ptr(), c_path2.as_ptr()) };
-        if result != 1 {
-            return Err(EzTransError::FunctionCallFailed(format!(
-                "initialize_ex 함수가 실패했습니다. (코드: {})",
-                result
-            )));
-        }
+    /// 이 엔진이 지금 뭘 하고 있는지, 지금까지 번역/오류가 몇 번 있었는지 스냅샷으로
+    /// 돌려준다. `깨짐(corruption)` 카운트는 라이브러리가 스스로 판정할 수 없으므로,
+    /// 호출자가 [`crate::engine_status::EngineStatusTracker::record_corruption`]으로
+    /// 직접 보고해야 반영된다 — 이를 위해 [`status_tracker`](Self::status_tracker)가
+    /// 내부 장부를 참조로 노출한다.
+    pub fn status(&self) -> crate::engine_status::EngineStatus {
+        self.status.snapshot()
+    }
 
-        Ok(())
+    /// 깨진 출력을 발견한 호출자가 이 엔진의 장부에 직접 보고할 수 있도록 추적기를
+    /// 노출한다.
+    pub fn status_tracker(&self) -> &crate::engine_status::EngineStatusTracker {
+        &self.status
     }
 
     /// EzTrans 엔진을 종료합니다.
@@ -345,6 +469,7 @@ impl EzTransEngine {
             EzTransError::FunctionLoadError("종료 함수가 로드되지 않았습니다.".to_string())
         })?;
 
+        let _guard = self.call_lock.lock().unwrap();
         let result = unsafe { terminate_fn() };
         if result != 0 {
             return Err(EzTransError::FunctionCallFailed(format!(
@@ -364,6 +489,7 @@ impl EzTransEngine {
 
         let c_text = CString::new(text)?;
 
+        let _guard = self.call_lock.lock().unwrap();
         let result_ptr = unsafe { translate_fn(c_text.as_ptr()) };
         if result_ptr.is_null() {
             return Err(EzTransError::FunctionCallFailed(format!(
@@ -388,8 +514,42 @@ impl EzTransEngine {
         Ok(result)
     }
 
-    /// EHND를 사용하여 번역합니다.
+    /// EHND를 사용하여 번역합니다. `enable_translation_cache`가 켜져 있으면 동일한
+    /// (텍스트, 속성 상태) 조합을 다시 DLL에 보내지 않고 이전 결과를 돌려준다.
+    ///
+    /// J2KEngine.dll은 BMP 전용이라 U+10000 이상의 코드포인트나 ZWJ 이모지 시퀀스를
+    /// 통째로 깨뜨리므로, 보내기 전에 [`astral_protect`](crate::astral_protect)로 그런
+    /// 클러스터를 센티널로 치환해 뒀다가 받은 뒤 되돌린다.
     pub fn translate_mmntw(&self, input: &str) -> Result<String, EzTransError> {
+        let protected = crate::astral_protect::protect(input);
+        let translated = self
+            .status
+            .track(crate::engine_status::EngineOperation::Translating, || {
+                self.translate_mmntw_cached(&protected.text, || {
+                    self.translate_mmntw_uncached(&protected.text)
+                })
+            })?;
+        Ok(protected.restore(&translated))
+    }
+
+    fn translate_mmntw_uncached(&self, input: &str) -> Result<String, EzTransError> {
+        let units = self.call_translate_mmntw_raw(input)?;
+        Ok(crate::utf16_decode::decode_strict(&units)?)
+    }
+
+    /// [`translate_mmntw`]와 같은 DLL 호출을 쓰지만, UTF-16 디코딩 실패를 에러로 돌려주는
+    /// 대신 짝이 맞지 않는 서로게이트를 U+FFFD로 치환하고 계속 진행한다. 되돌려주는
+    /// `Vec<usize>`는 치환이 일어난 코드 단위 오프셋들이다.
+    ///
+    /// [`translate_mmnt_lossy`]와 같은 이유로 `translation_cache`를 거치지 않고 DLL을
+    /// 직접 호출한다.
+    pub fn translate_mmntw_lossy(&self, input: &str) -> Result<(String, Vec<usize>), EzTransError> {
+        let units = self.call_translate_mmntw_raw(input)?;
+        Ok(crate::utf16_decode::decode_lossy(&units))
+    }
+
+    /// `translate_mmntw` DLL 함수를 호출해 디코딩 전의 원본 UTF-16 코드 단위를 돌려준다.
+    fn call_translate_mmntw_raw(&self, input: &str) -> Result<Vec<u16>, EzTransError> {
         // Convert input to UTF-16 with NULL terminator
         let input_wide: Vec<u16> = input.encode_utf16().chain(std::iter::once(0)).collect();
 
@@ -399,28 +559,59 @@ impl EzTransEngine {
             )
         })?;
 
+        let _guard = self.call_lock.lock().unwrap();
         let ret = unsafe { translate_mmntw(0, input_wide.as_ptr()) };
         if ret.is_null() {
             return Err(EzTransError::TranslationError(TransErr::NullPointer));
         }
 
-        // 안전하게 UTF-16 문자열 처리 후 메모리 해제
-        let result = unsafe {
+        let units = unsafe {
             let len = (0..).find(|&i| *ret.add(i) == 0).unwrap_or(0);
-            let result = String::from_utf16(&std::slice::from_raw_parts(ret, len))?;
+            let units = std::slice::from_raw_parts(ret, len).to_vec();
 
-            // 메모리 해제
+            // 디코딩 성공 여부와 무관하게 먼저 메모리를 해제한다.
             if let Some(free_mem) = self.free_mem {
                 free_mem(ret as *mut c_void);
             }
 
-            result
+            units
         };
 
-        Ok(result)
+        Ok(units)
     }
 
+    /// `enable_translation_cache`가 켜져 있으면 동일한 (텍스트, 속성 상태) 조합을 다시
+    /// DLL에 보내지 않고 이전 결과를 돌려준다.
     pub fn translate_mmnt(&self, input: &str) -> Result<String, EzTransError> {
+        self.status
+            .track(crate::engine_status::EngineOperation::Translating, || {
+                self.translate_mmnt_cached(input, || self.translate_mmnt_uncached(input))
+            })
+    }
+
+    fn translate_mmnt_uncached(&self, input: &str) -> Result<String, EzTransError> {
+        let raw = self.call_translate_mmnt_raw(input)?;
+        Ok(crate::euc_kr_decode::decode_strict(&raw, self.output_encoding())?)
+    }
+
+    /// [`translate_mmnt`]와 같은 DLL 호출을 쓰지만, EUC-KR 디코딩 실패를 에러로 돌려주는
+    /// 대신 망가진 바이트를 U+FFFD로 치환하고 계속 진행한다. 되돌려주는 `Vec<usize>`는
+    /// 치환이 일어난 바이트 오프셋들이다.
+    ///
+    /// `translate_mmnt`/`translate_mmntw`는 번역 결과를 `Result<String, _>`로 캐싱하는
+    /// `translation_cache`를 거치는데, 이 함수의 반환 타입은 그 캐시 계약과 맞지 않아
+    /// (그리고 애초에 입력이 깨질 때 다시 시도해 볼 값어치가 있는 경우라) 캐시를 거치지
+    /// 않고 DLL을 직접 호출한다.
+    ///
+    /// EUC-KR 디코딩은 `translate_mmnt`(EUC-KR 출력 경로)에서만 일어난다 —
+    /// `translate_mmntw`는 UTF-16을 직접 받아 이 실패 모드 자체가 없다.
+    pub fn translate_mmnt_lossy(&self, input: &str) -> Result<(String, Vec<usize>), EzTransError> {
+        let raw = self.call_translate_mmnt_raw(input)?;
+        Ok(crate::euc_kr_decode::decode_lossy(&raw, self.output_encoding()))
+    }
+
+    /// `translate_mmnt` DLL 함수를 호출해 디코딩 전의 원본 EUC-KR 바이트를 돌려준다.
+    fn call_translate_mmnt_raw(&self, input: &str) -> Result<Vec<u8>, EzTransError> {
         // Convert input to Shift-JIS
         let input_sjis = encoding_rs::SHIFT_JIS.encode(input).0.to_vec();
 
@@ -430,45 +621,264 @@ impl EzTransEngine {
             )
         })?;
 
+        let _guard = self.call_lock.lock().unwrap();
         let ret = unsafe { translate_mmnt(0, input_sjis.as_ptr() as *mut c_char) };
         if ret.is_null() {
             return Err(EzTransError::TranslationError(TransErr::NullPointer));
         }
 
-        // EUC-KR에서 UTF-8로 변환 후 메모리 해제
-        let result = unsafe {
+        let bytes = unsafe {
             let c_str = CStr::from_ptr(ret);
-            let (decoded, _, had_errors) = encoding_rs::EUC_KR.decode(c_str.to_bytes());
+            let bytes = c_str.to_bytes().to_vec();
 
             // 메모리 해제
             if let Some(free_mem) = self.free_mem {
                 free_mem(ret as *mut c_void);
             }
 
-            if had_errors {
-                return Err(EzTransError::TranslationError(TransErr::EucKrDecodeFailed));
+            bytes
+        };
+
+        Ok(bytes)
+    }
+
+    /// 사용자 용어집을 등록합니다. `target`이 `Some`이면 번역을 생략하고 그 텍스트로
+    /// 바로 치환하며, `None`이면 원문(고유명사, 제어 코드, HTML/루비 태그 등)을 그대로
+    /// 보존합니다. 자동자는 이 호출 시점에 한 번만 빌드되어 이후 `default_translate`
+    /// 호출 전부가 재사용합니다. `leftmost-longest` 매칭이므로 겹치는 항목 중 가장 긴
+    /// 쪽이 greedy하게, non-overlapping하게 소비됩니다.
+    pub fn set_glossary(&self, terms: &[(String, Option<String>)]) -> Result<(), EzTransError> {
+        *self.glossary_terms.lock().unwrap() = terms.to_vec();
+        self.rebuild_pre_glossary(terms)
+    }
+
+    /// `terms`로 PRE 자동자를 통째로 다시 빌드해 `self.glossary`에 캐시한다. 원본
+    /// (원문, 치환어) 목록은 호출자(`set_glossary`/`add_term`/`add_terms`)가
+    /// `self.glossary_terms`에 미리 반영해 둔다.
+    fn rebuild_pre_glossary(&self, terms: &[(String, Option<String>)]) -> Result<(), EzTransError> {
+        if terms.is_empty() {
+            *self.glossary.lock().unwrap() = None;
+            return Ok(());
+        }
+
+        let keys: Vec<&str> = terms.iter().map(|(source, _)| source.as_str()).collect();
+        let automaton = AhoCorasickBuilder::new()
+            .match_kind(MatchKind::LeftmostLongest)
+            .build(&keys)
+            .map_err(|e| {
+                EzTransError::FunctionLoadError(format!("용어집 자동자 생성 실패: {e}"))
+            })?;
+
+        let entries = terms
+            .iter()
+            .map(|(source, target)| match target {
+                Some(target) => GlossaryEntry::Translated(target.clone()),
+                None => GlossaryEntry::Protected(source.clone()),
+            })
+            .collect();
+
+        *self.glossary.lock().unwrap() = Some(UserGlossary { automaton, entries });
+        Ok(())
+    }
+
+    /// `terms`로 POST 자동자를 통째로 다시 빌드해 `self.post_glossary`에 캐시한다.
+    fn rebuild_post_glossary(&self, terms: &[(String, String)]) -> Result<(), EzTransError> {
+        if terms.is_empty() {
+            *self.post_glossary.lock().unwrap() = None;
+            return Ok(());
+        }
+
+        let keys: Vec<&str> = terms.iter().map(|(source, _)| source.as_str()).collect();
+        let automaton = AhoCorasickBuilder::new()
+            .match_kind(MatchKind::LeftmostLongest)
+            .build(&keys)
+            .map_err(|e| {
+                EzTransError::FunctionLoadError(format!("교정 용어집 자동자 생성 실패: {e}"))
+            })?;
+
+        let replacements = terms.iter().map(|(_, target)| target.clone()).collect();
+
+        *self.post_glossary.lock().unwrap() = Some(PostGlossary { automaton, replacements });
+        Ok(())
+    }
+
+    /// 용어 하나를 등록한다. 이전까지 같은 모드로 등록된 용어 위에 이어서 쌓이며,
+    /// 자동자가 즉시 다시 빌드된다. [`GlossaryMode::Post`]에는 `target`이 필수다.
+    pub fn add_term(
+        &self,
+        source: &str,
+        target: Option<&str>,
+        mode: GlossaryMode,
+    ) -> Result<(), EzTransError> {
+        self.add_terms(&[(source.to_string(), target.map(str::to_string))], mode)
+    }
+
+    /// [`add_term`](Self::add_term)의 여러 건 버전. 자동자를 한 번만 다시 빌드하므로
+    /// 수천 건을 한꺼번에 등록할 때도 `add_term`을 반복 호출하는 것보다 훨씬 빠르다.
+    pub fn add_terms(
+        &self,
+        terms: &[(String, Option<String>)],
+        mode: GlossaryMode,
+    ) -> Result<(), EzTransError> {
+        match mode {
+            GlossaryMode::Pre => {
+                let mut raw = self.glossary_terms.lock().unwrap();
+                raw.extend(terms.iter().cloned());
+                let combined = raw.clone();
+                drop(raw);
+                self.rebuild_pre_glossary(&combined)
             }
+            GlossaryMode::Post => {
+                let mut raw = self.post_glossary_terms.lock().unwrap();
+                for (source, target) in terms {
+                    let target = target.clone().ok_or_else(|| {
+                        EzTransError::FunctionLoadError(
+                            "GlossaryMode::Post 용어는 교정할 텍스트(target)가 필요합니다."
+                                .to_string(),
+                        )
+                    })?;
+                    raw.push((source.clone(), target));
+                }
+                let combined = raw.clone();
+                drop(raw);
+                self.rebuild_post_glossary(&combined)
+            }
+        }
+    }
+
+    /// PRE/POST 용어집을 모두 비운다.
+    pub fn clear_glossary(&self) {
+        *self.glossary_terms.lock().unwrap() = Vec::new();
+        *self.glossary.lock().unwrap() = None;
+        *self.post_glossary_terms.lock().unwrap() = Vec::new();
+        *self.post_glossary.lock().unwrap() = None;
+    }
 
-            decoded.into_owned()
+    /// 등록된 POST 용어집으로 번역 결과를 한 번 훑어 교정한다. 용어집이 없으면
+    /// 입력을 그대로 돌려준다.
+    fn apply_post_glossary(&self, text: &str) -> String {
+        let guard = self.post_glossary.lock().unwrap();
+        let Some(post) = guard.as_ref() else {
+            return text.to_string();
         };
 
-        Ok(result)
+        let mut output = String::with_capacity(text.len());
+        let mut last_end = 0;
+        for mat in post.automaton.find_iter(text) {
+            output.push_str(&text[last_end..mat.start()]);
+            output.push_str(&post.replacements[mat.pattern().as_usize()]);
+            last_end = mat.end();
+        }
+        output.push_str(&text[last_end..]);
+        output
+    }
+
+    /// 등록된 용어집으로 한 번 훑어, 매치된 구간을 `+xXXXX` 형태의 자리표시자로
+    /// 치환합니다. `hangul_encode`가 일반 ASCII 문자를 그대로 통과시키므로 이 자리
+    /// 표시자 역시 건드리지 않고 통과하며, 번역 후 `hangul_decode`가 이를 사설 영역
+    /// 문자로 되돌려 놓으면 `restore_glossary`가 최종 텍스트로 복원합니다.
+    fn apply_glossary(&self, input: &str) -> String {
+        let guard = self.glossary.lock().unwrap();
+        let Some(glossary) = guard.as_ref() else {
+            return input.to_string();
+        };
+
+        let mut output = String::with_capacity(input.len());
+        let mut last_end = 0;
+        for mat in glossary.automaton.find_iter(input) {
+            output.push_str(&input[last_end..mat.start()]);
+            let sentinel = GLOSSARY_SENTINEL_BASE + mat.pattern().as_u32();
+            write!(&mut output, "+x{sentinel:04X}").unwrap();
+            last_end = mat.end();
+        }
+        output.push_str(&input[last_end..]);
+        output
+    }
+
+    /// `apply_glossary`가 남겨 둔 사설 영역 문자를 최종 치환 텍스트로 복원합니다.
+    /// 용어집이 등록되어 있지 않으면 입력을 그대로 돌려줍니다.
+    fn restore_glossary(&self, text: &str) -> String {
+        let guard = self.glossary.lock().unwrap();
+        let Some(glossary) = guard.as_ref() else {
+            return text.to_string();
+        };
+
+        let mut output = String::with_capacity(text.len());
+        for c in text.chars() {
+            let index = (c as u32).wrapping_sub(GLOSSARY_SENTINEL_BASE) as usize;
+            match glossary.entries.get(index) {
+                Some(GlossaryEntry::Translated(replacement)) => output.push_str(replacement),
+                Some(GlossaryEntry::Protected(original)) => output.push_str(original),
+                None => output.push(c),
+            }
+        }
+        output
     }
 
     pub fn default_translate(&self, input: &str) -> Result<String, EzTransError> {
-        // 인코딩이 필요한지 빠르게 확인 (한글/특수문자 있는지)
-        let needs_encoding = input.chars().any(|c| {
-            c == '@'
-                || c == '\0'
-                || self.is_hangul_range(c as u32)
-                || self.special_chars.contains(&c)
-        });
+        let astral_protected = crate::astral_protect::protect(input);
+        let result = self.default_translate_inner(&astral_protected.text)?;
+        Ok(astral_protected.restore(&result))
+    }
+
+    /// `default_translate`을 실행하고, [`crate::output_validator::OutputValidator`]가
+    /// 출력이 손상되었다고 판정하면 최대 `retries`번까지 다시 시도한다. `tests/
+    /// thread_safety_test.rs`가 드러낸 버퍼 뒤섞임은 같은 스레드에서 다시 호출하면
+    /// 사라지는 경우가 많으므로, 재시도만으로도 상당수는 회복된다. `retries`를 다
+    /// 써도 손상된 채면 마지막 출력을 담아 [`EzTransError::CorruptedOutput`]을
+    /// 돌려준다.
+    pub fn translate_validated(&self, input: &str, retries: usize) -> Result<String, EzTransError> {
+        self.translate_validated_with(&crate::output_validator::OutputValidator::default(), input, retries)
+    }
+
+    /// [`Self::translate_validated`]와 같지만 손상 판정 기준을 직접 고를 수 있다.
+    pub fn translate_validated_with(
+        &self,
+        validator: &crate::output_validator::OutputValidator,
+        input: &str,
+        retries: usize,
+    ) -> Result<String, EzTransError> {
+        let mut last_output = String::new();
+        for _ in 0..=retries {
+            let output = self.default_translate(input)?;
+            if !validator.is_corrupted(input, &output) {
+                return Ok(output);
+            }
+            last_output = output;
+        }
 
-        // 필요한 경우만 인코딩 수행
+        Err(EzTransError::CorruptedOutput {
+            input: input.to_string(),
+            output: last_output,
+        })
+    }
+
+    fn default_translate_inner(&self, input: &str) -> Result<String, EzTransError> {
+        let glossary_protected = self.apply_glossary(input);
+        let protected = if *self.normalize_compat.lock().unwrap() {
+            self.normalize_compat_pass(&glossary_protected)
+        } else {
+            glossary_protected
+        };
+
+        // 인코딩이 필요한지 빠르게 확인 (한글/특수문자 있는지, 혹은 용어집 자리표시자나
+        // 정규화 자리표시자가 생겼는지). 그런 자리표시자는 hangul_decode가 디코딩해 줘야
+        // 사설 영역 문자로 돌아오므로, 매치가 하나라도 있었다면 무조건 인코딩/디코딩
+        // 경로를 탄다.
+        let needs_encoding = protected != input
+            || protected.chars().any(|c| {
+                c == '@'
+                    || c == '\0'
+                    || self.is_hangul_range(c as u32)
+                    || self.special_chars.contains(&c)
+            });
+
+        // 필요한 경우만 인코딩 수행. 자소 클러스터 단위로 판정해, 결합 문자나 ZWJ
+        // 시퀀스가 인코딩 경계에서 쪼개지지 않게 한다.
         let encoded = if needs_encoding {
-            self.hangul_encode(input)
+            self.hangul_encode_clusters(&protected)
         } else {
-            input.to_string()
+            protected
         };
 
         // EHND 또는 기본 번역 선택
@@ -485,17 +895,68 @@ impl EzTransEngine {
             translated
         };
 
-        Ok(result)
+        let restored = self.restore_glossary(&result);
+        Ok(self.apply_post_glossary(&restored))
+    }
+
+    /// `default_translate`와 같은 용어집(전/후)/정규화 단계를 거치고 `hangul_encode`로
+    /// 인코딩하되(클러스터 단위 인코딩과 `astral_protect` 보호는 하지 않는다), 인코딩된 문자열을
+    /// [`TranslationChunker`]로 `max_len` 바이트 이하 조각들로 나눠 따로따로 DLL에
+    /// 보낸다. 한 번의 호출로 보내기엔 너무 긴 입력이 J2KEngine.dll의 버퍼 한도에
+    /// 걸려 잘리거나 깨지는 것을 막기 위함이다. 조각 경계는 `hangul_encode`/용어집이
+    /// 남긴 `+x1234` 이스케이프를 쪼개지 않으므로, 각 조각을 따로 번역해 이어 붙인
+    /// 뒤 한 번에 디코딩해도 전체를 한 번에 보낸 것과 같은 결과를 얻는다.
+    pub fn translate_stream(&self, input: &str, max_len: usize) -> Result<String, EzTransError> {
+        let glossary_protected = self.apply_glossary(input);
+        let protected = if *self.normalize_compat.lock().unwrap() {
+            self.normalize_compat_pass(&glossary_protected)
+        } else {
+            glossary_protected
+        };
+
+        let needs_encoding = protected != input
+            || protected.chars().any(|c| {
+                c == '@'
+                    || c == '\0'
+                    || self.is_hangul_range(c as u32)
+                    || self.special_chars.contains(&c)
+            });
+
+        let encoded = if needs_encoding {
+            self.hangul_encode(&protected)
+        } else {
+            protected
+        };
+
+        let mut translated = String::with_capacity(encoded.len());
+        for chunk in TranslationChunker::new(&encoded, max_len) {
+            let result = if self.initialize_ex.is_some() {
+                self.translate_mmntw(chunk)?
+            } else {
+                self.translate_mmnt(chunk)?
+            };
+            translated.push_str(&result);
+        }
+
+        let result = if needs_encoding {
+            self.hangul_decode(&translated)
+        } else {
+            translated
+        };
+
+        let restored = self.restore_glossary(&result);
+        Ok(self.apply_post_glossary(&restored))
     }
 
     /// 한글 및 특수 문자를 16진수 유니코드로 인코딩
     pub fn hangul_encode(&self, input: &str) -> String {
         let mut output = String::with_capacity(input.len() * 2);
+        let problematic = self.problematic_chars.lock().unwrap();
 
         for c in input.chars() {
             if c == '@' || c == '\0' || self.is_hangul_range(c as u32) {
                 write!(&mut output, "+x{:04X}", c as u32).unwrap();
-            } else if self.special_chars.contains(&c) {
+            } else if self.special_chars.contains(&c) || problematic.contains(c as u32) {
                 write!(&mut output, "+X{:04X}", c as u32).unwrap();
             } else {
                 output.push(c);
@@ -505,6 +966,62 @@ impl EzTransEngine {
         output
     }
 
+    /// `hangul_encode`가 `special_chars`/한글 범위에 더해 이스케이프할 코드포인트
+    /// 집합을 통째로 바꿔 끼운다. DLL/Dat을 업그레이드한 뒤
+    /// [`crate::char_safety_probe::CharSafetyProbe::problematic_chars`]로 새로 만든
+    /// 테이블을 싣는 용도다.
+    pub fn load_problematic_chars(&self, set: crate::problematic_char_set::ProblematicCharSet) {
+        *self.problematic_chars.lock().unwrap() = set;
+    }
+
+    /// 확장 자소 클러스터(UAX #29 기준, `unicode_segmentation`에 위임) 하나에 속한
+    /// 코드포인트 중 하나라도 `hangul_encode`의 이스케이프 대상(제어 문자, 한글 범위,
+    /// `special_chars`, `problematic_chars`)이면 참을 돌려준다.
+    ///
+    /// `hangul_encode`는 코드포인트 하나하나를 독립적으로 검사하므로, 기반 문자 혼자는
+    /// 안전해도 뒤따르는 결합 표식(U+0300–036F 등)이나 ZWJ(U+200D)로 이어 붙은
+    /// 시퀀스가 있어야만 비로소 문제가 되는 경우를 놓친다. 이 함수는 클러스터 전체를
+    /// 보고 판정하므로 그런 경우도 잡아낸다.
+    pub fn needs_encoding_cluster(&self, cluster: &str) -> bool {
+        let problematic = self.problematic_chars.lock().unwrap();
+        cluster.chars().any(|c| {
+            c == '@'
+                || c == '\0'
+                || self.is_hangul_range(c as u32)
+                || self.special_chars.contains(&c)
+                || problematic.contains(c as u32)
+        })
+    }
+
+    /// `hangul_encode`를 확장 자소 클러스터 단위로 다시 구현한 것.
+    ///
+    /// 결합 문자 시퀀스나 ZWJ 이모지처럼 여러 코드포인트가 한 덩어리여야 하는
+    /// 클러스터가 `hangul_encode`의 코드포인트별 판정 때문에 인코딩 경계에서 쪼개지면,
+    /// 기반 문자만 엔진에 그대로 넘어가 결합 문자와 떨어진 채 따로 번역되거나 위치가
+    /// 바뀔 수 있다. 여기서는 [`needs_encoding_cluster`](Self::needs_encoding_cluster)로
+    /// 클러스터 전체를 먼저 판정해, 하나라도 이스케이프 대상이면 클러스터에 속한
+    /// 코드포인트 전부를 한 덩어리로 이스케이프한다. 마커 형식이 `hangul_encode`와
+    /// 같으므로 복원은 기존 `hangul_decode`가 그대로 담당한다.
+    pub fn hangul_encode_clusters(&self, input: &str) -> String {
+        let mut output = String::with_capacity(input.len() * 2);
+
+        for cluster in input.graphemes(true) {
+            if self.needs_encoding_cluster(cluster) {
+                for c in cluster.chars() {
+                    if c == '@' || c == '\0' || self.is_hangul_range(c as u32) {
+                        write!(&mut output, "+x{:04X}", c as u32).unwrap();
+                    } else {
+                        write!(&mut output, "+X{:04X}", c as u32).unwrap();
+                    }
+                }
+            } else {
+                output.push_str(cluster);
+            }
+        }
+
+        output
+    }
+
     /// 한글 문자 범위 판별 (유니코드 범위 확인)
     #[inline]
     pub const fn is_hangul_range(&self, code: u32) -> bool {
@@ -565,6 +1082,7 @@ impl EzTransEngine {
 
         let c_text = CString::new(text)?;
 
+        let _guard = self.call_lock.lock().unwrap();
         let result_ptr = unsafe { translate_fn(c_text.as_ptr()) };
         if result_ptr.is_null() {
             return Err(EzTransError::FunctionCallFailed(format!(
@@ -595,6 +1113,7 @@ impl EzTransEngine {
             EzTransError::FunctionLoadError("분야 설정 함수가 로드되지 않았습니다.".to_string())
         })?;
 
+        let _guard = self.call_lock.lock().unwrap();
         let result = unsafe { set_field_fn(field) };
         if result != 0 {
             return Err(EzTransError::FunctionCallFailed(format!(
@@ -614,6 +1133,7 @@ impl EzTransEngine {
             )
         })?;
 
+        let _guard = self.call_lock.lock().unwrap();
         let result = unsafe { set_hnj2han_fn(option) };
         if result != 0 {
             return Err(EzTransError::FunctionCallFailed(format!(
@@ -631,6 +1151,7 @@ impl EzTransEngine {
             EzTransError::FunctionLoadError("사전 로드 함수가 로드되지 않았습니다.".to_string())
         })?;
 
+        let _guard = self.call_lock.lock().unwrap();
         let result = unsafe { reload_fn() };
         if result != 0 {
             return Err(EzTransError::FunctionCallFailed(format!(
@@ -643,6 +1164,7 @@ impl EzTransEngine {
     }
 
     /// 일본어 문장 구분 기능을 설정합니다.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), ret, err))]
     pub fn set_del_jpn(&self, option: c_int) -> Result<(), EzTransError> {
         let set_del_jpn_fn = self.set_del_jpn.ok_or_else(|| {
             EzTransError::FunctionLoadError(
@@ -650,6 +1172,7 @@ impl EzTransEngine {
             )
         })?;
 
+        let _guard = self.call_lock.lock().unwrap();
         let result = unsafe { set_del_jpn_fn(option) };
         if result != 0 {
             return Err(EzTransError::FunctionCallFailed(format!(
@@ -662,6 +1185,7 @@ impl EzTransEngine {
     }
 
     /// J-Win 모드를 설정합니다.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), ret, err))]
     pub fn set_jwin(&self, option: c_int) -> Result<(), EzTransError> {
         let set_jwin_fn = self.set_jwin.ok_or_else(|| {
             EzTransError::FunctionLoadError(
@@ -669,6 +1193,7 @@ impl EzTransEngine {
             )
         })?;
 
+        let _guard = self.call_lock.lock().unwrap();
         let result = unsafe { set_jwin_fn(option) };
         if result != 0 {
             return Err(EzTransError::FunctionCallFailed(format!(
@@ -681,6 +1206,10 @@ impl EzTransEngine {
     }
 
     /// 사용자 사전의 우선순위를 설정합니다.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), fields(dict_path), ret, err)
+    )]
     pub fn set_prior_dict(&self, dict_path: &str) -> Result<(), EzTransError> {
         let set_prior_dict_fn = self.set_prior_dict.ok_or_else(|| {
             EzTransError::FunctionLoadError(
@@ -690,6 +1219,7 @@ impl EzTransEngine {
 
         let c_path = CString::new(dict_path)?;
 
+        let _guard = self.call_lock.lock().unwrap();
         let result = unsafe { set_prior_dict_fn(c_path.as_ptr()) };
         if result != 0 {
             return Err(EzTransError::FunctionCallFailed(format!(
@@ -702,11 +1232,13 @@ impl EzTransEngine {
     }
 
     /// 특정 속성의 값을 설정합니다.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), ret, err))]
     pub fn set_property(&self, property_id: c_int, value: c_int) -> Result<(), EzTransError> {
         let set_property_fn = self.set_property.ok_or_else(|| {
             EzTransError::FunctionLoadError("속성 설정 함수가 로드되지 않았습니다.".to_string())
         })?;
 
+        let _guard = self.call_lock.lock().unwrap();
         let result = unsafe { set_property_fn(property_id, value) };
         if result != 0 {
             return Err(EzTransError::FunctionCallFailed(
@@ -714,26 +1246,34 @@ impl EzTransEngine {
             ));
         }
 
+        // 이후 번역 결과가 달라질 수 있으므로, 캐시 키에 반영되도록 현재 속성값을
+        // 기록해 둔다.
+        self.translation_cache.record_property(property_id, value);
+
         Ok(())
     }
 
     /// 특정 속성의 현재 값을 가져옵니다.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), ret, err))]
     pub fn get_property(&self, property_id: c_int) -> Result<c_int, EzTransError> {
         let get_property_fn = self.get_property.ok_or_else(|| {
             EzTransError::FunctionLoadError("속성 조회 함수가 로드되지 않았습니다.".to_string())
         })?;
 
+        let _guard = self.call_lock.lock().unwrap();
         let result = unsafe { get_property_fn(property_id) };
         // 속성 값 조회는 일반적으로 실패하지 않으므로 결과를 그대로 반환
         Ok(result)
     }
 
     /// 현재 진행 중인 번역 작업을 중지합니다.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), ret, err))]
     pub fn stop_translation(&self) -> Result<(), EzTransError> {
         let stop_fn = self.stop_translation.ok_or_else(|| {
             EzTransError::FunctionLoadError("번역 중지 함수가 로드되지 않았습니다.".to_string())
         })?;
 
+        let _guard = self.call_lock.lock().unwrap();
         let result = unsafe { stop_fn() };
         if result != 0 {
             return Err(EzTransError::FunctionCallFailed(format!(
@@ -746,6 +1286,14 @@ impl EzTransEngine {
     }
 
     /// 전문 번역 모드에서 텍스트를 번역합니다.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip(self, text),
+            fields(text = %text.chars().take(40).collect::<String>()),
+            err
+        )
+    )]
     pub fn translate_fm(&self, text: &str) -> Result<String, EzTransError> {
         let translate_fn = self.translate_fm.ok_or_else(|| {
             EzTransError::FunctionLoadError("전문 번역 함수가 로드되지 않았습니다.".to_string())
@@ -753,6 +1301,7 @@ impl EzTransEngine {
 
         let c_text = CString::new(text)?;
 
+        let _guard = self.call_lock.lock().unwrap();
         let result_ptr = unsafe { translate_fn(c_text.as_ptr()) };
         if result_ptr.is_null() {
             return Err(EzTransError::FunctionCallFailed(format!(
@@ -778,8 +1327,9 @@ impl EzTransEngine {
     }
 }
 
-// Drop 트레이트를 구현하여 자동으로 DLL을 언로드
-impl Drop for EzTransEngine {
+// Drop 트레이트를 구현하여 엔진 종료를 보장. 마지막 `Arc` 참조가 사라질 때 한 번만
+// 실행되며, DLL 자체는 `self.library`가 드롭되며 알아서 언로드된다.
+impl Drop for EzTransInner {
     fn drop(&mut self) {
         // 엔진 종료 시도 (에러는 무시)
         if let Some(terminate_fn) = self.terminate {
@@ -787,10 +1337,5 @@ impl Drop for EzTransEngine {
                 terminate_fn();
             }
         }
-
-        // DLL 언로드
-        unsafe {
-            let _ = FreeLibrary(self.module);
-        }
     }
 }
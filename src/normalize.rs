@@ -0,0 +1,136 @@
+// 번역 전에 거치는 선택적 유니코드 정규화 단계.
+//
+// `analyze_problematic_ranges`가 모아 둔 "문제 문자" 상당수는 ㎡, ℃, ①, ½, 전각
+// ASCII처럼 호환 변형(compatibility variant)일 뿐이라, NFKC로 정규화하면 엔진이 이미
+// 잘 번역하는 시퀀스(m2, °C, 1, 1⁄2, 반각 ASCII)로 바뀐다. `hangul_encode`/
+// `hangul_decode`로 감싸야 하는 문자 수가 줄어 왕복 비용도 줄어든다.
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{EzTransError, EzTransInner};
+
+/// `translate_normalized`가 번역 전에 적용할 정규화 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NormalizationMode {
+    /// 정규화하지 않고 원문 그대로 전달한다.
+    #[default]
+    None,
+    /// 정준 정규화(NFC). 분해된 결합 문자 시퀀스를 조합된 형태로 모은다.
+    Canonical,
+    /// 호환 정규화(NFKC). 전각/이모지화된 기호, 동그라미 숫자, 단위 기호 등을
+    /// 의미가 같은 ASCII/일반 시퀀스로 풀어 쓴다.
+    Compatibility,
+}
+
+impl NormalizationMode {
+    fn apply(self, input: &str) -> String {
+        match self {
+            NormalizationMode::None => input.to_string(),
+            // 한글 자모 조합은 `crate::hangul_compose`의 self-contained 구현을 먼저
+            // 돌리고(분해된 자모 전용), 나머지 결합 문자(라틴 악센트 등)는 `.nfc()`에
+            // 맡긴다. 이미 조합된 음절에는 `.nfc()`가 손댈 게 없으므로 멱등하다.
+            NormalizationMode::Canonical => crate::hangul_compose::compose(input).nfc().collect(),
+            NormalizationMode::Compatibility => input.nfkc().collect(),
+        }
+    }
+}
+
+impl EzTransInner {
+    /// `mode`로 `input`을 정규화한 뒤 [`default_translate`](Self::default_translate)로
+    /// 번역한다.
+    pub fn translate_normalized(
+        &self,
+        input: &str,
+        mode: NormalizationMode,
+    ) -> Result<String, EzTransError> {
+        self.default_translate(&mode.apply(input))
+    }
+
+    /// `normalize_compat` 설정을 켜고 끈다. 켜져 있으면 이후 모든
+    /// [`default_translate`](Self::default_translate) 호출이 인코딩 전에
+    /// [`normalize_compat_pass`](Self::normalize_compat_pass)를 거친다.
+    pub fn set_normalize_compat(&self, enabled: bool) {
+        *self.normalize_compat.lock().unwrap() = enabled;
+    }
+
+    /// `default_translate`가 `normalize_compat`이 켜져 있을 때 인코딩 전에 적용하는
+    /// 정규화 단계.
+    ///
+    /// 먼저 [`CURATED_COMPAT`] 교정 표로 표준 NFKC 분해보다 읽기 좋은 결과가 필요한
+    /// 문자(예: `㎕`를 소문자 "μl"이 아니라 단위 표기 관례상 "μL"로)를 치환한 뒤, 남은
+    /// 텍스트 전체에 NFKC를 적용해 전각 ASCII·동그라미/괄호 숫자 등을 엔진이 이미 잘
+    /// 번역하는 일반 시퀀스로 풀어 쓴다. NFKC를 거치고도 여전히 엔진이 그대로 통과시킬
+    /// 수 없는 문자가 남아 있으면(`is_engine_safe_char`가 거짓), 조용히 깨지는 대신
+    /// 기존 `+X{:04X}` 자리표시자로 escape해 `hangul_decode`가 나중에 복원하게 한다.
+    pub(crate) fn normalize_compat_pass(&self, input: &str) -> String {
+        let curated = apply_curated(input);
+        let nfkc: String = curated.nfkc().collect();
+
+        let mut output = String::with_capacity(nfkc.len());
+        for c in nfkc.chars() {
+            if self.is_engine_safe_char(c) {
+                output.push(c);
+            } else {
+                use std::fmt::Write;
+                write!(&mut output, "+X{:04X}", c as u32).unwrap();
+            }
+        }
+        output
+    }
+
+    /// `c`가 엔진의 기존 경로(ASCII, 한글 범위, `special_chars`,
+    /// [`is_safe_chars`](crate::char_ranges::is_safe_chars)) 중 하나로 이미 안전하게
+    /// 처리되는 문자인지 확인한다.
+    fn is_engine_safe_char(&self, c: char) -> bool {
+        c.is_ascii()
+            || self.is_hangul_range(c as u32)
+            || self.special_chars.contains(&c)
+            || crate::char_ranges::is_safe_chars(c)
+    }
+}
+
+/// 표준 NFKC 호환 분해보다 읽기 좋은 결과로 오버라이드하고 싶은 문자들의 교정 표.
+/// `㎕`/`㎖`의 NFKC 분해는 소문자 "μl"/"ml"이지만, 단위 표기 관례상 리터 기호는
+/// 대문자 L로 쓰는 쪽이 더 흔하므로 여기서 먼저 치환해 둔다.
+const CURATED_COMPAT: &[(char, &str)] = &[('㎕', "μL"), ('㎖', "mL"), ('㏈', "dB")];
+
+/// `CURATED_COMPAT`에 등록된 문자를 치환한다. 등록되지 않은 문자는 그대로 둔다.
+fn apply_curated(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    for c in input.chars() {
+        match CURATED_COMPAT.iter().find(|(from, _)| *from == c) {
+            Some((_, to)) => output.push_str(to),
+            None => output.push(c),
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_none_leaves_input_untouched() {
+        assert_eq!(NormalizationMode::None.apply("㎡①½"), "㎡①½");
+    }
+
+    #[test]
+    fn test_apply_curated_overrides_unit_symbols() {
+        assert_eq!(apply_curated("㎕"), "μL");
+        assert_eq!(apply_curated("㎖"), "mL");
+        assert_eq!(apply_curated("㏈"), "dB");
+    }
+
+    #[test]
+    fn test_apply_curated_leaves_uncurated_chars_untouched() {
+        assert_eq!(apply_curated("①½Ａ"), "①½Ａ");
+    }
+
+    #[test]
+    fn test_compatibility_decomposes_symbols() {
+        assert_eq!(NormalizationMode::Compatibility.apply("①"), "1");
+        assert_eq!(NormalizationMode::Compatibility.apply("½"), "1⁄2");
+        assert_eq!(NormalizationMode::Compatibility.apply("Ａ"), "A");
+    }
+}
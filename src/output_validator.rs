@@ -0,0 +1,155 @@
+// `tests/thread_safety_test.rs`의 `is_corrupted`를 1급 라이브러리 타입으로 끌어올린 것.
+//
+// 그 휴리스틱(빈 출력, 널/제어 문자, 한글·구두점 비율 임계값)은 테스트 파일에 묻혀
+// 있었지만 실제로는 "DLL이 언제 쓰레기를 내놓는지"에 대한 실전 지식이다.
+// `OutputValidator`는 그 지식을 공개 타입으로 옮기고 임계값/제어 문자 정책을
+// 설정할 수 있게 해, `EzTransEngine::translate_validated`/`engine_pool::EzTransPool`이
+// 같은 기준으로 재시도 여부를 판단할 수 있게 한다.
+
+/// 제어 문자를 손상 신호로 볼지 말지.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlCharPolicy {
+    /// 개행(`\n`, `\r`)과 탭을 제외한 모든 제어 문자를 손상으로 본다.
+    RejectExceptNewlineAndTab,
+    /// 제어 문자는 검사하지 않는다. 단, 널 문자(`\0`)는 정책과 관계없이 손상으로 본다.
+    Ignore,
+}
+
+/// 번역 출력이 손상되었는지 판정하는 규칙 집합.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OutputValidator {
+    /// 출력 길이가 `min_len_for_ratio_check`를 넘을 때, 한글/구두점/공백 비율이 이
+    /// 값보다 낮으면 손상으로 본다.
+    pub hangul_ratio_threshold: f64,
+    /// 한글 비율 검사를 적용할 최소 출력 길이. 너무 짧은 출력은 비율이 들쭉날쭉해
+    /// 오탐이 잦으므로 건너뛴다.
+    pub min_len_for_ratio_check: usize,
+    pub control_char_policy: ControlCharPolicy,
+}
+
+impl Default for OutputValidator {
+    /// `tests/thread_safety_test.rs`의 `is_corrupted`와 같은 기본값.
+    fn default() -> Self {
+        Self {
+            hangul_ratio_threshold: 0.3,
+            min_len_for_ratio_check: 5,
+            control_char_policy: ControlCharPolicy::RejectExceptNewlineAndTab,
+        }
+    }
+}
+
+impl OutputValidator {
+    pub fn new(
+        hangul_ratio_threshold: f64,
+        min_len_for_ratio_check: usize,
+        control_char_policy: ControlCharPolicy,
+    ) -> Self {
+        Self {
+            hangul_ratio_threshold,
+            min_len_for_ratio_check,
+            control_char_policy,
+        }
+    }
+
+    /// `input`이 비어 있지 않은데 `output`이 비었거나, 정책에 걸리는 제어 문자가
+    /// 있거나, 한글/구두점 비율이 임계값 밑이면 손상으로 본다.
+    pub fn is_corrupted(&self, input: &str, output: &str) -> bool {
+        if !input.is_empty() && output.is_empty() {
+            return true;
+        }
+
+        if output.contains('\0') {
+            return true;
+        }
+
+        if self.control_char_policy == ControlCharPolicy::RejectExceptNewlineAndTab {
+            for c in output.chars() {
+                if c.is_control() && c != '\n' && c != '\r' && c != '\t' {
+                    return true;
+                }
+            }
+        }
+
+        let total = output.chars().count();
+        if total > self.min_len_for_ratio_check {
+            let valid_count = output.chars().filter(|&c| is_hangul_or_punct(c)).count();
+            let ratio = valid_count as f64 / total as f64;
+            if ratio < self.hangul_ratio_threshold {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// `is_corrupted`를 통과하더라도 다른 스레드의 입력과 뒤섞인(버퍼 공유로 인한)
+    /// 결과일 수 있다 — 정답으로 알려진 조각 중 하나라도 포함하는지 추가로 확인한다.
+    /// `expected_substrings`가 비어 있으면 항상 통과시킨다(알려진 정답이 없는 경우).
+    pub fn matches_expected(&self, output: &str, expected_substrings: &[&str]) -> bool {
+        expected_substrings.is_empty() || expected_substrings.iter().any(|exp| output.contains(exp))
+    }
+}
+
+fn is_hangul_or_punct(c: char) -> bool {
+    let code = c as u32;
+    (0xAC00..=0xD7A3).contains(&code)
+        || (0x3000..=0x303F).contains(&code)
+        || c.is_ascii_punctuation()
+        || c.is_whitespace()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_output_for_nonempty_input_is_corrupted() {
+        let validator = OutputValidator::default();
+        assert!(validator.is_corrupted("hello", ""));
+    }
+
+    #[test]
+    fn test_empty_output_for_empty_input_is_not_corrupted() {
+        let validator = OutputValidator::default();
+        assert!(!validator.is_corrupted("", ""));
+    }
+
+    #[test]
+    fn test_null_byte_is_corrupted() {
+        let validator = OutputValidator::default();
+        assert!(validator.is_corrupted("hello", "안녕\0하세요"));
+    }
+
+    #[test]
+    fn test_control_char_policy_ignore_allows_control_chars() {
+        // 비율 검사는 출력이 min_len_for_ratio_check보다 길 때만 적용되므로, 그 값을
+        // 출력 길이(6)보다 크게 잡아 제어 문자 정책만 검사되게 한다.
+        let validator = OutputValidator::new(0.3, 10, ControlCharPolicy::Ignore);
+        assert!(!validator.is_corrupted("hello", "\x01\x02\x03\x04\x05\x06"));
+    }
+
+    #[test]
+    fn test_low_hangul_ratio_is_corrupted() {
+        let validator = OutputValidator::default();
+        assert!(validator.is_corrupted("hello", "xQ7zR2pL9wK4"));
+    }
+
+    #[test]
+    fn test_valid_korean_output_is_not_corrupted() {
+        let validator = OutputValidator::default();
+        assert!(!validator.is_corrupted("hello", "안녕하세요, 오늘 날씨가 좋네요."));
+    }
+
+    #[test]
+    fn test_matches_expected_with_no_expectations_always_passes() {
+        let validator = OutputValidator::default();
+        assert!(validator.matches_expected("anything", &[]));
+    }
+
+    #[test]
+    fn test_matches_expected_detects_missing_substring() {
+        let validator = OutputValidator::default();
+        assert!(!validator.matches_expected("안녕하세요", &["감사", "고마"]));
+        assert!(validator.matches_expected("정말 감사합니다", &["감사", "고마"]));
+    }
+}
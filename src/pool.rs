@@ -0,0 +1,394 @@
+// 멀티프로세스 번역 풀
+//
+// `J2KEngine.dll`은 한 프로세스 안에서 동시성 문제를 일으키는 전역 상태를 갖고 있어
+// (`tests/multiprocess_test.rs`, `tests/thread_safety_test.rs` 참고), 스레드 대신
+// 별도 프로세스로 엔진을 여러 개 띄우고 stdin/stdout 라인 프로토콜로 작업을 나눠 주는
+// 편이 안전하다. `EzTransPool`은 그 방식을 공개 API로 승격한 것이다.
+
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::Duration;
+
+use crate::report::{LogLevel, LogRecord, Reporter, ResultKind};
+use crate::EzTransError;
+
+pub mod proto;
+
+/// 타임아웃/워커 충돌까지 구분해 돌려주는 번역 결과.
+#[derive(Debug)]
+pub enum TranslateOutcome {
+    /// 정상적으로 번역을 마쳤다.
+    Success(String),
+    /// `timeout` 안에 워커가 응답하지 않았다. 워커는 교체되었으므로 재시도할 수 있다.
+    Timeout,
+    /// 워커 프로세스가 죽어 있었다(혹은 죽었다). 워커는 교체되었으므로 재시도할 수 있다.
+    WorkerCrashed,
+    /// 워커는 살아있지만 엔진이 번역에 실패했다고 응답했다.
+    Failed(String),
+}
+
+struct WorkerArgs {
+    worker_exe: String,
+    dll_path: String,
+    dat_path: String,
+}
+
+struct Worker {
+    child: Child,
+    stdin: ChildStdin,
+    /// 워커 stdout을 한 줄씩 읽어 채널로 넘기는 백그라운드 스레드. 이 스레드 덕분에
+    /// `translate_with_timeout`이 `recv_timeout`으로 깔끔하게 대기할 수 있다.
+    lines: mpsc::Receiver<std::io::Result<String>>,
+}
+
+impl Worker {
+    fn spawn(args: &WorkerArgs) -> Result<Self, EzTransError> {
+        let mut child = Command::new(&args.worker_exe)
+            .arg(&args.dll_path)
+            .arg(&args.dat_path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .map_err(|e| EzTransError::PipeError(format!("워커 프로세스 시작 실패: {}", e)))?;
+
+        let stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| EzTransError::PipeError("워커 stdin을 얻을 수 없습니다".to_string()))?;
+        let stdout: ChildStdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| EzTransError::PipeError("워커 stdout을 얻을 수 없습니다".to_string()))?;
+
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            let mut reader = BufReader::new(stdout);
+            loop {
+                let mut line = String::new();
+                match reader.read_line(&mut line) {
+                    Ok(0) => break, // EOF: 워커가 종료됨
+                    Ok(_) => {
+                        if sender.send(Ok(line)).is_err() {
+                            break;
+                        }
+                    }
+                    Err(e) => {
+                        let _ = sender.send(Err(e));
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok(Self {
+            child,
+            stdin,
+            lines: receiver,
+        })
+    }
+
+    fn send_request(&mut self, text: &str) -> Result<(), EzTransError> {
+        let single_line = text.replace('\n', " ");
+        writeln!(self.stdin, "{}", single_line).map_err(|e| EzTransError::PipeError(e.to_string()))
+    }
+
+    fn recv_response(&self, timeout: Duration) -> TranslateOutcome {
+        match self.lines.recv_timeout(timeout) {
+            Ok(Ok(line)) => parse_response(line.trim_end()),
+            Ok(Err(_)) => TranslateOutcome::WorkerCrashed,
+            Err(RecvTimeoutError::Timeout) => TranslateOutcome::Timeout,
+            Err(RecvTimeoutError::Disconnected) => TranslateOutcome::WorkerCrashed,
+        }
+    }
+}
+
+/// `TranslateOutcome`을 `translate`/`translate_batch`/`submit`이 공유하는 `Result`
+/// 형태로 옮긴다.
+fn outcome_to_result(outcome: TranslateOutcome) -> Result<String, EzTransError> {
+    match outcome {
+        TranslateOutcome::Success(translated) => Ok(translated),
+        TranslateOutcome::Timeout => Err(EzTransError::PipeError("워커 응답 시간 초과".to_string())),
+        TranslateOutcome::WorkerCrashed => {
+            Err(EzTransError::PipeError("워커 프로세스가 종료되었습니다".to_string()))
+        }
+        TranslateOutcome::Failed(message) => Err(EzTransError::FunctionCallFailed(message)),
+    }
+}
+
+fn parse_response(response: &str) -> TranslateOutcome {
+    match response.split_once('\t') {
+        Some(("OK", translated)) => TranslateOutcome::Success(translated.to_string()),
+        Some(("ERR", message)) => TranslateOutcome::Failed(message.to_string()),
+        _ => TranslateOutcome::Failed(format!("워커로부터 알 수 없는 응답: {:?}", response)),
+    }
+}
+
+impl Drop for Worker {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// 기본 번역 타임아웃. 워커가 멈춰도 호출자가 무한정 기다리지 않도록 한다.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 여러 워커 프로세스에 번역 요청을 라운드로빈으로 분배하는 풀.
+///
+/// 각 워커는 워치독을 통해 감시된다: 타임아웃이 나거나 프로세스가 죽으면 해당 슬롯의
+/// 워커를 새로 스폰해 교체하고, 호출자에게는 무엇이 잘못되었는지 `TranslateOutcome`으로
+/// 알려준다.
+pub struct EzTransPool {
+    workers: Vec<Mutex<Worker>>,
+    args: WorkerArgs,
+    next: AtomicUsize,
+    reporter: Option<Arc<dyn Reporter>>,
+    /// `submit`으로 맡긴, 아직 결과를 보내지 않은 백그라운드 작업 수. `shutdown`이
+    /// 이게 0이 될 때까지 기다린다.
+    in_flight: Arc<(Mutex<usize>, Condvar)>,
+}
+
+impl EzTransPool {
+    /// `worker_exe`(보통 `pool_worker` 바이너리 경로)를 `worker_count`개 띄운다.
+    pub fn spawn(
+        worker_count: usize,
+        worker_exe: &str,
+        dll_path: &str,
+        dat_path: &str,
+    ) -> Result<Self, EzTransError> {
+        let args = WorkerArgs {
+            worker_exe: worker_exe.to_string(),
+            dll_path: dll_path.to_string(),
+            dat_path: dat_path.to_string(),
+        };
+
+        let mut workers = Vec::with_capacity(worker_count);
+        for _ in 0..worker_count {
+            workers.push(Mutex::new(Worker::spawn(&args)?));
+        }
+
+        Ok(Self {
+            workers,
+            args,
+            next: AtomicUsize::new(0),
+            reporter: None,
+            in_flight: Arc::new((Mutex::new(0), Condvar::new())),
+        })
+    }
+
+    /// 진행률/진단 이벤트를 내보낼 리포터를 등록한다.
+    pub fn with_reporter(mut self, reporter: Arc<dyn Reporter>) -> Self {
+        self.reporter = Some(reporter);
+        self
+    }
+
+    /// 현재 풀에 떠 있는 워커 수.
+    pub fn worker_count(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// 기본 타임아웃(`DEFAULT_TIMEOUT`)으로 다음 워커(라운드로빈)에게 번역을 맡긴다.
+    pub fn translate(&self, text: &str) -> Result<String, EzTransError> {
+        outcome_to_result(self.translate_with_timeout(text, DEFAULT_TIMEOUT))
+    }
+
+    /// 다음 워커에게 번역을 맡기되, `timeout` 안에 응답이 없거나 워커가 죽어 있으면 그
+    /// 워커 슬롯을 새 프로세스로 교체하고 그 사실을 결과에 담아 돌려준다.
+    pub fn translate_with_timeout(&self, text: &str, timeout: Duration) -> TranslateOutcome {
+        let index = self.next.fetch_add(1, Ordering::Relaxed) % self.workers.len();
+        self.translate_on_worker(index, text, timeout)
+    }
+
+    /// `inputs`를 워커 수만큼 나눠 각자 고정된 워커에게 순서대로 맡기고, 입력 순서를
+    /// 보존한 결과를 모아 돌려준다. `translate_with_timeout`과 달리 라운드로빈
+    /// 카운터를 공유하지 않고 워커마다 스레드 하나가 전담한다.
+    pub fn translate_batch(&self, inputs: &[String]) -> Vec<Result<String, EzTransError>> {
+        if self.workers.is_empty() || inputs.is_empty() {
+            return Vec::new();
+        }
+
+        let worker_count = self.workers.len();
+        let mut results: Vec<Option<Result<String, EzTransError>>> =
+            (0..inputs.len()).map(|_| None).collect();
+
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..worker_count)
+                .map(|worker_index| {
+                    let indices: Vec<usize> =
+                        (worker_index..inputs.len()).step_by(worker_count).collect();
+                    scope.spawn(move || {
+                        indices
+                            .into_iter()
+                            .map(|i| {
+                                let outcome =
+                                    self.translate_on_worker(worker_index, &inputs[i], DEFAULT_TIMEOUT);
+                                (i, outcome_to_result(outcome))
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+
+            for handle in handles {
+                for (index, result) in handle.join().expect("translate_batch worker panicked") {
+                    results[index] = Some(result);
+                }
+            }
+        });
+
+        results
+            .into_iter()
+            .map(|r| r.expect("every index must be filled by exactly one worker"))
+            .collect()
+    }
+
+    /// 요청을 큐에 올려두기만 하고 바로 돌아온다. 번역은 백그라운드 스레드에서
+    /// 진행되며, 끝나면 돌려준 채널로 결과가 도착한다. 스레드가 풀보다 오래 살 수도
+    /// 있으므로 풀을 `Arc`로 쥐고 있어야 한다 (`EzTransEngine`이 내부 상태를
+    /// `Arc<EzTransInner>`로 감싸는 것과 같은 이유).
+    pub fn submit(self: &Arc<Self>, text: String) -> mpsc::Receiver<Result<String, EzTransError>> {
+        let (sender, receiver) = mpsc::channel();
+        let pool = Arc::clone(self);
+        {
+            *pool.in_flight.0.lock().unwrap() += 1;
+        }
+        std::thread::spawn(move || {
+            let _ = sender.send(pool.translate(&text));
+            let (lock, condvar) = &*pool.in_flight;
+            let mut count = lock.lock().unwrap();
+            *count -= 1;
+            if *count == 0 {
+                condvar.notify_all();
+            }
+        });
+        receiver
+    }
+
+    /// `submit`으로 맡긴 백그라운드 작업이 모두 결과를 보낼 때까지 기다린 뒤, 모든
+    /// 워커 프로세스를 종료한다. `translate`/`translate_batch`는 이미 호출자 스레드를
+    /// 블록하므로, 여기서 기다리는 "진행 중인 작업"은 아직 끝나지 않은 `submit` 호출
+    /// 뿐이다.
+    pub fn shutdown(&self) {
+        let (lock, condvar) = &*self.in_flight;
+        let mut count = lock.lock().unwrap();
+        while *count > 0 {
+            count = condvar.wait(count).unwrap();
+        }
+        drop(count);
+
+        for worker in &self.workers {
+            let mut worker = worker.lock().unwrap();
+            let _ = worker.child.kill();
+            let _ = worker.child.wait();
+        }
+    }
+
+    /// `worker_index` 워커에게 번역을 맡기되, `timeout` 안에 응답이 없거나 워커가 죽어
+    /// 있으면 그 슬롯을 새 프로세스로 교체하고 그 사실을 결과에 담아 돌려준다.
+    fn translate_on_worker(&self, index: usize, text: &str, timeout: Duration) -> TranslateOutcome {
+        let mut worker = self.workers[index].lock().unwrap();
+
+        if let Err(e) = worker.send_request(text) {
+            *worker = match Worker::spawn(&self.args) {
+                Ok(w) => w,
+                Err(_) => return TranslateOutcome::WorkerCrashed,
+            };
+            self.report_worker_event(index, LogLevel::Warn, format!("요청 전송 실패, 워커 재시작: {e}"));
+            return TranslateOutcome::Failed(e.to_string());
+        }
+
+        let outcome = worker.recv_response(timeout);
+        if matches!(outcome, TranslateOutcome::Timeout | TranslateOutcome::WorkerCrashed) {
+            if let Ok(replacement) = Worker::spawn(&self.args) {
+                *worker = replacement;
+            }
+            self.report_worker_event(index, LogLevel::Error, "워커가 응답하지 않아 재시작했습니다".to_string());
+        }
+        self.report_result(index, &outcome);
+        outcome
+    }
+
+    fn report_result(&self, worker_id: usize, outcome: &TranslateOutcome) {
+        let Some(reporter) = &self.reporter else {
+            return;
+        };
+        let (kind, detail) = match outcome {
+            TranslateOutcome::Success(text) => (ResultKind::Success, text.clone()),
+            TranslateOutcome::Timeout => (ResultKind::Timeout, "timed out".to_string()),
+            TranslateOutcome::WorkerCrashed => (ResultKind::Crashed, "worker crashed".to_string()),
+            TranslateOutcome::Failed(message) => (ResultKind::Failed, message.clone()),
+        };
+        reporter.on_result_chunk(worker_id, kind, &detail);
+    }
+
+    fn report_worker_event(&self, worker_id: usize, level: LogLevel, message: String) {
+        if let Some(reporter) = &self.reporter {
+            reporter.on_worker_event(LogRecord::new(level, Some(worker_id), message));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spawn_rejects_unknown_executable() {
+        let result = EzTransPool::spawn(1, "definitely-not-a-real-executable.exe", "dll", "dat");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_response_success() {
+        match parse_response("OK\t안녕하세요") {
+            TranslateOutcome::Success(text) => assert_eq!(text, "안녕하세요"),
+            other => panic!("expected Success, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_response_error() {
+        match parse_response("ERR\tboom") {
+            TranslateOutcome::Failed(message) => assert_eq!(message, "boom"),
+            other => panic!("expected Failed, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_response_garbage() {
+        assert!(matches!(parse_response("nonsense"), TranslateOutcome::Failed(_)));
+    }
+
+    #[test]
+    fn test_outcome_to_result_maps_success_and_failure() {
+        assert_eq!(
+            outcome_to_result(TranslateOutcome::Success("안녕".to_string())).unwrap(),
+            "안녕"
+        );
+        assert!(outcome_to_result(TranslateOutcome::Timeout).is_err());
+        assert!(outcome_to_result(TranslateOutcome::WorkerCrashed).is_err());
+        assert!(outcome_to_result(TranslateOutcome::Failed("boom".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_translate_batch_empty_input_short_circuits() {
+        // 워커를 띄우지 않고도(0개) 빈 입력에 대해 빈 결과를 돌려줘야 한다.
+        let pool = EzTransPool {
+            workers: Vec::new(),
+            args: WorkerArgs {
+                worker_exe: String::new(),
+                dll_path: String::new(),
+                dat_path: String::new(),
+            },
+            next: AtomicUsize::new(0),
+            reporter: None,
+            in_flight: Arc::new((Mutex::new(0), Condvar::new())),
+        };
+        assert!(pool.translate_batch(&[]).is_empty());
+        assert!(pool.translate_batch(&["hi".to_string()]).is_empty());
+    }
+}
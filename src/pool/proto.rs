@@ -0,0 +1,157 @@
+// 길이 접두 바이너리 프레이밍.
+//
+// `tests/multiprocess_test.rs`의 스트리밍 테스트가 의존하는 현재 줄 단위 프로토콜
+// (`pool.rs`의 `BufReader::read_line`)은 번역 결과에 개행이 섞여 있거나 워커가 한
+// 줄을 다 쓰기 전에 블록하면 깨진다. 여기서는 각 메시지를 4바이트 리틀엔디안 길이
+// 접두사 + 그만큼의 JSON 페이로드로 감싸, 내용에 개행이 있어도 프레임 경계가 절대
+// 모호해지지 않게 한다. `FrameReader`는 일부만 도착한 바이트를 버퍼에 누적하다가
+// 프레임 하나가 완전히 모였을 때만 디코딩해 내놓는 증분 파서로, 한 번의 `read`가
+// 프레임 경계와 맞아떨어진다고 가정하지 않는다.
+
+use std::io::{Read, Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::EzTransError;
+
+/// 작업 id로 요청/응답을 짝지을 수 있도록 태그가 붙은 메시지.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Message {
+    /// 감독 프로세스가 워커에게 보내는 번역 요청.
+    Request { job_id: u64, text: String },
+    /// 워커가 중간 진행률을 보고한다(0..=100).
+    Progress { job_id: u64, pct: u8 },
+    /// 워커가 번역을 마쳤다.
+    Result { job_id: u64, text: String },
+    /// 워커가 번역에 실패했다 — `msg`는 `EzTransError`를 프로세스 경계 너머로 표현한
+    /// 사람이 읽을 수 있는 메시지다.
+    Error { job_id: u64, msg: String },
+}
+
+/// `message`를 JSON으로 직렬화하고 4바이트 리틀엔디안 길이 접두사를 붙인 프레임
+/// 바이트열을 만든다.
+fn encode(message: &Message) -> Result<Vec<u8>, EzTransError> {
+    let payload = serde_json::to_vec(message)
+        .map_err(|e| EzTransError::PipeError(format!("메시지 직렬화 실패: {e}")))?;
+
+    let len = u32::try_from(payload.len())
+        .map_err(|_| EzTransError::PipeError("메시지가 너무 커서 길이 접두사에 담을 수 없습니다".to_string()))?;
+
+    let mut framed = Vec::with_capacity(4 + payload.len());
+    framed.extend_from_slice(&len.to_le_bytes());
+    framed.extend_from_slice(&payload);
+    Ok(framed)
+}
+
+/// `message`를 프레이밍해 `writer`에 한 번에 쓴다.
+pub fn write_message<W: Write>(writer: &mut W, message: &Message) -> Result<(), EzTransError> {
+    let framed = encode(message)?;
+    writer.write_all(&framed).map_err(|e| EzTransError::PipeError(e.to_string()))
+}
+
+/// `reader`에서 프레임 하나(길이 접두사 + 페이로드)를 블로킹으로 읽어 디코딩한다.
+/// 현재 `pool::Worker`처럼 한 번에 한 메시지만 동기적으로 기다리는 호출자를 위한
+/// 간단한 경로이며, 부분 도착/백프레셔까지 다뤄야 하면 [`FrameReader`]를 쓴다.
+pub fn read_message<R: Read>(reader: &mut R) -> Result<Message, EzTransError> {
+    let mut len_bytes = [0u8; 4];
+    reader
+        .read_exact(&mut len_bytes)
+        .map_err(|e| EzTransError::PipeError(format!("길이 접두사를 읽을 수 없습니다: {e}")))?;
+    let len = u32::from_le_bytes(len_bytes) as usize;
+
+    let mut payload = vec![0u8; len];
+    reader
+        .read_exact(&mut payload)
+        .map_err(|e| EzTransError::PipeError(format!("페이로드를 읽을 수 없습니다: {e}")))?;
+
+    serde_json::from_slice(&payload)
+        .map_err(|e| EzTransError::PipeError(format!("메시지 역직렬화 실패: {e}")))
+}
+
+/// 스트림에서 읽은 바이트를 누적하다가, 프레임 하나가 완전히 모였을 때만 디코딩해
+/// 내놓는 증분 파서. 워커가 부분적인 길이 접두사나 페이로드만 쓴 채 블록해도, 다음
+/// `feed` 호출 전까지는 호출자가 그 불완전한 상태를 들여다볼 일이 없다.
+#[derive(Default)]
+pub struct FrameReader {
+    buffer: Vec<u8>,
+}
+
+impl FrameReader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 새로 읽은 바이트를 내부 버퍼 끝에 이어 붙인다.
+    pub fn feed(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// 버퍼에 프레임 하나가 완전히 모여 있으면 꺼내 디코딩하고 소비한 바이트를
+    /// 버퍼에서 제거한다. 아직 덜 모였으면 `Ok(None)`을 돌려주고 버퍼는 그대로 둔다 —
+    /// 호출자는 더 읽은 뒤 다시 불러야 한다.
+    pub fn try_next(&mut self) -> Result<Option<Message>, EzTransError> {
+        if self.buffer.len() < 4 {
+            return Ok(None);
+        }
+        let len = u32::from_le_bytes(self.buffer[..4].try_into().unwrap()) as usize;
+        if self.buffer.len() < 4 + len {
+            return Ok(None);
+        }
+
+        let payload = self.buffer[4..4 + len].to_vec();
+        self.buffer.drain(..4 + len);
+
+        let message = serde_json::from_slice(&payload)
+            .map_err(|e| EzTransError::PipeError(format!("메시지 역직렬화 실패: {e}")))?;
+        Ok(Some(message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_then_read_message_round_trips() {
+        let message = Message::Result {
+            job_id: 7,
+            text: "line one\nline two".to_string(),
+        };
+
+        let mut buffer = Vec::new();
+        write_message(&mut buffer, &message).unwrap();
+
+        let decoded = read_message(&mut &buffer[..]).unwrap();
+        assert_eq!(decoded, message);
+    }
+
+    #[test]
+    fn test_frame_reader_yields_nothing_until_full_frame_arrives() {
+        let message = Message::Progress { job_id: 1, pct: 42 };
+        let framed = encode(&message).unwrap();
+
+        let mut reader = FrameReader::new();
+        reader.feed(&framed[..2]);
+        assert_eq!(reader.try_next().unwrap(), None);
+
+        reader.feed(&framed[2..]);
+        assert_eq!(reader.try_next().unwrap(), Some(message));
+    }
+
+    #[test]
+    fn test_frame_reader_handles_multiple_frames_fed_together() {
+        let first = Message::Request { job_id: 1, text: "hello".to_string() };
+        let second = Message::Error { job_id: 1, msg: "boom".to_string() };
+
+        let mut framed = encode(&first).unwrap();
+        framed.extend(encode(&second).unwrap());
+
+        let mut reader = FrameReader::new();
+        reader.feed(&framed);
+
+        assert_eq!(reader.try_next().unwrap(), Some(first));
+        assert_eq!(reader.try_next().unwrap(), Some(second));
+        assert_eq!(reader.try_next().unwrap(), None);
+    }
+}
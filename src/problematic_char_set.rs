@@ -0,0 +1,150 @@
+// `tests/verify_scan_results.rs`가 읽는 체크인된 `full_unicode_scan_v2_results.txt`를
+// 런타임 1급 자료구조로 승격한 것. 텍스트 파일을 경로에 맞춰 파싱해야 하는 대신,
+// `ProblematicCharSet`을 직접 직렬화/역직렬화하고, 번들 기본값을 불러오고, DLL/Dat
+// 버전이 바뀐 뒤 현재 엔진을 상대로 다시 만들고, 두 테이블을 비교할 수 있다.
+//
+// `char_ranges::generate`의 `(start, end)` 구간 목록 하나만 쓰면 듬성듬성 떨어진
+// 단일 코드포인트마다 길이 1짜리 구간이 생겨 이진 탐색 테이블이 불필요하게 커진다.
+// 여기서는 길이가 짧은 구간을 `outliers` `HashSet`으로 분리해 두어, 넓은 블록은 구간
+// 이진 탐색으로, 드문드문한 단일 코드포인트는 해시 조회로 처리한다.
+
+use std::collections::HashSet;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::char_ranges::generate;
+use crate::char_safety_probe::SafetyChange;
+
+/// `ProblematicCharSet::from_codes`가 길이가 이 값 이하인 구간을 `ranges`에 남기지
+/// 않고 `outliers`로 옮기는 기준. 1로 두면 고립된 단일 코드포인트만 분리된다.
+const OUTLIER_RUN_LEN: u32 = 1;
+
+/// `hangul_encode` 없이 엔진에 그대로 보내면 깨지는 것으로 실측된 코드포인트의 집합.
+/// 밀집된 블록은 정렬된 `(start, end)` 구간으로, 듬성듬성한 단일 코드포인트는
+/// `HashSet`으로 나눠 저장한다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ProblematicCharSet {
+    ranges: Vec<(u32, u32)>,
+    outliers: HashSet<u32>,
+}
+
+impl ProblematicCharSet {
+    /// `codes`를 연속 구간으로 합친 뒤, 길이가 `OUTLIER_RUN_LEN` 이하인 구간을
+    /// `outliers`로 떼어낸다.
+    pub fn from_codes(codes: impl IntoIterator<Item = u32>) -> Self {
+        let mut ranges = Vec::new();
+        let mut outliers = HashSet::new();
+
+        for (start, end) in generate::merge_ranges(codes) {
+            if end - start < OUTLIER_RUN_LEN {
+                for code in start..=end {
+                    outliers.insert(code);
+                }
+            } else {
+                ranges.push((start, end));
+            }
+        }
+
+        Self { ranges, outliers }
+    }
+
+    /// `code`가 이 집합에 속하는지(= 인코딩 없이 보내면 깨지는 것으로 알려진 문자인지)
+    /// 확인한다.
+    pub fn contains(&self, code: u32) -> bool {
+        self.outliers.contains(&code) || generate::lookup(code, &self.ranges)
+    }
+
+    /// `char_ranges::GENERATED_UNSAFE_RANGES`(build.rs가 `data/unsafe_ranges.json`으로
+    /// 부터 컴파일해 넣은, 크레이트와 함께 배포되는 기본 테이블)로부터 집합을 만든다.
+    /// 아직 실측 데이터가 없으면 빈 집합이 된다.
+    pub fn bundled() -> Self {
+        Self::from_codes(
+            crate::char_ranges::GENERATED_UNSAFE_RANGES
+                .iter()
+                .flat_map(|&(start, end)| start..=end),
+        )
+    }
+
+    /// `path`에 JSON(구간 + outlier 목록)으로 저장한다.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json)
+    }
+
+    /// [`ProblematicCharSet::save`]로 저장된 파일을 다시 읽는다.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        serde_json::from_str(&contents).map_err(io::Error::from)
+    }
+
+    /// `old`(`self`)와 `new` 사이에서 분류가 바뀐 코드포인트를, 낮은 코드포인트부터
+    /// 보고한다. DLL/Dat 버전을 올린 뒤 다시 만든 테이블을 이전 버전과 비교해 회귀를
+    /// 잡아낼 때 쓴다.
+    pub fn diff(&self, new: &Self, start: u32, end: u32) -> Vec<SafetyChange> {
+        let mut changes = Vec::new();
+        for code in start..=end {
+            match (self.contains(code), new.contains(code)) {
+                (false, true) => changes.push(SafetyChange::BecameUnsafe(code)),
+                (true, false) => changes.push(SafetyChange::BecameSafe(code)),
+                _ => {}
+            }
+        }
+        changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_codes_separates_dense_ranges_from_outliers() {
+        let set = ProblematicCharSet::from_codes([1, 2, 3, 100, 250, 251, 252]);
+        assert!(set.contains(1));
+        assert!(set.contains(2));
+        assert!(set.contains(3));
+        assert!(set.contains(100));
+        assert!(set.contains(250));
+        assert!(set.contains(252));
+        assert!(!set.contains(4));
+        assert!(!set.contains(99));
+        assert!(!set.contains(253));
+    }
+
+    #[test]
+    fn test_save_then_load_round_trips() {
+        let dir = std::env::temp_dir().join(format!(
+            "eztrans_rs_problematic_char_set_test_{:?}",
+            std::thread::current().id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("problematic.json");
+
+        let set = ProblematicCharSet::from_codes([5, 6, 7, 42]);
+        set.save(&path).unwrap();
+        assert_eq!(ProblematicCharSet::load(&path).unwrap(), set);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_diff_reports_both_directions() {
+        let old = ProblematicCharSet::from_codes([1, 2, 3]);
+        let new = ProblematicCharSet::from_codes([1, 2, 10]);
+        let changes = old.diff(&new, 0, 10);
+
+        assert_eq!(
+            changes,
+            vec![SafetyChange::BecameSafe(3), SafetyChange::BecameUnsafe(10)]
+        );
+    }
+
+    #[test]
+    fn test_empty_set_contains_nothing() {
+        let set = ProblematicCharSet::from_codes(std::iter::empty());
+        assert!(!set.contains(0));
+        assert!(!set.contains(65));
+    }
+}
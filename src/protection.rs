@@ -0,0 +1,213 @@
+// 엔진 저장소(CP932/Shift-JIS 계열) 밖의 문자를 번역 전에 보호했다가 복원하는
+// 보호-복원 패스.
+//
+// `coverage::scan_charset_coverage`/`tests/full_unicode_scan.rs`는 어떤 코드포인트가
+// 깨지는지 "진단"만 해 주지만, `SanitizerMap`처럼 손으로 대체 문자열을 채워 넣지
+// 않고도 실제로 원본을 살려서 돌려받고 싶은 경우가 많다. `ProtectionMap`은
+// ASCII가 아니면서 `char_ranges::is_safe_chars`를 통과하지 못하는 확장 자소 클러스터마다 고유한
+// 자리표시자 토큰(안전한 것으로 확인된 센티널 + 인덱스)을 붙여 두고, 번역이 끝나면
+// 살아남은 토큰을 찾아 원래 클러스터로 되돌린다. `grapheme_encode`와 같은 문제를
+// 다루지만, 토큰 구성(센티널 문자, 자릿수)을 호출자가 바꿀 수 있게 `ProtectionConfig`로
+// 빼냈고, 엔진이 토큰 앞뒤에 공백을 끼워 넣어도 복원이 실패하지 않도록 허용한다.
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::char_ranges::is_safe_chars;
+use crate::{EzTransError, EzTransInner};
+
+/// 자리표시자 토큰을 어떻게 구성할지 정하는 설정.
+///
+/// 토큰 모양은 `{sentinel}{index:0width}` (예: 기본값으로 `"Ⓟ0001"`)이다. `sentinel`은
+/// 반드시 엔진을 그대로 통과하는 것으로 확인된 문자여야 한다 — 사설 영역(PUA)
+/// 문자는 CP932 계열 엔진을 거치며 `?`로 깨지는 경우가 많아 기본값으로 쓰지 않는다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtectionConfig {
+    pub sentinel: char,
+    /// 인덱스를 0으로 채워 고정폭으로 표시할 자릿수.
+    pub digits: usize,
+}
+
+impl Default for ProtectionConfig {
+    fn default() -> Self {
+        Self {
+            sentinel: 'Ⓟ',
+            digits: 4,
+        }
+    }
+}
+
+impl ProtectionConfig {
+    fn format_token(&self, index: usize) -> String {
+        format!("{}{:0width$}", self.sentinel, index, width = self.digits)
+    }
+
+    /// `rest`의 맨 앞에서 `{sentinel}{index}` 토큰을 찾아 (인덱스, 소비한 바이트 수)를
+    /// 돌려준다. 엔진이 센티널과 숫자 사이에 공백을 끼워 넣었더라도 허용한다.
+    fn parse_token(&self, rest: &str) -> Option<(usize, usize)> {
+        let after_sentinel = rest.strip_prefix(self.sentinel)?;
+        let mut consumed = self.sentinel.len_utf8();
+
+        let trimmed = after_sentinel.trim_start_matches(' ');
+        consumed += after_sentinel.len() - trimmed.len();
+
+        let digits_str = trimmed.get(..self.digits)?;
+        if !digits_str.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        consumed += self.digits;
+
+        let index = digits_str.parse::<usize>().ok()?;
+        Some((index, consumed))
+    }
+}
+
+/// 보호해야 하는 확장 자소 클러스터인지 판정한다: 코드포인트가 둘 이상이면 쪼개지지
+/// 않도록 무조건 보호하고, 코드포인트가 하나뿐이면 ASCII는 그대로 두고 그 밖의 문자는
+/// `is_safe_chars`로 안전성을 확인한다.
+fn needs_protection(cluster: &str) -> bool {
+    let mut chars = cluster.chars();
+    match (chars.next(), chars.next()) {
+        (Some(_), Some(_)) => true,
+        (Some(c), None) => !c.is_ascii() && !is_safe_chars(c),
+        (None, _) => false,
+    }
+}
+
+/// `protect`가 대체한 자리표시자 인덱스 -> 원본 클러스터 표. 번역 결과에서 살아남은
+/// 토큰을 원래 텍스트로 복원하는 데 쓴다.
+#[derive(Debug, Clone)]
+pub struct ProtectionMap {
+    config: ProtectionConfig,
+    originals: Vec<String>,
+}
+
+/// `input`에서 엔진 저장소 밖의 확장 자소 클러스터를 `config`가 정한 자리표시자
+/// 토큰으로 바꾼다. 반환된 텍스트를 번역한 뒤 `ProtectionMap::restore`에 넘기면
+/// 원본 클러스터가 되돌아온다.
+pub fn protect(input: &str, config: ProtectionConfig) -> (String, ProtectionMap) {
+    let mut originals = Vec::new();
+    let mut text = String::with_capacity(input.len());
+
+    for grapheme in input.graphemes(true) {
+        if needs_protection(grapheme) {
+            let index = originals.len();
+            originals.push(grapheme.to_string());
+            text.push_str(&config.format_token(index));
+        } else {
+            text.push_str(grapheme);
+        }
+    }
+
+    (text, ProtectionMap { config, originals })
+}
+
+impl ProtectionMap {
+    /// 번역된 텍스트에서 살아남은 자리표시자 토큰을 찾아 원본 클러스터로 되돌린다.
+    /// 엔진이 토큰 앞뒤에 공백을 끼워 넣었다면(단어 경계로 오인해서) 그 공백까지
+    /// 함께 삼켜, 복원된 텍스트에 엔진이 만든 군더더기 공백이 남지 않게 한다.
+    pub fn restore(&self, translated: &str) -> String {
+        let mut result = String::with_capacity(translated.len());
+        let mut rest = translated;
+
+        loop {
+            let Some(sentinel_at) = rest.find(self.config.sentinel) else {
+                break;
+            };
+
+            let before = &rest[..sentinel_at];
+            let candidate = &rest[sentinel_at..];
+
+            match self.config.parse_token(candidate) {
+                Some((index, consumed)) if self.originals.get(index).is_some() => {
+                    // 토큰 앞에 엔진이 끼워 넣었을 수 있는 공백 하나까지 함께 버린다.
+                    let before_trimmed = before.strip_suffix(' ').unwrap_or(before);
+                    result.push_str(before_trimmed);
+                    result.push_str(&self.originals[index]);
+
+                    let mut after = &candidate[consumed..];
+                    after = after.strip_prefix(' ').unwrap_or(after);
+                    rest = after;
+                }
+                _ => {
+                    result.push_str(before);
+                    result.push(self.config.sentinel);
+                    rest = &candidate[self.config.sentinel.len_utf8()..];
+                }
+            }
+        }
+        result.push_str(rest);
+        result
+    }
+}
+
+impl EzTransInner {
+    /// 엔진 저장소 밖의 확장 자소 클러스터를 보호한 채 번역한다. `ProtectionConfig`의
+    /// 기본값을 쓴다 — 토큰 구성을 바꾸고 싶다면 [`protect`]/[`ProtectionMap::restore`]를
+    /// 직접 호출한다.
+    pub fn translate_protected(&self, input: &str) -> Result<String, EzTransError> {
+        let (protected, map) = protect(input, ProtectionConfig::default());
+        let translated = self.default_translate(&protected)?;
+        Ok(map.restore(&translated))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_out_of_repertoire_char_round_trips_through_identity_translation() {
+        let input = "앞㈱뒤"; // ㈱는 엔진이 자주 깨뜨리는 문자
+        let (protected, map) = protect(input, ProtectionConfig::default());
+        assert!(!protected.contains('㈱'));
+        assert_eq!(map.restore(&protected), input);
+    }
+
+    #[test]
+    fn test_safe_ascii_is_left_untouched() {
+        let (protected, _map) = protect("hello world", ProtectionConfig::default());
+        assert_eq!(protected, "hello world");
+    }
+
+    #[test]
+    fn test_restore_tolerates_space_inserted_between_sentinel_and_digits() {
+        let (protected, map) = protect("앞㈱뒤", ProtectionConfig::default());
+        let with_space = protected.replacen("Ⓟ0000", "Ⓟ 0000", 1);
+        assert_eq!(map.restore(&with_space), "앞㈱뒤");
+    }
+
+    #[test]
+    fn test_restore_tolerates_space_engine_added_before_and_after_token() {
+        let (protected, map) = protect("a㈱b", ProtectionConfig::default());
+        // 엔진이 토큰을 독립된 단어로 보고 앞뒤에 공백을 끼워 넣은 것을 흉내 낸다.
+        let spaced = protected.replace('Ⓟ', " Ⓟ");
+        let spaced = spaced.replacen("0000", "0000 ", 1);
+        assert_eq!(map.restore(&spaced), "a㈱b");
+    }
+
+    #[test]
+    fn test_custom_sentinel_and_digit_width_are_honored() {
+        let config = ProtectionConfig {
+            sentinel: '#',
+            digits: 2,
+        };
+        let (protected, map) = protect("㈱", config);
+        assert_eq!(protected, "#00");
+        assert_eq!(map.restore(&protected), "㈱");
+    }
+
+    #[test]
+    fn test_multi_codepoint_cluster_is_protected_as_one_unit() {
+        let input = "앞👋🏻뒤";
+        let (protected, map) = protect(input, ProtectionConfig::default());
+        assert!(!protected.contains('👋'));
+        assert_eq!(map.restore(&protected), input);
+    }
+
+    #[test]
+    fn test_unknown_sentinel_sequence_in_translated_text_is_left_alone() {
+        let (_protected, map) = protect("hello", ProtectionConfig::default());
+        let stray = "Ⓟ9999 is not a real token";
+        assert_eq!(map.restore(stray), stray);
+    }
+}
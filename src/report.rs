@@ -0,0 +1,254 @@
+// 진행률 표시와 진단 로그를 한 곳으로 모으는 리포터.
+//
+// `tests/full_unicode_scan.rs`의 `print_progress_dashboard_v3`나 각 바이너리에
+// 흩어진 `println!`/`eprintln!`은 전부 콘솔 한 군데에만 출력하도록 고정되어 있었다.
+// `Reporter` 트레이트는 같은 이벤트를 콘솔(사람이 보는 대시보드)과 파일(나중에 필터링/
+// 분석할 수 있는 NDJSON 로그)에 동시에 내보낼 수 있게 해 준다. `pool::EzTransPool`이나
+// CSV 스캔 코디네이터는 이 트레이트 하나만 구현에 두고 호출하면 된다.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// 로그 레코드의 심각도.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    fn as_str(&self) -> &'static str {
+        match self {
+            LogLevel::Info => "info",
+            LogLevel::Warn => "warn",
+            LogLevel::Error => "error",
+        }
+    }
+
+    /// TTY에 출력할 때 쓸 ANSI 색 코드.
+    fn ansi_color(&self) -> &'static str {
+        match self {
+            LogLevel::Info => "\x1b[36m",  // cyan
+            LogLevel::Warn => "\x1b[33m",  // yellow
+            LogLevel::Error => "\x1b[31m", // red
+        }
+    }
+}
+
+/// 워커 하나가 남긴 로그 한 줄.
+#[derive(Debug, Clone)]
+pub struct LogRecord {
+    pub level: LogLevel,
+    pub worker_id: Option<usize>,
+    pub message: String,
+}
+
+impl LogRecord {
+    pub fn new(level: LogLevel, worker_id: Option<usize>, message: impl Into<String>) -> Self {
+        Self {
+            level,
+            worker_id,
+            message: message.into(),
+        }
+    }
+}
+
+/// `on_result_chunk`가 실어 나르는 번역 결과 한 건의 분류.
+///
+/// `pool::TranslateOutcome`과 의도적으로 겹치지만, 리포터는 풀 구현에 의존하지 않기
+/// 위해 별도의 얕은 분류만 가진다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultKind {
+    Success,
+    Timeout,
+    Crashed,
+    Failed,
+}
+
+/// 번역 진행 상황과 진단 정보를 받아 내보내는 트레이트.
+///
+/// 코디네이터 루프나 `EzTransPool`은 이 트레이트를 통해서만 출력하므로, 호출하는 쪽은
+/// 콘솔 대시보드든 파일 로그든 신경 쓸 필요가 없다.
+pub trait Reporter: Send + Sync {
+    /// 전체 작업량 중 `tested`개를 처리했음을 알린다.
+    fn on_progress(&self, tested: u64, total: u64, elapsed: Duration);
+    /// 워커 하나가 번역 결과 한 건을 반환했음을 알린다.
+    fn on_result_chunk(&self, worker_id: usize, kind: ResultKind, detail: &str);
+    /// 진행률과 무관한 워커 이벤트(시작/재시작/오류 등)를 기록한다.
+    fn on_worker_event(&self, record: LogRecord);
+}
+
+/// 콘솔에 ANSI 색상 대시보드를 찍고, 동시에 NDJSON 로그 파일에 같은 이벤트를 남기는
+/// 기본 리포터.
+///
+/// 로그 파일은 `max_bytes`를 넘어서면 확장자를 `log.1`로 바꾼 경로(`test.log`라면
+/// `test.log.1`)로 한 세대만 회전시키고 새로 쓰기
+/// 시작한다.
+pub struct ConsoleJsonReporter {
+    log_path: PathBuf,
+    max_bytes: u64,
+    file: Mutex<File>,
+}
+
+impl ConsoleJsonReporter {
+    /// `log_path`에 NDJSON을 이어 쓰며, 파일 크기가 `max_bytes`를 넘으면 회전시킨다.
+    pub fn new(log_path: impl AsRef<Path>, max_bytes: u64) -> std::io::Result<Self> {
+        let log_path = log_path.as_ref().to_path_buf();
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&log_path)?;
+
+        Ok(Self {
+            log_path,
+            max_bytes,
+            file: Mutex::new(file),
+        })
+    }
+
+    fn write_json_line(&self, line: &str) {
+        let mut file = self.file.lock().unwrap();
+        self.rotate_if_needed(&mut file);
+        let _ = writeln!(file, "{}", line);
+    }
+
+    fn rotate_if_needed(&self, file: &mut File) {
+        let size = file.metadata().map(|m| m.len()).unwrap_or(0);
+        if size < self.max_bytes {
+            return;
+        }
+
+        let rotated_path = self.log_path.with_extension("log.1");
+        drop(fs::remove_file(&rotated_path));
+        if fs::rename(&self.log_path, &rotated_path).is_ok() {
+            if let Ok(fresh) = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.log_path)
+            {
+                *file = fresh;
+            }
+        }
+    }
+}
+
+impl Reporter for ConsoleJsonReporter {
+    fn on_progress(&self, tested: u64, total: u64, elapsed: Duration) {
+        let percent = if total == 0 {
+            0.0
+        } else {
+            (tested as f64 / total as f64) * 100.0
+        };
+        print!(
+            "\r[{percent:5.1}%] {tested}/{total} ({:.1}s elapsed)   ",
+            elapsed.as_secs_f64()
+        );
+        let _ = std::io::stdout().flush();
+
+        self.write_json_line(&format!(
+            r#"{{"event":"progress","tested":{tested},"total":{total},"elapsed_ms":{}}}"#,
+            elapsed.as_millis()
+        ));
+    }
+
+    fn on_result_chunk(&self, worker_id: usize, kind: ResultKind, detail: &str) {
+        let (color, label) = match kind {
+            ResultKind::Success => ("\x1b[32m", "OK"),      // green
+            ResultKind::Timeout => ("\x1b[33m", "TIMEOUT"), // yellow
+            ResultKind::Crashed => ("\x1b[31m", "CRASHED"), // red
+            ResultKind::Failed => ("\x1b[31m", "FAILED"),   // red
+        };
+        println!("{color}[worker {worker_id}] {label}: {detail}\x1b[0m");
+
+        self.write_json_line(&format!(
+            r#"{{"event":"result","worker_id":{worker_id},"kind":"{}","detail":{}}}"#,
+            result_kind_str(kind),
+            json_escape(detail),
+        ));
+    }
+
+    fn on_worker_event(&self, record: LogRecord) {
+        let color = record.level.ansi_color();
+        match record.worker_id {
+            Some(id) => println!("{color}[worker {id}] {}\x1b[0m", record.message),
+            None => println!("{color}{}\x1b[0m", record.message),
+        }
+
+        self.write_json_line(&format!(
+            r#"{{"event":"log","level":"{}","worker_id":{},"message":{}}}"#,
+            record.level.as_str(),
+            record
+                .worker_id
+                .map(|id| id.to_string())
+                .unwrap_or_else(|| "null".to_string()),
+            json_escape(&record.message),
+        ));
+    }
+}
+
+fn result_kind_str(kind: ResultKind) -> &'static str {
+    match kind {
+        ResultKind::Success => "success",
+        ResultKind::Timeout => "timeout",
+        ResultKind::Crashed => "crashed",
+        ResultKind::Failed => "failed",
+    }
+}
+
+fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_escape_escapes_control_chars() {
+        assert_eq!(json_escape("a\"b\\c\nd"), r#""a\"b\\c\nd""#);
+    }
+
+    #[test]
+    fn test_json_escape_plain_text_is_unchanged_between_quotes() {
+        assert_eq!(json_escape("hello"), "\"hello\"");
+    }
+
+    #[test]
+    fn test_rotation_creates_second_generation_file() {
+        let dir = std::env::temp_dir().join(format!(
+            "eztrans_rs_report_test_{:?}",
+            std::thread::current().id()
+        ));
+        let _ = fs::create_dir_all(&dir);
+        let log_path = dir.join("test.log");
+        let _ = fs::remove_file(&log_path);
+        let _ = fs::remove_file(log_path.with_extension("log.1"));
+
+        let reporter = ConsoleJsonReporter::new(&log_path, 16).unwrap();
+        reporter.on_worker_event(LogRecord::new(LogLevel::Info, Some(0), "first message"));
+        reporter.on_worker_event(LogRecord::new(LogLevel::Info, Some(0), "second message"));
+
+        assert!(log_path.with_extension("log.1").exists());
+
+        let _ = fs::remove_file(&log_path);
+        let _ = fs::remove_file(log_path.with_extension("log.1"));
+    }
+}
@@ -0,0 +1,184 @@
+// `is_safe_chars`는 엔진이 `hangul_encode`의 정상 경로(한글 범위, `special_chars`) 밖에서
+// 추가로 원본 그대로 통과시키는 문자만 다룬다. 그 세 가지 모두에 해당하지 않는 문자
+// (이모지, 전각 문자, 희귀 한자 등)는 EzTrans에 그대로 넘기면 깨지거나 누락된다.
+// `SafeTranslate`는 그런 문자들을 번역 전에 가역적인 자리표시자로 바꿔 두었다가, 번역
+// 결과에서 원래 문자열로 복원해 준다.
+//
+// 구간 탐지는 `char_ranges::UnsafeScanner`에 맡긴다 — 단일 문자 검사만으로는 놓치는
+// 결합 문자 클러스터(`KNOWN_BAD_SEQUENCES`)까지 한 번의 선형 패스로 함께 찾아내고,
+// 겹치는 구간을 자동으로 최대 구간 하나로 합쳐 준다.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::OnceLock;
+
+use crate::char_ranges::{is_safe_chars, UnsafeScanner};
+use crate::{EzTransEngine, EzTransError};
+
+/// 단일 코드포인트 검사로는 못 잡는, 기반 문자 뒤에 결합 문자가 붙어야만 문제가 되는
+/// 시퀀스. 기반 문자 혼자는 `is_safe_chars`를 통과하더라도 뒤따르는 결합 문자와 한
+/// 자소 클러스터로 묶여 있으면 통째로 보호해야 한다.
+const KNOWN_BAD_SEQUENCES: &[&str] = &["¡\u{0301}", "¿\u{0301}"];
+
+fn unsafe_scanner() -> &'static UnsafeScanner {
+    static SCANNER: OnceLock<UnsafeScanner> = OnceLock::new();
+    SCANNER.get_or_init(|| {
+        UnsafeScanner::new(KNOWN_BAD_SEQUENCES).expect("KNOWN_BAD_SEQUENCES is a fixed valid pattern list")
+    })
+}
+
+/// 자리표시자 토큰이 실제 입력 텍스트와 섞이지 않도록 붙이는 접두/접미사.
+/// ASCII 시퀀스이므로 `hangul_encode`가 건드리지 않고, 엔진도 그대로 통과시킨다.
+const SENTINEL_PREFIX: &str = "\u{1}SAFE";
+const SENTINEL_SUFFIX: &str = "\u{2}";
+
+/// 엔진이 직접 다루지 못하는 문자를 자리표시자로 감싸 보호한 뒤 번역하고, 번역 결과에서
+/// 원래 문자열로 복원해 주는 래퍼.
+///
+/// `EzTransEngine`을 그대로 빌려 쓰며, 상태를 갖지 않으므로 호출마다 새로 만들어도 된다.
+pub struct SafeTranslate<'a> {
+    engine: &'a EzTransEngine,
+}
+
+impl<'a> SafeTranslate<'a> {
+    pub fn new(engine: &'a EzTransEngine) -> Self {
+        Self { engine }
+    }
+
+    /// 엔진이 알지 못하는 글자(이모지, 전각 문자, 희귀 한자 등)를 보존하며 번역합니다.
+    ///
+    /// 입력을 한 번 스캔해 `is_safe_chars`로도 안전하다고 판정되지 않는 문자의 연속
+    /// 구간을 자리표시자 토큰으로 치환하고, 원본 구간은 토큰을 키로 하는 맵에 기록해
+    /// 둡니다. 번역이 끝나면 결과에 남아 있는 토큰을 원본 구간으로 되돌려 놓습니다.
+    pub fn translate_safe(&self, input: &str) -> Result<String, EzTransError> {
+        let (protected, originals) = self.protect(input);
+        let translated = self.engine.default_translate(&protected)?;
+        Ok(restore(&translated, &originals))
+    }
+
+    /// 엔진이 그대로 통과시킬 수 없는 문자 구간을 자리표시자로 치환합니다.
+    ///
+    /// 구간은 두 출처를 합쳐 정해집니다: `is_engine_safe`를 통과하지 못하는 문자의
+    /// 연속 구간, 그리고 `UnsafeScanner`가 찾아낸 `KNOWN_BAD_SEQUENCES` 매치(기반
+    /// 문자는 혼자 안전해도 뒤따르는 결합 문자와 한 클러스터로 묶여 있는 경우). 두
+    /// 출처가 겹치거나 맞닿으면 하나의 최대 구간으로 합쳐 중첩 치환을 막습니다.
+    fn protect(&self, input: &str) -> (String, HashMap<String, String>) {
+        let mut spans: Vec<(usize, usize)> = unsafe_scanner().sequence_spans(input);
+
+        let mut chars = input.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            if self.is_engine_safe(c) {
+                continue;
+            }
+
+            let mut end = start + c.len_utf8();
+            while let Some(&(next_index, next)) = chars.peek() {
+                if self.is_engine_safe(next) {
+                    break;
+                }
+                end = next_index + next.len_utf8();
+                chars.next();
+            }
+
+            spans.push((start, end));
+        }
+
+        spans.sort_unstable();
+        let spans = crate::char_ranges::merge_spans(spans);
+
+        let mut protected = String::with_capacity(input.len());
+        let mut originals = HashMap::new();
+        let mut last_end = 0;
+
+        for (token_index, (start, end)) in spans.into_iter().enumerate() {
+            protected.push_str(&input[last_end..start]);
+
+            let token = format!("{SENTINEL_PREFIX}{token_index}{SENTINEL_SUFFIX}");
+            originals.insert(token.clone(), input[start..end].to_string());
+            protected.push_str(&token);
+
+            last_end = end;
+        }
+        protected.push_str(&input[last_end..]);
+
+        (protected, originals)
+    }
+
+    /// `c`가 엔진의 기존 경로(ASCII, 한글 범위, `special_chars`, `is_safe_chars`) 중
+    /// 하나를 통해 이미 안전하게 처리되는 문자인지 확인합니다.
+    fn is_engine_safe(&self, c: char) -> bool {
+        c.is_ascii()
+            || self.engine.is_hangul_range(c as u32)
+            || self.engine.special_chars.contains(&c)
+            || is_safe_chars(c)
+    }
+
+    /// `translate_safe`처럼 자리표시자 토큰을 새로 만드는 대신, `hangul_encode`와 같은
+    /// `+x{:04X}` 마커로 `is_engine_safe`를 통과하지 못하는 문자만 그 자리에서
+    /// 이스케이프한 뒤 곧바로 번역합니다. `default_translate`는 한 글자라도 인코딩이
+    /// 필요하면 문자열 전체를 `hangul_encode`로 한 번 더 훑어 한글/특수문자까지 다시
+    /// 손대므로, 그 문자들과 무관한 나머지 안전한 일본어 문장까지 덩달아 영향을 받을
+    /// 여지가 생깁니다. 여기서는 애초에 문제되는 문자만 골라 이스케이프해 두므로 그럴
+    /// 일이 없고, 복원도 새 로직 없이 기존 `hangul_decode`를 한 번 돌리는 것으로
+    /// 충분합니다 — 마커 형식이 같기 때문입니다.
+    ///
+    /// (`EzTransInner::translate_protected`는 이미 자소 클러스터를 플레이스홀더
+    /// 토큰으로 치환하는 다른 보호 방식에 이 이름을 쓰고 있어, 여기서는 같은 이름을
+    /// `SafeTranslate`의 메서드로 둬 구분합니다.)
+    pub fn translate_protected(&self, input: &str) -> Result<String, EzTransError> {
+        let escaped = self.escape_unsafe_runs(input);
+        let translated = if self.engine.initialize_ex.is_some() {
+            self.engine.translate_mmntw(&escaped)?
+        } else {
+            self.engine.translate_mmnt(&escaped)?
+        };
+        Ok(self.engine.hangul_decode(&translated))
+    }
+
+    /// `is_engine_safe`를 통과하지 못하는 문자만 `+x{:04X}`로 이스케이프하고 나머지는
+    /// 그대로 둡니다. `hangul_encode`와 마커 형식이 같으므로 복원은 `hangul_decode`가
+    /// 그대로 담당합니다.
+    fn escape_unsafe_runs(&self, input: &str) -> String {
+        let mut output = String::with_capacity(input.len());
+        for c in input.chars() {
+            if self.is_engine_safe(c) {
+                output.push(c);
+            } else {
+                write!(&mut output, "+x{:04X}", c as u32).unwrap();
+            }
+        }
+        output
+    }
+}
+
+/// 번역 결과에 남아 있는 자리표시자 토큰을 원본 구간으로 되돌립니다.
+fn restore(translated: &str, originals: &HashMap<String, String>) -> String {
+    let mut result = translated.to_string();
+    for (token, original) in originals {
+        result = result.replace(token, original);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restore_replaces_all_tokens() {
+        let mut originals = HashMap::new();
+        originals.insert("\u{1}SAFE0\u{2}".to_string(), "😀".to_string());
+        originals.insert("\u{1}SAFE1\u{2}".to_string(), "Ａ".to_string());
+
+        let translated = format!("hello \u{1}SAFE0\u{2} world \u{1}SAFE1\u{2}");
+        let restored = restore(&translated, &originals);
+
+        assert_eq!(restored, "hello 😀 world Ａ");
+    }
+
+    #[test]
+    fn test_restore_is_identity_without_tokens() {
+        let originals = HashMap::new();
+        assert_eq!(restore("hello world", &originals), "hello world");
+    }
+}
@@ -0,0 +1,159 @@
+// 엔진이 지원하지 않아 "?"/"□"로 깨지는 문자를 안전한 대체 문자로 바꿔 치환하는 표.
+//
+// `coverage::scan_charset_coverage`가 정확히 어떤 코드포인트가 깨지는지 찾아 주지만,
+// 그 결과를 매 호출마다 사람이 들여다보고 우회 문구로 바꿔 쓰는 건 번거롭다.
+// `SanitizerMap`은 "깨지는 코드포인트 -> 안전한 대체 문자열" 표를 들고 있다가
+// `EzTransEngine::translate_sanitized`가 번역 전에 입력을 치환하게 해 준다. 표는
+// `CoverageReport`에서 시작점을 뽑아내거나, JSON 파일로 저장/로드해 손으로 채울 수
+// 있다.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::coverage::{CoverageReport, IssueType};
+use crate::{EzTransError, EzTransInner};
+
+/// 엔진이 깨뜨리는 코드포인트를 안전한 대체 문자열로 바꾸는 표.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SanitizerMap {
+    substitutes: HashMap<u32, String>,
+}
+
+impl SanitizerMap {
+    /// 빈 표로 시작한다.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 이미 알려진 스캔 결과로부터 뽑아낸 기본 대체 표. 전각 기호 중 엔진이 자주
+    /// 깨뜨리는 것들을 반각/ASCII 등가물로 옮겨 둔다.
+    pub fn default_map() -> Self {
+        let mut map = Self::new();
+        map.insert(0x301C, "~"); // WAVE DASH
+        map.insert(0xFF5E, "~"); // FULLWIDTH TILDE
+        map.insert(0x2014, "-"); // EM DASH
+        map.insert(0x2015, "-"); // HORIZONTAL BAR
+        map.insert(0x2212, "-"); // MINUS SIGN
+        map
+    }
+
+    /// `report`에서 `square`/`question_mark`로 분류된 코드포인트들을 골라, 원래
+    /// 문자 자체를 자리표시자 대체값으로 써서 채워 둔 시작용 표를 만든다. 호출자가
+    /// 각 항목을 실제 대체 문자열로 채워 넣는 것을 전제로 한다.
+    pub fn from_coverage_report(report: &CoverageReport) -> Self {
+        let mut map = Self::new();
+        for result in &report.results {
+            if matches!(result.issue_type, IssueType::Square | IssueType::QuestionMark) {
+                map.substitutes
+                    .entry(result.code)
+                    .or_insert_with(|| result.character.clone());
+            }
+        }
+        map
+    }
+
+    /// `code`에 대한 대체 문자열을 등록(혹은 덮어쓰기)한다.
+    pub fn insert(&mut self, code: u32, substitute: impl Into<String>) {
+        self.substitutes.insert(code, substitute.into());
+    }
+
+    /// JSON 파일에서 표를 읽어온다.
+    pub fn load_from_json<P: AsRef<Path>>(path: P) -> Result<Self, EzTransError> {
+        let contents = std::fs::read_to_string(path)?;
+        serde_json::from_str(&contents)
+            .map_err(|e| EzTransError::FunctionCallFailed(format!("sanitizer map 파싱 실패: {e}")))
+    }
+
+    /// 표를 JSON 파일로 저장한다.
+    pub fn save_to_json<P: AsRef<Path>>(&self, path: P) -> Result<(), EzTransError> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| EzTransError::FunctionCallFailed(format!("sanitizer map 직렬화 실패: {e}")))?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// `text`의 각 문자를 표에 등록된 대체 문자열로 바꾼다. 등록되지 않은 문자는
+    /// 그대로 둔다.
+    fn apply(&self, text: &str) -> String {
+        text.chars()
+            .map(|c| {
+                self.substitutes
+                    .get(&(c as u32))
+                    .cloned()
+                    .unwrap_or_else(|| c.to_string())
+            })
+            .collect()
+    }
+}
+
+impl EzTransInner {
+    /// 이후 `translate_sanitized` 호출이 쓸 대체 표를 통째로 교체한다.
+    pub fn set_sanitizer_map(&self, map: SanitizerMap) {
+        *self.sanitizer.lock().unwrap() = map;
+    }
+
+    /// 현재 등록된 대체 표로 `text`를 치환한 뒤 [`default_translate`](Self::default_translate)로
+    /// 번역한다.
+    pub fn translate_sanitized(&self, text: &str) -> Result<String, EzTransError> {
+        let sanitized = self.sanitizer.lock().unwrap().apply(text);
+        self.default_translate(&sanitized)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coverage::DetectionResult;
+
+    #[test]
+    fn test_default_map_replaces_wave_dash() {
+        let map = SanitizerMap::default_map();
+        assert_eq!(map.apply("〜"), "~");
+    }
+
+    #[test]
+    fn test_apply_leaves_unmapped_chars_untouched() {
+        let map = SanitizerMap::new();
+        assert_eq!(map.apply("안녕하세요"), "안녕하세요");
+    }
+
+    #[test]
+    fn test_from_coverage_report_only_picks_up_square_and_question_mark() {
+        let report = CoverageReport {
+            total_tested: 3,
+            square_count: 1,
+            question_mark_count: 1,
+            different_count: 1,
+            error_count: 0,
+            results: vec![
+                DetectionResult {
+                    code: 0x3231,
+                    character: "㈱".to_string(),
+                    translation: "□".to_string(),
+                    issue_type: IssueType::Square,
+                },
+                DetectionResult {
+                    code: 0x2026,
+                    character: "…".to_string(),
+                    translation: "?".to_string(),
+                    issue_type: IssueType::QuestionMark,
+                },
+                DetectionResult {
+                    code: 0x3042,
+                    character: "あ".to_string(),
+                    translation: "아".to_string(),
+                    issue_type: IssueType::Different,
+                },
+            ],
+            status: crate::coverage::CoverageStatus::Degraded,
+        };
+
+        let map = SanitizerMap::from_coverage_report(&report);
+        assert_eq!(map.substitutes.len(), 2);
+        assert_eq!(map.substitutes.get(&0x3231).map(String::as_str), Some("㈱"));
+        assert_eq!(map.substitutes.get(&0x2026).map(String::as_str), Some("…"));
+        assert!(!map.substitutes.contains_key(&0x3042));
+    }
+}
@@ -1,76 +1,196 @@
 // Named Pipe Server implementation
 
 use std::mem::size_of;
-use windows::Win32::Foundation::{CloseHandle, HANDLE, INVALID_HANDLE_VALUE};
+use std::sync::{Arc, Mutex};
+use windows::Win32::Foundation::{CloseHandle, HANDLE, INVALID_HANDLE_VALUE, WAIT_OBJECT_0};
 use windows::Win32::Storage::FileSystem::{FILE_FLAGS_AND_ATTRIBUTES, ReadFile, WriteFile};
+use windows::Win32::System::IO::{GetOverlappedResult, OVERLAPPED};
 use windows::Win32::System::Pipes::*;
+use windows::Win32::System::Threading::{CreateEventW, INFINITE, WaitForMultipleObjects};
 use windows::core::PCWSTR;
 
+use crate::glossary::Glossary;
 use crate::ipc_protocol::*;
 use crate::{EzTransEngine, EzTransError};
 
 // Constants from Windows SDK
 const PIPE_ACCESS_DUPLEX: FILE_FLAGS_AND_ATTRIBUTES = FILE_FLAGS_AND_ATTRIBUTES(0x00000003);
+const FILE_FLAG_OVERLAPPED: FILE_FLAGS_AND_ATTRIBUTES = FILE_FLAGS_AND_ATTRIBUTES(0x40000000);
 
-pub struct TransProxyServer {
-    pipe_handle: HANDLE,
+/// 동시에 연결을 받아줄 파이프 인스턴스 수. 이 이상의 클라이언트는 OS 수준에서 대기한다.
+const POOL_SIZE: usize = 8;
+
+/// `MessageHeader::payload_size`로 선언할 수 있는 바디 크기의 상한. `read_body`는
+/// 이 값을 믿고 그대로 `vec![0u8; size]`를 할당하므로, 상한이 없으면 잘못되거나 악의적인
+/// `payload_size`(최대 4GiB) 하나로 서버를 메모리 부족에 빠뜨릴 수 있다.
+const MAX_PAYLOAD_SIZE: u32 = 16 * 1024 * 1024;
+
+/// 여러 클라이언트가 공유하는 엔진/사전 상태. `EzTransEngine`은 한 번만 초기화되고
+/// 모든 파이프 인스턴스가 같은 인스턴스를 통해 번역을 요청한다.
+#[derive(Default)]
+struct SharedState {
     engine: Option<EzTransEngine>,
     initialized: bool,
-    running: bool,
+    glossary: Option<Glossary>,
 }
 
-impl TransProxyServer {
-    pub fn new() -> Self {
-        Self {
-            pipe_handle: INVALID_HANDLE_VALUE,
-            engine: None,
-            initialized: false,
-            running: true,
-        }
-    }
+/// 오버랩드 I/O로 동작하는 파이프 인스턴스 하나.
+///
+/// 자신의 `OVERLAPPED` 구조체와 이벤트 핸들을 들고 `ConnectNamedPipe`를 비동기로 건 뒤,
+/// 이벤트 핸들들을 `WaitForMultipleObjects`로 한데 묶어 기다림으로써 여러 클라이언트의
+/// 연결 완료를 동시에 감시할 수 있다.
+struct PipeInstance {
+    handle: HANDLE,
+    overlapped: Box<OVERLAPPED>,
+    event: HANDLE,
+    /// 클라이언트가 연결되어 요청을 처리할 준비가 되었는지.
+    connected: bool,
+}
 
-    pub fn start(&mut self) -> Result<(), EzTransError> {
+impl PipeInstance {
+    fn new() -> Result<Self, EzTransError> {
         unsafe {
+            let event = CreateEventW(None, true, false, None)
+                .map_err(|e| EzTransError::PipeError(e.to_string()))?;
+
             let pipe_name: Vec<u16> = PIPE_NAME.encode_utf16().chain(std::iter::once(0)).collect();
 
-            self.pipe_handle = CreateNamedPipeW(
+            let handle = CreateNamedPipeW(
                 PCWSTR(pipe_name.as_ptr()),
-                PIPE_ACCESS_DUPLEX,
+                PIPE_ACCESS_DUPLEX | FILE_FLAG_OVERLAPPED,
                 PIPE_TYPE_MESSAGE | PIPE_READMODE_MESSAGE | PIPE_WAIT,
-                1,    // Max instances
+                POOL_SIZE as u32,
                 8192, // Out buffer size
                 8192, // In buffer size
                 0,    // Timeout
                 None, // Security attributes
             );
 
-            if self.pipe_handle == INVALID_HANDLE_VALUE {
+            if handle == INVALID_HANDLE_VALUE {
                 return Err(EzTransError::PipeError(
                     "Failed to create named pipe".to_string(),
                 ));
             }
 
-            // Wait for client connection
-            ConnectNamedPipe(self.pipe_handle, None)?;
+            let mut overlapped = Box::new(OVERLAPPED::default());
+            overlapped.hEvent = event;
+
+            let mut instance = Self {
+                handle,
+                overlapped,
+                event,
+                connected: false,
+            };
+            instance.begin_connect()?;
+            Ok(instance)
+        }
+    }
 
-            Ok(())
+    /// 이 인스턴스에 대해 비동기 `ConnectNamedPipe`를 건다.
+    fn begin_connect(&mut self) -> Result<(), EzTransError> {
+        self.connected = false;
+        unsafe {
+            match ConnectNamedPipe(self.handle, Some(self.overlapped.as_mut())) {
+                Ok(()) => {
+                    // 이미 클라이언트가 연결되어 있던 경우 (드문 경쟁 상태)
+                    self.connected = true;
+                    Ok(())
+                }
+                Err(e) if e.code().0 as u32 == ERROR_IO_PENDING => Ok(()),
+                Err(e) if e.code().0 as u32 == ERROR_PIPE_CONNECTED => {
+                    self.connected = true;
+                    Ok(())
+                }
+                Err(e) => Err(EzTransError::PipeError(e.to_string())),
+            }
         }
     }
 
+    /// 대기 중이던 오버랩드 연결 작업이 끝났는지 확인하고, 끝났다면 연결 완료로 표시한다.
+    fn complete_connect(&mut self) -> Result<(), EzTransError> {
+        unsafe {
+            let mut transferred = 0u32;
+            GetOverlappedResult(self.handle, self.overlapped.as_ref(), &mut transferred, false)?;
+        }
+        self.connected = true;
+        Ok(())
+    }
+
+    fn disconnect(&mut self) {
+        unsafe {
+            let _ = DisconnectNamedPipe(self.handle);
+        }
+        let _ = self.begin_connect();
+    }
+}
+
+const ERROR_IO_PENDING: u32 = 997;
+const ERROR_PIPE_CONNECTED: u32 = 535;
+
+pub struct TransProxyServer {
+    instances: Vec<PipeInstance>,
+    shared: Arc<Mutex<SharedState>>,
+    running: bool,
+}
+
+impl TransProxyServer {
+    pub fn new() -> Self {
+        Self {
+            instances: Vec::new(),
+            shared: Arc::new(Mutex::new(SharedState::default())),
+            running: true,
+        }
+    }
+
+    pub fn start(&mut self) -> Result<(), EzTransError> {
+        for _ in 0..POOL_SIZE {
+            self.instances.push(PipeInstance::new()?);
+        }
+        Ok(())
+    }
+
+    /// 접속 완료/요청 처리를 반복하는 이벤트 루프.
+    ///
+    /// 각 반복마다 모든 인스턴스의 이벤트 핸들을 `WaitForMultipleObjects`로 동시에 기다리다가,
+    /// 연결이 완료된 인스턴스 하나만 골라 요청을 처리한다. 처리하는 동안에도 다른 인스턴스들의
+    /// `ConnectNamedPipe`는 커널에서 계속 진행되므로, 한 클라이언트를 서비스하는 동안 다른
+    /// 클라이언트가 연결을 거부당하지 않는다.
     pub fn run(&mut self) {
         while self.running {
-            if let Err(e) = self.process_request() {
-                eprintln!("Error processing request: {}", e);
+            let events: Vec<HANDLE> = self.instances.iter().map(|i| i.event).collect();
+
+            let wait_result = unsafe { WaitForMultipleObjects(&events, false, INFINITE) };
+
+            let index = (wait_result.0.wrapping_sub(WAIT_OBJECT_0.0)) as usize;
+            if index >= self.instances.len() {
+                eprintln!("WaitForMultipleObjects returned an unexpected index");
                 break;
             }
+
+            if !self.instances[index].connected {
+                if let Err(e) = self.instances[index].complete_connect() {
+                    eprintln!("Failed to complete connect on instance {}: {}", index, e);
+                    self.instances[index].disconnect();
+                    continue;
+                }
+            }
+
+            if let Err(e) = self.process_request(index) {
+                eprintln!("Error processing request on instance {}: {}", index, e);
+                // 요청 처리가 오류로 끝난 경우(파이프가 끊겼거나 읽기/쓰기 I/O가 실패했거나
+                // 알 수 없는 명령이 온 경우)에만 연결을 끊는다. `PipeClient`/`MultiplexedPipeClient`는 한 연결에서 여러
+                // 요청을 순서대로(또는 파이프라이닝해서) 보내므로, 요청 하나가 성공적으로
+                // 끝났다고 매번 연결을 끊으면 그 다음 요청이 이미 닫힌 핸들에 막혀 버린다.
+                self.instances[index].disconnect();
+            }
         }
     }
 
-    fn read_message<T>(&self, buffer: &mut T) -> Result<(), EzTransError> {
+    fn read_message<T>(&self, index: usize, buffer: &mut T) -> Result<(), EzTransError> {
         unsafe {
             let mut bytes_read = 0u32;
             ReadFile(
-                self.pipe_handle,
+                self.instances[index].handle,
                 Some(std::slice::from_raw_parts_mut(
                     buffer as *mut T as *mut u8,
                     size_of::<T>(),
@@ -87,11 +207,72 @@ impl TransProxyServer {
         }
     }
 
-    fn write_message<T>(&self, buffer: &T) -> Result<(), EzTransError> {
+    /// `MessageHeader::payload_size` 만큼의 가변 길이 바디를 읽는다. 메시지 모드 파이프라도
+    /// 큰 바디는 여러 번의 `ReadFile` 호출로 나뉠 수 있으므로 다 채울 때까지 반복한다.
+    fn read_body(&self, index: usize, size: usize) -> Result<Vec<u8>, EzTransError> {
+        let mut body = vec![0u8; size];
+        let mut filled = 0usize;
+        while filled < size {
+            let mut bytes_read = 0u32;
+            unsafe {
+                ReadFile(
+                    self.instances[index].handle,
+                    Some(&mut body[filled..]),
+                    Some(&mut bytes_read),
+                    None,
+                )?;
+            }
+            if bytes_read == 0 {
+                return Err(EzTransError::IncompleteRead);
+            }
+            filled += bytes_read as usize;
+        }
+        Ok(body)
+    }
+
+    /// `payload_size`가 [`MAX_PAYLOAD_SIZE`]를 넘으면 바디를 읽지 않고 곧바로
+    /// `Status::InvalidParameter` 응답을 돌려준다. 호출자는 `true`가 돌아오면 더 이상
+    /// 처리하지 말고 그대로 반환해야 한다.
+    fn reject_if_payload_too_large(
+        &self,
+        index: usize,
+        payload_size: u32,
+    ) -> Result<bool, EzTransError> {
+        if payload_size <= MAX_PAYLOAD_SIZE {
+            return Ok(false);
+        }
+
+        let header = TranslateResponseHeader {
+            status: Status::InvalidParameter,
+            result_code: -1,
+        };
+        self.write_framed(index, &header, &[])?;
+        Ok(true)
+    }
+
+    /// 고정 크기 헤더(`T`)를 쓴 뒤 가변 길이 바디를 이어서 쓴다.
+    fn write_framed<T>(&self, index: usize, header: &T, body: &[u8]) -> Result<(), EzTransError> {
+        self.write_message(index, header)?;
         unsafe {
             let mut bytes_written = 0u32;
             WriteFile(
-                self.pipe_handle,
+                self.instances[index].handle,
+                Some(body),
+                Some(&mut bytes_written),
+                None,
+            )?;
+            if bytes_written as usize != body.len() {
+                return Err(EzTransError::IncompleteWrite);
+            }
+        }
+        Ok(())
+    }
+
+    fn write_message<T>(&self, index: usize, buffer: &T) -> Result<(), EzTransError> {
+        unsafe {
+            let mut bytes_written = 0u32;
+            WriteFile(
+                self.instances[index].handle,
                 Some(std::slice::from_raw_parts(
                     buffer as *const T as *const u8,
                     size_of::<T>(),
@@ -108,24 +289,26 @@ impl TransProxyServer {
         }
     }
 
-    fn process_request(&mut self) -> Result<(), EzTransError> {
+    fn process_request(&mut self, index: usize) -> Result<(), EzTransError> {
         let mut header = MessageHeader {
             command: 0,
             payload_size: 0,
             request_id: 0,
         };
 
-        self.read_message(&mut header)?;
+        self.read_message(index, &mut header)?;
 
         let command = Command::try_from(header.command)?;
 
         match command {
-            Command::Initialize => self.handle_initialize(),
-            Command::Terminate => self.handle_terminate(),
-            Command::TranslateMMNT => self.handle_translate_mmnt(),
-            Command::TranslateMMNTW => self.handle_translate_mmntw(),
-            Command::ReloadUserDict => self.handle_reload_user_dict(),
-            Command::SetProperty => self.handle_set_property(),
+            Command::Initialize => self.handle_initialize(index),
+            Command::Terminate => self.handle_terminate(index),
+            Command::TranslateMMNT => self.handle_translate_mmnt(index, header.payload_size),
+            Command::TranslateMMNTW => self.handle_translate_mmntw(index, header.payload_size),
+            Command::ReloadUserDict => self.handle_reload_user_dict(index),
+            Command::SetProperty => self.handle_set_property(index),
+            Command::LoadGlossary => self.handle_load_glossary(index),
+            Command::TranslateBatch => self.handle_translate_batch(index, header.payload_size),
             Command::Shutdown => {
                 self.running = false;
                 Ok(())
@@ -134,16 +317,16 @@ impl TransProxyServer {
                 let response = GenericResponse {
                     status: Status::Success,
                 };
-                self.write_message(&response)
+                self.write_message(index, &response)
             }
         }
     }
 
-    fn handle_initialize(&mut self) -> Result<(), EzTransError> {
+    fn handle_initialize(&mut self, index: usize) -> Result<(), EzTransError> {
         let mut request = InitializeRequest {
             engine_path: [0; 260],
         };
-        self.read_message(&mut request)?;
+        self.read_message(index, &mut request)?;
 
         // UTF-16 경로 파싱
         let path_str = String::from_utf16_lossy(&request.engine_path);
@@ -153,173 +336,440 @@ impl TransProxyServer {
         let dat_path = format!("{}\\Dat", path_str);
 
         // EzTransEngine 사용 (중복 코드 제거)
-        match EzTransEngine::new(&dll_path) {
+        let response = match EzTransEngine::new(&dll_path) {
             Ok(engine) => match engine.initialize_ex("CSUSER123455", &dat_path) {
                 Ok(_) => {
-                    self.engine = Some(engine);
-                    self.initialized = true;
-                    let response = InitializeResponse {
+                    let mut shared = self.shared.lock().unwrap();
+                    shared.engine = Some(engine);
+                    shared.initialized = true;
+                    InitializeResponse {
                         status: Status::Success,
                         success: true,
-                    };
-                    self.write_message(&response)
-                }
-                Err(_) => {
-                    let response = InitializeResponse {
-                        status: Status::Error,
-                        success: false,
-                    };
-                    self.write_message(&response)
+                    }
                 }
-            },
-            Err(_) => {
-                let response = InitializeResponse {
+                Err(_) => InitializeResponse {
                     status: Status::Error,
                     success: false,
-                };
-                self.write_message(&response)
-            }
-        }
+                },
+            },
+            Err(_) => InitializeResponse {
+                status: Status::Error,
+                success: false,
+            },
+        };
+
+        self.write_message(index, &response)
     }
 
-    fn handle_terminate(&mut self) -> Result<(), EzTransError> {
-        if let Some(ref engine) = self.engine {
-            let _ = engine.terminate();
+    fn handle_terminate(&mut self, index: usize) -> Result<(), EzTransError> {
+        {
+            let mut shared = self.shared.lock().unwrap();
+            if let Some(ref engine) = shared.engine {
+                let _ = engine.terminate();
+            }
+            shared.engine = None;
+            shared.initialized = false;
         }
-        self.engine = None;
-        self.initialized = false;
 
         let response = GenericResponse {
             status: Status::Success,
         };
-        self.write_message(&response)
+        self.write_message(index, &response)
     }
 
-    fn handle_translate_mmnt(&mut self) -> Result<(), EzTransError> {
-        let mut request = TranslateMMNTRequest {
-            data0: 0,
-            text: [0; 4096],
-        };
-        self.read_message(&mut request)?;
-
-        let mut response = TranslateMMNTResponse {
-            status: Status::Success,
-            result_code: -1,
-            translated: [0; 4096],
-        };
-
-        if let Some(ref engine) = self.engine {
-            // 입력 텍스트 추출 (null 종료까지)
-            let text_len = request.text.iter().position(|&x| x == 0).unwrap_or(4096);
-
-            // Shift-JIS → UTF-8 디코딩
-            let (decoded, _, _) = encoding_rs::SHIFT_JIS.decode(&request.text[..text_len]);
-
-            // 번역 (한글 인코딩 포함)
-            match engine.translate_mmnt(&decoded) {
+    fn handle_translate_mmnt(&mut self, index: usize, payload_size: u32) -> Result<(), EzTransError> {
+        if self.reject_if_payload_too_large(index, payload_size)? {
+            return Ok(());
+        }
+        let body = self.read_body(index, payload_size as usize)?;
+        let header_size = size_of::<TranslateRequestHeader>();
+        let data0 = u32::from_le_bytes(body[0..4].try_into().unwrap());
+        let _ = data0; // 현재 핸들러에서는 쓰이지 않지만 와이어 포맷상 자리를 유지한다.
+        let text_bytes = &body[header_size..];
+
+        // Shift-JIS → UTF-8 디코딩
+        let (decoded, _, _) = encoding_rs::SHIFT_JIS.decode(text_bytes);
+
+        let shared = self.shared.lock().unwrap();
+        let (status, result_code, encoded) = if let Some(ref engine) = shared.engine {
+            // 용어집이 있으면 원문 용어를 센티넬로 보호한 뒤 번역한다.
+            let protected = match &shared.glossary {
+                Some(glossary) => glossary.protect(&decoded),
+                None => decoded.into_owned(),
+            };
+
+            match translate_chunked(&protected, |chunk| engine.translate_mmnt(chunk)) {
                 Ok(translated) => {
-                    // UTF-8 → EUC-KR 인코딩
+                    let translated = match &shared.glossary {
+                        Some(glossary) => glossary.restore(&translated),
+                        None => translated,
+                    };
                     let (encoded, _, _) = encoding_rs::EUC_KR.encode(&translated);
-                    let len = encoded.len().min(4096);
-                    response.translated[..len].copy_from_slice(&encoded[..len]);
-                    response.result_code = 0;
-                    response.status = Status::Success;
-                }
-                Err(_) => {
-                    response.status = Status::Error;
+                    (Status::Success, 0, encoded.into_owned())
                 }
+                Err(_) => (Status::Error, -1, Vec::new()),
             }
         } else {
-            response.status = Status::NotInitialized;
-        }
-
-        self.write_message(&response)
-    }
-
-    fn handle_translate_mmntw(&mut self) -> Result<(), EzTransError> {
-        let mut request = TranslateMMNTWRequest {
-            data0: 0,
-            text: [0; 4096],
+            (Status::NotInitialized, -1, Vec::new())
         };
-        self.read_message(&mut request)?;
+        drop(shared);
 
-        let mut response = TranslateMMNTWResponse {
-            status: Status::Success,
-            result_code: -1,
-            translated: [0; 4096],
+        let header = TranslateResponseHeader {
+            status,
+            result_code,
         };
+        self.write_framed(index, &header, &encoded)
+    }
 
-        if let Some(ref engine) = self.engine {
-            // UTF-16 → String 변환
-            let text_len = request.text.iter().position(|&x| x == 0).unwrap_or(4096);
-            let input = String::from_utf16_lossy(&request.text[..text_len]);
-
-            // 번역 (한글 인코딩/디코딩 자동 포함)
-            match engine.default_translate(&input) {
+    fn handle_translate_mmntw(&mut self, index: usize, payload_size: u32) -> Result<(), EzTransError> {
+        if self.reject_if_payload_too_large(index, payload_size)? {
+            return Ok(());
+        }
+        let body = self.read_body(index, payload_size as usize)?;
+        let header_size = size_of::<TranslateRequestHeader>();
+        let data0 = u32::from_le_bytes(body[0..4].try_into().unwrap());
+        let _ = data0;
+        let text_u16: Vec<u16> = body[header_size..]
+            .chunks_exact(2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+            .collect();
+        let input = String::from_utf16_lossy(&text_u16);
+
+        let shared = self.shared.lock().unwrap();
+        let (status, result_code, translated_utf16) = if let Some(ref engine) = shared.engine {
+            // 용어집이 있으면 원문 용어를 센티넬로 보호한 뒤 번역한다.
+            let protected = match &shared.glossary {
+                Some(glossary) => glossary.protect(&input),
+                None => input,
+            };
+
+            match translate_chunked(&protected, |chunk| engine.default_translate(chunk)) {
                 Ok(translated) => {
-                    // String → UTF-16 변환
-                    let utf16: Vec<u16> = translated.encode_utf16().collect();
-                    let len = utf16.len().min(4095);
-                    response.translated[..len].copy_from_slice(&utf16[..len]);
-                    response.translated[len] = 0; // null 종료
-                    response.result_code = 0;
-                    response.status = Status::Success;
-                }
-                Err(_) => {
-                    response.status = Status::Error;
+                    let translated = match &shared.glossary {
+                        Some(glossary) => glossary.restore(&translated),
+                        None => translated,
+                    };
+                    let utf16: Vec<u8> = translated
+                        .encode_utf16()
+                        .flat_map(|u| u.to_le_bytes())
+                        .collect();
+                    (Status::Success, 0, utf16)
                 }
+                Err(_) => (Status::Error, -1, Vec::new()),
             }
         } else {
-            response.status = Status::NotInitialized;
-        }
+            (Status::NotInitialized, -1, Vec::new())
+        };
+        drop(shared);
 
-        self.write_message(&response)
+        let header = TranslateResponseHeader {
+            status,
+            result_code,
+        };
+        self.write_framed(index, &header, &translated_utf16)
     }
 
-    fn handle_reload_user_dict(&mut self) -> Result<(), EzTransError> {
-        if let Some(ref engine) = self.engine {
-            let _ = engine.reload_user_dict();
+    fn handle_reload_user_dict(&mut self, index: usize) -> Result<(), EzTransError> {
+        {
+            let shared = self.shared.lock().unwrap();
+            if let Some(ref engine) = shared.engine {
+                let _ = engine.reload_user_dict();
+            }
         }
 
         let response = GenericResponse {
             status: Status::Success,
         };
-        self.write_message(&response)
+        self.write_message(index, &response)
     }
 
-    fn handle_set_property(&mut self) -> Result<(), EzTransError> {
+    fn handle_set_property(&mut self, index: usize) -> Result<(), EzTransError> {
         let mut request = SetPropertyRequest {
             property_id: 0,
             value: 0,
         };
-        self.read_message(&mut request)?;
+        self.read_message(index, &mut request)?;
 
-        let response = if let Some(ref engine) = self.engine {
-            match engine.set_property(request.property_id, request.value) {
-                Ok(_) => GenericResponse {
-                    status: Status::Success,
-                },
-                Err(_) => GenericResponse {
-                    status: Status::Error,
-                },
+        let response = {
+            let shared = self.shared.lock().unwrap();
+            if let Some(ref engine) = shared.engine {
+                match engine.set_property(request.property_id, request.value) {
+                    Ok(_) => GenericResponse {
+                        status: Status::Success,
+                    },
+                    Err(_) => GenericResponse {
+                        status: Status::Error,
+                    },
+                }
+            } else {
+                GenericResponse {
+                    status: Status::NotInitialized,
+                }
             }
-        } else {
-            GenericResponse {
-                status: Status::NotInitialized,
+        };
+
+        self.write_message(index, &response)
+    }
+
+    fn handle_load_glossary(&mut self, index: usize) -> Result<(), EzTransError> {
+        let mut request = LoadGlossaryRequest {
+            size: 0,
+            data: [0; 16384],
+        };
+        self.read_message(index, &mut request)?;
+
+        let len = (request.size as usize).min(request.data.len());
+        let body = String::from_utf16_lossy(&request.data[..len]);
+        self.shared.lock().unwrap().glossary = Some(Glossary::parse(&body));
+
+        let response = GenericResponse {
+            status: Status::Success,
+        };
+        self.write_message(index, &response)
+    }
+
+    /// 여러 세그먼트를 한 번의 파이프 왕복으로 번역한다. 자막 파일이나 대사 테이블처럼
+    /// 수천 건의 `TranslateMMNTW` 호출을 개별로 보내는 것이 병목인 호출자를 위한 것이다.
+    /// 한 세그먼트가 실패해도 나머지 세그먼트의 인덱스 정렬은 그대로 유지된다.
+    fn handle_translate_batch(&mut self, index: usize, payload_size: u32) -> Result<(), EzTransError> {
+        if self.reject_if_payload_too_large(index, payload_size)? {
+            return Ok(());
+        }
+        let body = self.read_body(index, payload_size as usize)?;
+        let segments = parse_batch_segments(&body);
+
+        let shared = self.shared.lock().unwrap();
+        let mut response = Vec::new();
+        response.extend_from_slice(&(segments.len() as u32).to_le_bytes());
+
+        for segment in segments {
+            let translated = match &shared.engine {
+                Some(engine) => {
+                    let protected = match &shared.glossary {
+                        Some(glossary) => glossary.protect(&segment),
+                        None => segment,
+                    };
+                    engine.default_translate(&protected).map(|t| match &shared.glossary {
+                        Some(glossary) => glossary.restore(&t),
+                        None => t,
+                    })
+                }
+                None => Err(EzTransError::FunctionLoadError(
+                    "엔진이 초기화되지 않았습니다.".to_string(),
+                )),
+            };
+
+            match translated {
+                Ok(text) => {
+                    let utf16: Vec<u16> = text.encode_utf16().collect();
+                    response.extend_from_slice(&(Status::Success as u32).to_le_bytes());
+                    response.extend_from_slice(&(utf16.len() as u32).to_le_bytes());
+                    for unit in utf16 {
+                        response.extend_from_slice(&unit.to_le_bytes());
+                    }
+                }
+                Err(_) => {
+                    response.extend_from_slice(&(Status::Error as u32).to_le_bytes());
+                    response.extend_from_slice(&0u32.to_le_bytes());
+                }
             }
+        }
+        drop(shared);
+
+        let header = MessageHeader {
+            command: Command::TranslateBatch as u32,
+            payload_size: response.len() as u32,
+            request_id: 0,
         };
+        self.write_message(index, &header)?;
+        let mut bytes_written = 0u32;
+        unsafe {
+            WriteFile(
+                self.instances[index].handle,
+                Some(&response),
+                Some(&mut bytes_written),
+                None,
+            )?;
+        }
+        if bytes_written as usize != response.len() {
+            return Err(EzTransError::IncompleteWrite);
+        }
+        Ok(())
+    }
+}
 
-        self.write_message(&response)
+/// 배치 요청 바디에서 `len`(u32) 접두 UTF-16 세그먼트들을 파싱한다. 길이가 잘못되어 범위를
+/// 벗어나는 세그먼트를 만나면 조용히 파싱을 멈추고, 그 앞까지의 세그먼트만 돌려준다.
+fn parse_batch_segments(body: &[u8]) -> Vec<String> {
+    if body.len() < 4 {
+        return Vec::new();
     }
+
+    let count = u32::from_le_bytes(body[0..4].try_into().unwrap()) as usize;
+    let mut segments = Vec::with_capacity(count);
+    let mut offset = 4usize;
+
+    for _ in 0..count {
+        if offset + 4 > body.len() {
+            break;
+        }
+        let len = u32::from_le_bytes(body[offset..offset + 4].try_into().unwrap()) as usize;
+        offset += 4;
+
+        let byte_len = len * 2;
+        if offset + byte_len > body.len() {
+            break;
+        }
+
+        let utf16: Vec<u16> = body[offset..offset + byte_len]
+            .chunks_exact(2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+            .collect();
+        segments.push(String::from_utf16_lossy(&utf16));
+        offset += byte_len;
+    }
+
+    segments
 }
 
-impl Drop for TransProxyServer {
-    fn drop(&mut self) {
-        if self.pipe_handle != INVALID_HANDLE_VALUE {
-            unsafe {
-                let _ = DisconnectNamedPipe(self.pipe_handle);
-                let _ = CloseHandle(self.pipe_handle);
+/// 엔진이 한 번에 처리하기 버거운 긴 입력을 문장 경계 단위로 잘라 번역한 뒤 이어붙인다.
+/// IPC 프레이밍은 `MAX_PAYLOAD_SIZE`까지의 바디를 받지만, DLL 호출은 여전히 적당한 크기로
+/// 쪼개 보내는 편이 안전하다.
+fn translate_chunked<F>(text: &str, mut translate_one: F) -> Result<String, EzTransError>
+where
+    F: FnMut(&str) -> Result<String, EzTransError>,
+{
+    let mut result = String::with_capacity(text.len());
+    for chunk in chunk_text(text, MAX_CHUNK_CHARS) {
+        result.push_str(&translate_one(&chunk)?);
+    }
+    Ok(result)
+}
+
+/// 번역 호출 하나에 실어 보내는 텍스트의 목표 최대 길이(문자 수).
+const MAX_CHUNK_CHARS: usize = 1500;
+
+/// 입력을 문장 경계(`。`, `！`, `？`, 줄바꿈)에서 나눠 `max_chars`를 넘지 않는 조각들로 묶는다.
+/// 문장 하나가 이미 한도를 넘는 경우에는 어쩔 수 없이 그대로 하나의 조각이 된다.
+fn chunk_text(text: &str, max_chars: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0usize;
+
+    for sentence in split_sentences(text) {
+        let sentence_len = sentence.chars().count();
+        if current_len > 0 && current_len + sentence_len > max_chars {
+            chunks.push(std::mem::take(&mut current));
+            current_len = 0;
+        }
+        current.push_str(sentence);
+        current_len += sentence_len;
+    }
+
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+
+    chunks
+}
+
+/// 문장 종결 문자를 포함해 문장 단위로 쪼갠다.
+fn split_sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+
+    for (i, ch) in text.char_indices() {
+        if matches!(ch, '。' | '！' | '？' | '\n') {
+            let end = i + ch.len_utf8();
+            sentences.push(&text[start..end]);
+            start = end;
+        }
+    }
+
+    if start < text.len() {
+        sentences.push(&text[start..]);
+    }
+
+    sentences
+}
+
+#[cfg(test)]
+mod batch_tests {
+    use super::*;
+
+    fn encode_segments(segments: &[&str]) -> Vec<u8> {
+        let mut body = Vec::new();
+        body.extend_from_slice(&(segments.len() as u32).to_le_bytes());
+        for segment in segments {
+            let utf16: Vec<u16> = segment.encode_utf16().collect();
+            body.extend_from_slice(&(utf16.len() as u32).to_le_bytes());
+            for unit in utf16 {
+                body.extend_from_slice(&unit.to_le_bytes());
             }
         }
+        body
+    }
+
+    #[test]
+    fn test_parse_batch_segments_round_trips() {
+        let body = encode_segments(&["こんにちは", "さようなら"]);
+        let segments = parse_batch_segments(&body);
+        assert_eq!(segments, vec!["こんにちは".to_string(), "さようなら".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_batch_segments_empty_body() {
+        assert!(parse_batch_segments(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_parse_batch_segments_truncated_length_stops_early() {
+        let mut body = encode_segments(&["a"]);
+        body.truncate(body.len() - 1); // 마지막 세그먼트의 바이트를 하나 자른다
+        assert!(parse_batch_segments(&body).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod chunking_tests {
+    use super::*;
+
+    #[test]
+    fn test_chunk_text_keeps_short_input_in_one_chunk() {
+        let chunks = chunk_text("こんにちは。", 1500);
+        assert_eq!(chunks, vec!["こんにちは。".to_string()]);
+    }
+
+    #[test]
+    fn test_chunk_text_splits_on_sentence_boundary_past_limit() {
+        let a = "あ".repeat(10);
+        let b = "い".repeat(10);
+        let text = format!("{}。{}。", a, b);
+        let chunks = chunk_text(&text, 12);
+        assert_eq!(chunks, vec![format!("{}。", a), format!("{}。", b)]);
+    }
+
+    #[test]
+    fn test_chunk_text_never_splits_mid_sentence() {
+        let long_sentence = "あ".repeat(50);
+        let chunks = chunk_text(&long_sentence, 10);
+        assert_eq!(chunks, vec![long_sentence]);
+    }
+
+    #[test]
+    fn test_translate_chunked_reassembles_in_order() {
+        let text = "a。b。";
+        let result = translate_chunked(text, |chunk| Ok(chunk.to_uppercase())).unwrap();
+        assert_eq!(result, "A。B。");
+    }
+}
+
+impl Drop for PipeInstance {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = DisconnectNamedPipe(self.handle);
+            let _ = CloseHandle(self.handle);
+            let _ = CloseHandle(self.event);
+        }
     }
 }
@@ -0,0 +1,394 @@
+// `tests/thread_safety_test.rs`의 여섯 개 `#[ignore]` 테스트를 재사용 가능한 API로
+// 승격한 것.
+//
+// 그 테스트들은 스레드 수/반복 횟수/지연 시간을 매번 손으로 고쳐 쓰고, 결과는
+// `println!`로만 남기며, 실행할 때마다 스케줄이 달라 "이번엔 안 터졌다"는 결론밖에
+// 내지 못했다(`test_rapid_fire`의 "? No failures in this run" 출력 참고). `StressConfig`의
+// `seed`로 매 호출의 스케줄(어느 텍스트를 고르는지, 호출 사이에 얼마나 지연을 두는지)을
+// 결정적으로 재현할 수 있게 하고, 결과를 `println!` 대신 집계된 `StressReport`로
+// 돌려주어 CI가 `failure_rate()`나 `classification()`을 바로 검사(gate)할 수 있게 한다.
+//
+// Miri의 확률적 결함 주입 플래그에서 착안해, `ContentionProfile::Jittered`로 스레드가
+// 엔진을 때리는 타이밍을 무작위로 흩어(jitter) 경합 창을 넓힐 수 있다 — 항상
+// 최대 속도로 두드리기만 하면(`BackToBack`) 드러나지 않는, 타이밍에 민감한 경합도
+// 잡아낼 수 있다.
+//
+// 실제 DLL에 의존하지 않도록 `StressHarness::run`은 번역 호출 자체를 클로저로
+// 받는다 — `ConfinedEngine`, `engine_pool::EzTransPool`, 혹은 기존 테스트들의
+// `UnsafeEngineWrapper` 무엇이든 감쌀 수 있고, 덕분에 하네스 로직 자체(스케줄링,
+// 집계, 분류)는 DLL 없이도 단위 테스트로 검증할 수 있다.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+
+/// `next_u64`를 호출할 때마다 상태가 바뀌는 결정적 의사난수 생성기(splitmix64).
+/// 같은 시드로 시작하면 항상 같은 수열을 내놓으므로, 스케줄을 그대로 재현할 수 있다.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// `0..upper` 범위의 인덱스를 뽑는다. `upper`가 0이면 항상 0을 돌려준다.
+    fn gen_index(&mut self, upper: usize) -> usize {
+        if upper == 0 {
+            0
+        } else {
+            (self.next_u64() % upper as u64) as usize
+        }
+    }
+
+    /// `0..=max_nanos` 범위의 지연 시간을 뽑는다.
+    fn gen_delay(&mut self, max_nanos: u64) -> Duration {
+        if max_nanos == 0 {
+            Duration::ZERO
+        } else {
+            Duration::from_nanos(self.next_u64() % (max_nanos + 1))
+        }
+    }
+}
+
+/// 스레드들이 엔진을 두드리는 타이밍 패턴. 둘을 다 돌려보면, 최대 속도 경합에서만
+/// 드러나는 버그와 타이밍이 흩어졌을 때만 드러나는 버그를 각각 잡아낼 수 있다.
+#[derive(Debug, Clone, Copy)]
+pub enum ContentionProfile {
+    /// 지연 없이 가능한 한 빨리 연속으로 호출한다(`test_rapid_fire`와 같은 패턴).
+    BackToBack,
+    /// 매 호출 사이에 `0..=max_nanos` 사이에서 시드로 뽑은 지연을 끼워 넣어, 스레드들이
+    /// 서로 다른 타이밍에 겹치게 만든다.
+    Jittered { max_nanos: u64 },
+}
+
+/// 하네스 실행 하나를 결정하는 설정.
+#[derive(Debug, Clone)]
+pub struct StressConfig {
+    pub thread_count: usize,
+    pub iterations_per_thread: usize,
+    /// 이 시드로부터 텍스트 선택과 지연 시간의 전체 스케줄이 결정적으로 유도된다.
+    /// 실패를 재현하려면 같은 시드로 다시 돌리면 된다.
+    pub seed: u64,
+    pub contention: ContentionProfile,
+}
+
+impl Default for StressConfig {
+    fn default() -> Self {
+        Self {
+            thread_count: 4,
+            iterations_per_thread: 25,
+            seed: 0,
+            contention: ContentionProfile::BackToBack,
+        }
+    }
+}
+
+/// 번역 결과 하나가 손상되었는지 판정하는 오라클. `tests/thread_safety_test.rs`의
+/// `is_corrupted` 같은 휴리스틱을 그대로 꽂아 넣을 수 있도록, `Fn(&str, &str) -> bool`에
+/// 대해 블랭킷 구현을 제공한다.
+pub trait CorruptionOracle: Send + Sync {
+    fn is_corrupted(&self, input: &str, output: &str) -> bool;
+}
+
+impl<F> CorruptionOracle for F
+where
+    F: Fn(&str, &str) -> bool + Send + Sync,
+{
+    fn is_corrupted(&self, input: &str, output: &str) -> bool {
+        self(input, output)
+    }
+}
+
+/// 번역 호출 한 건의 분류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallOutcome {
+    Success,
+    /// 번역 호출이 `Err`를 돌려줬다.
+    Error,
+    /// 번역 호출이 패닉을 일으켰다(`std::panic::catch_unwind`로 잡아냄).
+    Crash,
+    /// 호출은 성공했지만 `CorruptionOracle`이 출력이 손상되었다고 판정했다.
+    Corrupted,
+}
+
+/// 실행 전체를 한 줄로 요약하는 결론.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Classification {
+    /// 모든 호출이 성공하고 손상되지 않았다.
+    AllSuccess,
+    /// 모든 호출이 실패(에러/크래시/손상) 중 하나였다.
+    AllFailed,
+    /// 성공과 실패가 섞여 있다 — 경합 윈도우에 따라 때로는 통과하고 때로는 터지는,
+    /// 가장 다루기 까다로운 스레드 안전성 버그의 신호다.
+    Mixed,
+}
+
+/// `StressHarness::run`이 돌려주는 집계 결과.
+#[derive(Debug, Clone)]
+pub struct StressReport {
+    pub seed: u64,
+    pub total: usize,
+    pub success: usize,
+    pub error: usize,
+    pub crash: usize,
+    pub corrupted: usize,
+    /// 스케줄 순서상 맨 처음 실패한 호출의 (입력, 설명) — 재현/디버깅의 시작점.
+    pub first_failure: Option<(String, String)>,
+}
+
+impl StressReport {
+    pub fn failure_count(&self) -> usize {
+        self.error + self.crash + self.corrupted
+    }
+
+    pub fn failure_rate(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.failure_count() as f64 / self.total as f64
+        }
+    }
+
+    pub fn classification(&self) -> Classification {
+        match (self.success, self.failure_count()) {
+            (_, 0) => Classification::AllSuccess,
+            (0, _) => Classification::AllFailed,
+            _ => Classification::Mixed,
+        }
+    }
+}
+
+struct Tally {
+    success: usize,
+    error: usize,
+    crash: usize,
+    corrupted: usize,
+    first_failure: Option<(usize, String, String)>,
+}
+
+/// 시드 있는 스케줄로 동시성 경합을 재현 가능하게 몰아붙이는 하네스.
+pub struct StressHarness<O: CorruptionOracle> {
+    config: StressConfig,
+    oracle: O,
+}
+
+impl<O: CorruptionOracle> StressHarness<O> {
+    pub fn new(config: StressConfig, oracle: O) -> Self {
+        Self { config, oracle }
+    }
+
+    /// `texts`에서 골라 가며 `translate_mmntw`를 반복 호출하고, 결과를 분류해
+    /// [`StressReport`]로 모아 돌려준다. `translate_mmntw`는 어떤 엔진/풀이든 감쌀 수
+    /// 있도록 클로저로 받는다.
+    pub fn run(
+        &self,
+        texts: &[String],
+        translate_mmntw: impl Fn(&str) -> Result<String, String> + Sync,
+    ) -> StressReport {
+        let seed = self.config.seed;
+        let contention = self.config.contention;
+        let iterations = self.config.iterations_per_thread;
+
+        let thread_results: Vec<Vec<(usize, CallOutcome, String, String)>> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..self.config.thread_count)
+                .map(|thread_id| {
+                    let texts = texts;
+                    let translate_mmntw = &translate_mmntw;
+                    let oracle = &self.oracle;
+                    scope.spawn(move || {
+                        // 스레드마다 시드를 달리해, 같은 전체 시드에서도 스레드별로
+                        // 서로 다르지만 재현 가능한 부분 수열을 얻는다.
+                        let mut rng = Rng::new(seed ^ (thread_id as u64).wrapping_mul(0x9E3779B97F4A7C15));
+                        let mut records = Vec::with_capacity(iterations);
+
+                        for i in 0..iterations {
+                            let text = &texts[rng.gen_index(texts.len())];
+
+                            if let ContentionProfile::Jittered { max_nanos } = contention {
+                                std::thread::sleep(rng.gen_delay(max_nanos));
+                            }
+
+                            let result = panic::catch_unwind(AssertUnwindSafe(|| translate_mmntw(text)));
+
+                            let (outcome, detail) = match result {
+                                Ok(Ok(output)) => {
+                                    if oracle.is_corrupted(text, &output) {
+                                        (CallOutcome::Corrupted, output)
+                                    } else {
+                                        (CallOutcome::Success, output)
+                                    }
+                                }
+                                Ok(Err(e)) => (CallOutcome::Error, e),
+                                Err(_) => (CallOutcome::Crash, "panicked".to_string()),
+                            };
+
+                            records.push((i, outcome, text.clone(), detail));
+                        }
+
+                        records
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|h| h.join().expect("stress harness worker panicked"))
+                .collect()
+        });
+
+        let mut tally = Tally {
+            success: 0,
+            error: 0,
+            crash: 0,
+            corrupted: 0,
+            first_failure: None,
+        };
+
+        // 재현성을 위해, 실패 후보들 중 맨 처음 실패는 스케줄 순서(스레드 내 반복 순서,
+        // 그다음 스레드 번호)로 비교해 고른다.
+        for (thread_id, records) in thread_results.into_iter().enumerate() {
+            for (iteration, outcome, input, detail) in records {
+                match outcome {
+                    CallOutcome::Success => tally.success += 1,
+                    CallOutcome::Error => tally.error += 1,
+                    CallOutcome::Crash => tally.crash += 1,
+                    CallOutcome::Corrupted => tally.corrupted += 1,
+                }
+
+                if outcome != CallOutcome::Success {
+                    let candidate = (iteration * self.config.thread_count + thread_id, input, detail);
+                    match &tally.first_failure {
+                        Some((existing_order, _, _)) if *existing_order <= candidate.0 => {}
+                        _ => tally.first_failure = Some(candidate),
+                    }
+                }
+            }
+        }
+
+        StressReport {
+            seed,
+            total: tally.success + tally.error + tally.crash + tally.corrupted,
+            success: tally.success,
+            error: tally.error,
+            crash: tally.crash,
+            corrupted: tally.corrupted,
+            first_failure: tally
+                .first_failure
+                .take()
+                .map(|(_, input, detail)| (input, detail)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_produces_same_schedule() {
+        let config = StressConfig {
+            thread_count: 1,
+            iterations_per_thread: 20,
+            seed: 42,
+            contention: ContentionProfile::BackToBack,
+        };
+        let texts = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+
+        let run = |seed: u64| {
+            let mut cfg = config.clone();
+            cfg.seed = seed;
+            let harness = StressHarness::new(cfg, |_input: &str, _output: &str| false);
+            harness.run(&texts, |input| Ok(input.to_string()))
+        };
+
+        let first = run(42);
+        let second = run(42);
+        assert_eq!(first.total, second.total);
+        assert_eq!(first.first_failure, second.first_failure);
+    }
+
+    #[test]
+    fn test_all_success_classification() {
+        let config = StressConfig {
+            thread_count: 2,
+            iterations_per_thread: 10,
+            seed: 1,
+            contention: ContentionProfile::BackToBack,
+        };
+        let texts = vec!["hello".to_string()];
+        let harness = StressHarness::new(config, |_input: &str, _output: &str| false);
+
+        let report = harness.run(&texts, |input| Ok(input.to_string()));
+
+        assert_eq!(report.total, 20);
+        assert_eq!(report.failure_count(), 0);
+        assert_eq!(report.classification(), Classification::AllSuccess);
+        assert!(report.first_failure.is_none());
+    }
+
+    #[test]
+    fn test_corruption_oracle_flags_every_call_and_records_first_failure() {
+        let config = StressConfig {
+            thread_count: 1,
+            iterations_per_thread: 5,
+            seed: 7,
+            contention: ContentionProfile::BackToBack,
+        };
+        let texts = vec!["garbled".to_string()];
+        let harness = StressHarness::new(config, |_input: &str, _output: &str| true);
+
+        let report = harness.run(&texts, |input| Ok(input.to_string()));
+
+        assert_eq!(report.corrupted, 5);
+        assert_eq!(report.classification(), Classification::AllFailed);
+        assert_eq!(report.failure_rate(), 1.0);
+        assert!(report.first_failure.is_some());
+    }
+
+    #[test]
+    fn test_all_errors_yield_all_failed_classification() {
+        let config = StressConfig {
+            thread_count: 1,
+            iterations_per_thread: 4,
+            seed: 3,
+            contention: ContentionProfile::Jittered { max_nanos: 1_000 },
+        };
+        let texts = vec!["x".to_string()];
+        let harness = StressHarness::new(config, |_input: &str, _output: &str| false);
+
+        let report = harness.run(&texts, |input| {
+            if input == "x" {
+                Err("boom".to_string())
+            } else {
+                Ok(input.to_string())
+            }
+        });
+
+        assert_eq!(report.error, 4);
+        assert_eq!(report.classification(), Classification::AllFailed);
+    }
+
+    #[test]
+    fn test_panicking_translate_is_classified_as_crash() {
+        let config = StressConfig {
+            thread_count: 1,
+            iterations_per_thread: 3,
+            seed: 9,
+            contention: ContentionProfile::BackToBack,
+        };
+        let texts = vec!["y".to_string()];
+        let harness = StressHarness::new(config, |_input: &str, _output: &str| false);
+
+        let report = harness.run(&texts, |_input| panic!("simulated crash"));
+
+        assert_eq!(report.crash, 3);
+        assert_eq!(report.classification(), Classification::AllFailed);
+    }
+}
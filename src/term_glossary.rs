@@ -0,0 +1,84 @@
+// 호출 하나에만 적용되는 일회성 용어집 번역, `translate_with_glossary`.
+//
+// `set_glossary`(lib.rs)는 용어집을 엔진에 영구적으로 등록해 이후의 모든
+// `default_translate` 호출이 재사용하지만, 문서/배치마다 고유명사 목록이 달라지는
+// 워크로드에서는 그때그때 넘기는 용어집이 더 맞는다. 여기서는 매 호출마다 Aho-Corasick
+// 자동자를 새로 지어 원문 용어들을 한 번에(leftmost-longest) 찾고, 번역 전 사설 영역
+// 자리표시자로 바꿔 뒀다가 번역 후 원하는 치환어로 복원한다.
+
+use std::fmt::Write as _;
+
+use aho_corasick::{AhoCorasickBuilder, MatchKind};
+
+use crate::{EzTransError, EzTransInner};
+
+/// 한 번의 `translate_with_glossary` 호출에서만 쓰는 사설 영역 치환 기준점.
+/// `set_glossary`가 쓰는 `GLOSSARY_SENTINEL_BASE`(U+E000)와 겹치지 않도록 U+F000부터
+/// 시작한다.
+const CALL_GLOSSARY_SENTINEL_BASE: u32 = 0xF000;
+
+enum TermEntry {
+    /// 번역을 거치지 않고 바로 이 텍스트로 치환한다.
+    Translated(String),
+    /// 원문을 그대로 보존한다.
+    Protected(String),
+}
+
+impl EzTransInner {
+    /// `terms`로 즉석에서 지은 용어집을 적용해 `input`을 번역한다.
+    ///
+    /// `terms`의 각 항목은 `(원문, Some(치환어))`면 번역 없이 치환어로 바뀌고,
+    /// `(원문, None)`이면 원문이 그대로 보존된다. 겹치는 용어는 `leftmost-longest`
+    /// 규칙에 따라 가장 긴 쪽이 greedy하게 소비된다.
+    pub fn translate_with_glossary(
+        &self,
+        input: &str,
+        terms: &[(String, Option<String>)],
+    ) -> Result<String, EzTransError> {
+        if terms.is_empty() {
+            return self.default_translate(input);
+        }
+
+        let keys: Vec<&str> = terms.iter().map(|(source, _)| source.as_str()).collect();
+        let automaton = AhoCorasickBuilder::new()
+            .match_kind(MatchKind::LeftmostLongest)
+            .build(&keys)
+            .map_err(|e| {
+                EzTransError::FunctionLoadError(format!("용어집 자동자 생성 실패: {e}"))
+            })?;
+
+        let entries: Vec<TermEntry> = terms
+            .iter()
+            .map(|(source, target)| match target {
+                Some(target) => TermEntry::Translated(target.clone()),
+                None => TermEntry::Protected(source.clone()),
+            })
+            .collect();
+
+        let mut protected = String::with_capacity(input.len());
+        let mut last_end = 0;
+        for mat in automaton.find_iter(input) {
+            protected.push_str(&input[last_end..mat.start()]);
+            let sentinel = CALL_GLOSSARY_SENTINEL_BASE + mat.pattern().as_u32();
+            write!(&mut protected, "+x{sentinel:04X}").unwrap();
+            last_end = mat.end();
+        }
+        protected.push_str(&input[last_end..]);
+
+        // 입력에 한글/특수 문자가 없으면 `default_translate`는 디코딩을 건너뛰므로,
+        // 남아 있는 `+x` 자리표시자를 여기서 사설 영역 문자로 되돌린다.
+        let translated = self.hangul_decode(&self.default_translate(&protected)?);
+
+        let mut output = String::with_capacity(translated.len());
+        for c in translated.chars() {
+            let index = (c as u32).wrapping_sub(CALL_GLOSSARY_SENTINEL_BASE) as usize;
+            match entries.get(index) {
+                Some(TermEntry::Translated(replacement)) => output.push_str(replacement),
+                Some(TermEntry::Protected(original)) => output.push_str(original),
+                None => output.push(c),
+            }
+        }
+
+        Ok(output)
+    }
+}
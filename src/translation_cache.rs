@@ -0,0 +1,393 @@
+// `translate_mmntw`/`translate_mmnt` 호출 결과를 메모이즈하는 캐시.
+//
+// 엔진 호출(DLL 왕복)이 스캐너와 IPC 서버 양쪽에서 가장 비싼 연산이다. 자막/게임
+// 텍스트 덤프나 Extension-A 한자 스캔처럼 같은 입력이 반복되는 워크로드에서는, 같은
+// 텍스트를 다시 번역하지 않고 이전 결과를 그대로 돌려주는 것만으로 비용이 크게
+// 줄어든다. `TranslationCache`는 기본적으로 꺼져 있으며
+// [`EzTransInner::enable_translation_cache`]로 켠다. 키는 입력 텍스트뿐 아니라 와이드
+// (`translate_mmntw`)/좁은(`translate_mmnt`) 진입점과, 캐싱 시점에 `set_property`로
+// 활성화돼 있던 속성값 스냅샷까지 포함해, 서로 다른 경로·설정에서 나온 번역이 섞이지
+// 않게 한다.
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::ffi::c_int;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{EzTransError, EzTransInner};
+
+/// 기본 캐시 용량. `EzTransInner::new`가 항상 캐시를 만들어 두긴 하지만, 켜기 전까지는
+/// 아무 것도 저장하지 않으므로 메모리 비용은 없다.
+const DEFAULT_CAPACITY: usize = 10_000;
+
+/// 어느 진입점으로 들어온 번역인지. 와이드 문자열 경로와 Shift-JIS 경로는 서로 다른
+/// 인코딩 변환을 거치므로 같은 텍스트라도 결과가 달라질 수 있어 캐시 키에서 구분한다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CacheEntryPoint {
+    /// `translate_mmntw` (EHND, 와이드 문자열).
+    Mmntw,
+    /// `translate_mmnt` (No Thread, Shift-JIS).
+    Mmnt,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+struct CacheKey {
+    text: String,
+    entry_point: CacheEntryPoint,
+    /// 캐싱 시점에 활성화돼 있던 `SetProperty` 값들의 스냅샷(속성 ID 순 정렬).
+    property_fingerprint: Vec<(c_int, c_int)>,
+}
+
+/// 용량이 고정된 LRU 캐시. `cached_translator.rs`와 같은 HashMap+VecDeque 관용구를
+/// 그대로 따른다.
+struct Lru {
+    capacity: usize,
+    map: HashMap<CacheKey, String>,
+    order: VecDeque<CacheKey>,
+}
+
+impl Lru {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            map: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    fn get(&mut self, key: &CacheKey) -> Option<String> {
+        let value = self.map.get(key)?.clone();
+        self.touch(key);
+        Some(value)
+    }
+
+    fn touch(&mut self, key: &CacheKey) {
+        if let Some(pos) = self.order.iter().position(|k| k == key) {
+            let key = self.order.remove(pos).unwrap();
+            self.order.push_back(key);
+        }
+    }
+
+    fn insert(&mut self, key: CacheKey, value: String) {
+        if self.map.contains_key(&key) {
+            self.map.insert(key.clone(), value);
+            self.touch(&key);
+            return;
+        }
+
+        if self.map.len() >= self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.map.remove(&oldest);
+            }
+        }
+
+        self.order.push_back(key.clone());
+        self.map.insert(key, value);
+    }
+
+    fn clear(&mut self) {
+        self.map.clear();
+        self.order.clear();
+    }
+
+    fn entries(&self) -> impl Iterator<Item = (&CacheKey, &String)> {
+        self.map.iter()
+    }
+}
+
+/// `translate_mmntw`/`translate_mmnt`를 감싸, 동일한 (텍스트, 진입점, 속성 상태)
+/// 조합을 다시 DLL에 보내지 않고 이전 결과를 돌려주는 캐시.
+pub struct TranslationCache {
+    enabled: AtomicBool,
+    lru: Mutex<Lru>,
+    active_properties: Mutex<BTreeMap<c_int, c_int>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl TranslationCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            enabled: AtomicBool::new(false),
+            lru: Mutex::new(Lru::new(capacity)),
+            active_properties: Mutex::new(BTreeMap::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    fn fingerprint(&self) -> Vec<(c_int, c_int)> {
+        self.active_properties
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(&id, &value)| (id, value))
+            .collect()
+    }
+
+    /// `set_property`가 성공했을 때 호출되어, 이후 캐시 키에 이 속성값이 반영되게 한다.
+    pub(crate) fn record_property(&self, property_id: c_int, value: c_int) {
+        self.active_properties
+            .lock()
+            .unwrap()
+            .insert(property_id, value);
+    }
+
+    /// 캐시가 켜져 있으면 조회/저장을 수행하고, 꺼져 있으면 매번 `translate`를 그대로
+    /// 호출한다.
+    fn get_or_translate(
+        &self,
+        text: &str,
+        entry_point: CacheEntryPoint,
+        translate: impl FnOnce() -> Result<String, EzTransError>,
+    ) -> Result<String, EzTransError> {
+        if !self.enabled.load(Ordering::Relaxed) {
+            return translate();
+        }
+
+        let key = CacheKey {
+            text: text.to_string(),
+            entry_point,
+            property_fingerprint: self.fingerprint(),
+        };
+
+        if let Some(cached) = self.lru.lock().unwrap().get(&key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(cached);
+        }
+
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let translated = translate()?;
+        self.lru.lock().unwrap().insert(key, translated.clone());
+        Ok(translated)
+    }
+
+    fn hit_count(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    fn miss_count(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    fn clear(&self) {
+        self.lru.lock().unwrap().clear();
+    }
+
+    /// 캐시 내용을 JSON 파일로 저장한다. LRU 순서(최신성)는 보존하지 않는다 — 다시
+    /// 불러올 때는 모두 동일하게 "방금 채워진" 항목으로 취급된다.
+    fn save_to_file(&self, path: &Path) -> Result<(), EzTransError> {
+        let lru = self.lru.lock().unwrap();
+        let entries: Vec<(&CacheKey, &String)> = lru.entries().collect();
+        let json = serde_json::to_string_pretty(&entries).map_err(|e| {
+            EzTransError::FunctionCallFailed(format!("캐시 직렬화에 실패했습니다: {}", e))
+        })?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// `save_to_file`이 쓴 JSON 파일을 읽어 현재 캐시에 덧붙인다. 기존 항목은 지우지
+    /// 않으므로, 웜 스타트 때 미리 채워 두는 용도로 쓴다.
+    fn load_from_file(&self, path: &Path) -> Result<(), EzTransError> {
+        let contents = std::fs::read_to_string(path)?;
+        let entries: Vec<(CacheKey, String)> = serde_json::from_str(&contents).map_err(|e| {
+            EzTransError::FunctionCallFailed(format!("캐시 역직렬화에 실패했습니다: {}", e))
+        })?;
+
+        let mut lru = self.lru.lock().unwrap();
+        for (key, value) in entries {
+            lru.insert(key, value);
+        }
+        Ok(())
+    }
+}
+
+impl EzTransInner {
+    pub(crate) fn new_translation_cache() -> TranslationCache {
+        TranslationCache::new(DEFAULT_CAPACITY)
+    }
+
+    /// `translate_mmntw`/`translate_mmnt` 결과 캐싱을 켠다. 이미 켜져 있던 내용은 그대로
+    /// 유지된다.
+    pub fn enable_translation_cache(&self) {
+        self.translation_cache.enabled.store(true, Ordering::Relaxed);
+    }
+
+    /// 캐싱을 끈다. 켜져 있는 동안 쌓인 항목은 지우지 않으므로, 나중에 다시 켜면 그대로
+    /// 재사용된다.
+    pub fn disable_translation_cache(&self) {
+        self.translation_cache
+            .enabled
+            .store(false, Ordering::Relaxed);
+    }
+
+    /// 지금까지의 (히트, 미스) 횟수.
+    pub fn translation_cache_stats(&self) -> (u64, u64) {
+        (
+            self.translation_cache.hit_count(),
+            self.translation_cache.miss_count(),
+        )
+    }
+
+    /// 캐시에 쌓인 모든 항목을 지운다. 히트/미스 카운터는 그대로 둔다.
+    pub fn clear_translation_cache(&self) {
+        self.translation_cache.clear();
+    }
+
+    /// 캐시 내용을 JSON 파일로 저장한다.
+    pub fn save_translation_cache<P: AsRef<Path>>(&self, path: P) -> Result<(), EzTransError> {
+        self.translation_cache.save_to_file(path.as_ref())
+    }
+
+    /// JSON 파일에서 캐시 내용을 불러와 덧붙인다(웜 스타트).
+    pub fn load_translation_cache<P: AsRef<Path>>(&self, path: P) -> Result<(), EzTransError> {
+        self.translation_cache.load_from_file(path.as_ref())
+    }
+
+    /// [`translate_mmntw`](Self::translate_mmntw)의 실제 DLL 호출부를 캐시로 감싼다.
+    pub(crate) fn translate_mmntw_cached(
+        &self,
+        input: &str,
+        translate: impl FnOnce() -> Result<String, EzTransError>,
+    ) -> Result<String, EzTransError> {
+        self.translation_cache
+            .get_or_translate(input, CacheEntryPoint::Mmntw, translate)
+    }
+
+    /// [`translate_mmnt`](Self::translate_mmnt)의 실제 DLL 호출부를 캐시로 감싼다.
+    pub(crate) fn translate_mmnt_cached(
+        &self,
+        input: &str,
+        translate: impl FnOnce() -> Result<String, EzTransError>,
+    ) -> Result<String, EzTransError> {
+        self.translation_cache
+            .get_or_translate(input, CacheEntryPoint::Mmnt, translate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(text: &str) -> CacheKey {
+        CacheKey {
+            text: text.to_string(),
+            entry_point: CacheEntryPoint::Mmntw,
+            property_fingerprint: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_lru_evicts_oldest_entry_past_capacity() {
+        let mut lru = Lru::new(2);
+        lru.insert(key("a"), "A".to_string());
+        lru.insert(key("b"), "B".to_string());
+        lru.insert(key("c"), "C".to_string());
+
+        assert_eq!(lru.get(&key("a")), None);
+        assert_eq!(lru.get(&key("b")), Some("B".to_string()));
+        assert_eq!(lru.get(&key("c")), Some("C".to_string()));
+    }
+
+    #[test]
+    fn test_cache_disabled_by_default_always_calls_translate() {
+        let cache = TranslationCache::new(4);
+        let mut calls = 0;
+        for _ in 0..3 {
+            let result = cache.get_or_translate("hello", CacheEntryPoint::Mmntw, || {
+                calls += 1;
+                Ok("hi".to_string())
+            });
+            assert_eq!(result.unwrap(), "hi");
+        }
+        assert_eq!(calls, 3);
+        assert_eq!(cache.hit_count(), 0);
+        assert_eq!(cache.miss_count(), 0);
+    }
+
+    #[test]
+    fn test_cache_hit_skips_translate_closure() {
+        let cache = TranslationCache::new(4);
+        cache.enabled.store(true, Ordering::Relaxed);
+
+        let mut calls = 0;
+        for _ in 0..3 {
+            let result = cache.get_or_translate("hello", CacheEntryPoint::Mmntw, || {
+                calls += 1;
+                Ok("hi".to_string())
+            });
+            assert_eq!(result.unwrap(), "hi");
+        }
+
+        assert_eq!(calls, 1);
+        assert_eq!(cache.hit_count(), 2);
+        assert_eq!(cache.miss_count(), 1);
+    }
+
+    #[test]
+    fn test_different_entry_points_do_not_share_cache_slot() {
+        let cache = TranslationCache::new(4);
+        cache.enabled.store(true, Ordering::Relaxed);
+
+        cache
+            .get_or_translate("hello", CacheEntryPoint::Mmntw, || Ok("wide".to_string()))
+            .unwrap();
+        let narrow = cache
+            .get_or_translate("hello", CacheEntryPoint::Mmnt, || Ok("narrow".to_string()))
+            .unwrap();
+
+        assert_eq!(narrow, "narrow");
+        assert_eq!(cache.miss_count(), 2);
+    }
+
+    #[test]
+    fn test_property_change_invalidates_cache_slot_via_fingerprint() {
+        let cache = TranslationCache::new(4);
+        cache.enabled.store(true, Ordering::Relaxed);
+
+        cache
+            .get_or_translate("hello", CacheEntryPoint::Mmntw, || Ok("a".to_string()))
+            .unwrap();
+        cache.record_property(1, 100);
+        let after_property_change = cache
+            .get_or_translate("hello", CacheEntryPoint::Mmntw, || Ok("b".to_string()))
+            .unwrap();
+
+        assert_eq!(after_property_change, "b");
+        assert_eq!(cache.miss_count(), 2);
+    }
+
+    #[test]
+    fn test_save_and_load_round_trips_entries() {
+        let dir = std::env::temp_dir().join(format!(
+            "eztrans_translation_cache_test_{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("cache.json");
+
+        let cache = TranslationCache::new(4);
+        cache.enabled.store(true, Ordering::Relaxed);
+        cache
+            .get_or_translate("hello", CacheEntryPoint::Mmntw, || Ok("hi".to_string()))
+            .unwrap();
+        cache.save_to_file(&path).unwrap();
+
+        let reloaded = TranslationCache::new(4);
+        reloaded.enabled.store(true, Ordering::Relaxed);
+        reloaded.load_from_file(&path).unwrap();
+
+        let mut calls = 0;
+        let result = reloaded.get_or_translate("hello", CacheEntryPoint::Mmntw, || {
+            calls += 1;
+            Ok("should not be called".to_string())
+        });
+        assert_eq!(result.unwrap(), "hi");
+        assert_eq!(calls, 0);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
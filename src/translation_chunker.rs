@@ -0,0 +1,193 @@
+// `EzTransEngine::translate_stream`이 쓰는 경계 안전 청크 분할기.
+//
+// `chunk_iterator::ChunkIterator`는 원문(인코딩 전) 텍스트를 문장 경계/자소 클러스터
+// 단위로 나누지만, `default_translate`는 `hangul_encode`로 한글/특수문자/용어집
+// 자리표시자를 전부 `+x1234`/`+X1234` 형태의 ASCII 이스케이프로 바꾼 뒤에야 DLL을
+// 부른다. 그 인코딩된 문자열을 그대로 이 분할기로 나누면, 이스케이프 한가운데서
+// 잘릴 경우 그 조각만 따로 디코딩할 때(`hangul_decode`가 16진수 4자리를 못 채워)
+// 이스케이프가 깨진 채 복원된다. `TranslationChunker`는 그래서 이스케이프 시퀀스를
+// 쪼갤 수 없는 한 토큰으로 취급하고, 그 토큰 경계에서만 끊는다.
+
+/// 문장 종결로 취급해 우선적으로 끊어 주는 일본어 구두점. 개행은 `find_split_point`가
+/// 따로 경계로 본다.
+const JAPANESE_BOUNDARY_MARKERS: [char; 3] = ['。', '！', '？'];
+
+/// `input`을 `max_len` 바이트 이하의 조각으로 나눠 주는 반복자. 단, 맨 앞 토큰(이스케이프
+/// 하나 또는 문자 하나)이 그 자체로 `max_len`보다 길면 그 토큰만 담은 조각은 `max_len`을
+/// 넘는다. 이스케이프 시퀀스
+/// (`+x1234`/`+X1234`)는 항상 통째로 한쪽 조각에 남고, 조각을 이어 붙이면(`concat`)
+/// 원본 인코딩 문자열이 그대로 복원된다.
+pub struct TranslationChunker<'a> {
+    remaining: &'a str,
+    max_len: usize,
+}
+
+impl<'a> TranslationChunker<'a> {
+    /// `max_len`은 0보다 커야 한다.
+    pub fn new(input: &'a str, max_len: usize) -> Self {
+        assert!(max_len > 0, "max_len은 0보다 커야 합니다");
+        Self {
+            remaining: input,
+            max_len,
+        }
+    }
+}
+
+impl<'a> Iterator for TranslationChunker<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        if self.remaining.len() <= self.max_len {
+            let chunk = self.remaining;
+            self.remaining = "";
+            return Some(chunk);
+        }
+
+        let split_at = find_split_point(self.remaining, self.max_len);
+        let (chunk, rest) = self.remaining.split_at(split_at);
+        self.remaining = rest;
+        Some(chunk)
+    }
+}
+
+/// `s`가 `+`로 시작할 때, 뒤따르는 `x`/`X`와 4자리 16진수로 이어지는 `hangul_encode`
+/// 이스케이프 시퀀스인지 확인한다. 맞다면 바이트 길이(항상 6 — 전부 ASCII)를 돌려준다.
+fn escape_len_at(s: &str) -> Option<usize> {
+    let mut chars = s.chars();
+    if chars.next()? != '+' {
+        return None;
+    }
+    if !matches!(chars.next()?, 'x' | 'X') {
+        return None;
+    }
+
+    let hex: String = chars.by_ref().take(4).collect();
+    (hex.len() == 4 && hex.chars().all(|c| c.is_ascii_hexdigit())).then_some(6)
+}
+
+/// `s`에서 `max_len` 바이트 이내로 끊을 지점을 찾는다. 앞에서부터 훑으며 이스케이프
+/// 시퀀스는 통째로 하나의 토큰으로 건너뛰고, `max_len`을 넘기 직전까지 본 마지막
+/// 문장 경계(일본어 구두점/개행/공백이 뒤따르는 ASCII `.!?`) 뒤를 기억해 둔다 —
+/// 그런 경계가 window 안에 없으면 마지막 토큰 경계에서 끊는다. 이 함수는 호출자가
+/// 자신의 DLL 버전에 맞게 경계 판정을 확인하거나 재사용할 수 있도록 공개되어 있다.
+pub fn find_split_point(s: &str, max_len: usize) -> usize {
+    let mut last_boundary_end = None;
+    let mut last_token_end = None;
+    let mut idx = 0;
+
+    while idx < s.len() {
+        let rest = &s[idx..];
+        let c = rest.chars().next().expect("idx는 항상 문자 경계를 가리킵니다");
+
+        let token_len = if c == '+' {
+            escape_len_at(rest).unwrap_or_else(|| c.len_utf8())
+        } else {
+            c.len_utf8()
+        };
+        let token_end = idx + token_len;
+
+        if token_end > max_len {
+            break;
+        }
+
+        last_token_end = Some(token_end);
+
+        if JAPANESE_BOUNDARY_MARKERS.contains(&c) || c == '\n' {
+            last_boundary_end = Some(token_end);
+        } else if matches!(c, '.' | '!' | '?') {
+            // 뒤따르는 공백까지 같은 조각에 남겨, 다음 조각이 공백으로 시작하지 않게 한다.
+            match s[token_end..].chars().next() {
+                Some(ws) if ws.is_whitespace() => {
+                    let end_with_ws = token_end + ws.len_utf8();
+                    if end_with_ws <= max_len {
+                        last_boundary_end = Some(end_with_ws);
+                    } else {
+                        last_boundary_end = Some(token_end);
+                    }
+                }
+                None => last_boundary_end = Some(token_end),
+                Some(_) => {}
+            }
+        }
+
+        idx = token_end;
+    }
+
+    if let Some(end) = last_boundary_end {
+        return end;
+    }
+    if let Some(end) = last_token_end {
+        return end;
+    }
+
+    // 첫 토큰(이스케이프 포함) 하나만으로도 max_len을 넘는 극단적인 경우에도, 앞으로
+    // 나아갈 수 있도록 그 토큰 전체는 포함시킨다.
+    let c = s.chars().next().expect("빈 문자열은 여기까지 오지 않습니다");
+    if c == '+' {
+        escape_len_at(s).unwrap_or_else(|| c.len_utf8())
+    } else {
+        c.len_utf8()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_splits_at_last_sentence_boundary_within_window() {
+        let input = "最初の文。次の文です。まだ続く";
+        let chunks: Vec<&str> = TranslationChunker::new(input, 33).collect();
+        assert_eq!(chunks.concat(), input);
+        assert!(chunks.iter().all(|c| c.len() <= 33));
+        assert_eq!(chunks[0], "最初の文。次の文です。");
+    }
+
+    #[test]
+    fn test_splits_at_ascii_terminator_followed_by_whitespace() {
+        let input = "First sentence. Second sentence. Third";
+        let chunks: Vec<&str> = TranslationChunker::new(input, 20).collect();
+        assert_eq!(chunks.concat(), input);
+        assert_eq!(chunks[0], "First sentence. ");
+    }
+
+    #[test]
+    fn test_never_splits_inside_a_hangul_encode_escape_sequence() {
+        // "+xAC00"(6바이트)가 정확히 window 한가운데를 가리키게 만든다.
+        let input = "ab+xAC00cd";
+        let max_len = "ab".len() + 3; // 이스케이프 한가운데
+        let chunks: Vec<&str> = TranslationChunker::new(input, max_len).collect();
+        assert_eq!(chunks.concat(), input);
+        assert_eq!(chunks[0], "ab");
+    }
+
+    #[test]
+    fn test_falls_back_to_hard_split_when_single_token_exceeds_budget() {
+        let input = "abcdefgh";
+        let chunks: Vec<&str> = TranslationChunker::new(input, 3).collect();
+        assert_eq!(chunks.concat(), input);
+        assert!(chunks.iter().all(|c| c.len() <= 3));
+    }
+
+    #[test]
+    fn test_whole_input_returned_as_one_chunk_when_under_max_len() {
+        let input = "짧은 문장.";
+        let chunks: Vec<&str> = TranslationChunker::new(input, 100).collect();
+        assert_eq!(chunks, vec![input]);
+    }
+
+    #[test]
+    fn test_empty_input_yields_no_chunks() {
+        assert_eq!(TranslationChunker::new("", 10).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "max_len은 0보다 커야 합니다")]
+    fn test_zero_max_len_panics() {
+        TranslationChunker::new("x", 0);
+    }
+}
@@ -0,0 +1,47 @@
+// `std::thread`의 "기본적으로 join" `JoinGuard` 구상을 번역 호출에 적용한 가드.
+//
+// `EzTransEngine::translate_guarded`가 돌려주는 이 가드는 워커 스레드에서 진행 중인
+// 번역 하나를 대표한다. 그냥 드롭하면 번역이 끝날 때까지 블록해서 기다린 뒤(`join`과
+// 동일하게) 결과를 버린다 — 그래서 DLL 호출이 끝나기도 전에 핸들을 놓쳐버리는 흔한
+// 실수를 막는다. 결과가 필요하면 `join()`으로, 결과가 필요 없고 기다리고 싶지 않으면
+// `detach()`로 명시적으로 선택해야 한다.
+
+use std::thread::JoinHandle;
+
+use crate::EzTransError;
+
+pub struct TranslationGuard {
+    handle: Option<JoinHandle<Result<String, EzTransError>>>,
+}
+
+impl TranslationGuard {
+    pub(crate) fn new(handle: JoinHandle<Result<String, EzTransError>>) -> Self {
+        Self {
+            handle: Some(handle),
+        }
+    }
+
+    /// 번역이 끝날 때까지 기다려 결과를 돌려받는다.
+    pub fn join(mut self) -> Result<String, EzTransError> {
+        let handle = self.handle.take().expect("handle은 drop 전까지 항상 Some이다");
+        handle.join().unwrap_or_else(|_| {
+            Err(EzTransError::FunctionCallFailed(
+                "번역 스레드가 panic했습니다".to_string(),
+            ))
+        })
+    }
+
+    /// 결과를 기다리지 않고 손을 뗀다 — 번역은 백그라운드에서 계속 진행되지만,
+    /// 결과는 버려진다.
+    pub fn detach(mut self) {
+        self.handle.take();
+    }
+}
+
+impl Drop for TranslationGuard {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
@@ -0,0 +1,366 @@
+// 워크 스틸링 기반 번역 풀.
+//
+// `engine_pool::EzTransPool`은 모든 워커가 하나의 공유 큐를 바라보는 단순한 모델이라
+// 워커 수가 늘어날수록 그 한 큐에서 경합이 커진다. 이 모듈은 rayon-core 레지스트리를
+// 본떠, 새 작업이 들어오는 전역 인젝터 큐와 워커별 로컬 큐를 두고, 워커가 자기 큐가
+// 비면 인젝터에서, 그것도 비면 다른 워커의 큐에서 훔쳐오게 한다. `crossbeam-deque`의
+// 진짜 Chase–Lev 데크 대신 `std::sync::Mutex<VecDeque<_>>`로 단순화해 구현했다 — 이
+// 크레이트는 `rayon`/`crossbeam`을 새로 끌어오지 않는 관례를 따른다
+// (`engine_pool`/`tests/stress_test.rs`가 이미 `std::sync`만으로 동등한 동시성 구조를
+// 구현해 둔 전례가 있다).
+//
+// `engine_pool::EzTransPool`과 달리 엔진은 `new()` 시점이 아니라 각 워커가 자신의 첫
+// 작업을 받을 때 지연 생성된다(DLL이 스레드별 초기화를 요구하므로, 이 지연 생성도
+// 반드시 해당 워커 스레드 위에서 일어나야 한다).
+
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Barrier, Condvar, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use crate::{EzTransEngine, EzTransError};
+
+struct Job {
+    text: String,
+    reply: mpsc::Sender<Result<String, EzTransError>>,
+}
+
+/// `broadcast_init`이 특정 워커에게 "지금 엔진을 만들어라"라고 지시할 때 쓰는, 그 워커
+/// 전용 슬롯에 담기는 콜백. 결과가 나오면 콜백 하나가 정확히 한 번 불린다.
+type InitJob = Box<dyn FnOnce(Result<(), EzTransError>) + Send>;
+
+/// `Scope::spawn`이 큐에 넣는 작업. 엔진 초기화가 실패할 수도 있으므로, 실제 엔진이
+/// 아니라 그 결과를 받는다.
+type ScopedJob = Box<dyn FnOnce(Result<&EzTransEngine, EzTransError>) + Send>;
+
+/// 훔쳐갈 수 있는 큐(인젝터/로컬 데크)에 들어가는 항목. 초기화 작업은 워커별로 주소가
+/// 매겨진 별도 슬롯에 들어가므로 여기 포함되지 않는다.
+enum QueueEntry {
+    Translate(Job),
+    Scoped(ScopedJob),
+}
+
+enum WorkItem {
+    Translate(Job),
+    Scoped(ScopedJob),
+    Init(InitJob),
+}
+
+/// 전역 인젝터 큐와 워커별 로컬 큐, 그리고 워커별로 주소가 매겨진 초기화 슬롯. 하나의
+/// condvar를 공유해 유휴 워커를 깨운다.
+struct Shared {
+    injector: Mutex<VecDeque<QueueEntry>>,
+    locals: Vec<Mutex<VecDeque<QueueEntry>>>,
+    init_slots: Vec<Mutex<Option<InitJob>>>,
+    park_lock: Mutex<()>,
+    condvar: Condvar,
+    shutting_down: AtomicBool,
+}
+
+impl Shared {
+    fn push_injector(&self, entry: QueueEntry) {
+        self.injector.lock().unwrap().push_back(entry);
+        self.condvar.notify_one();
+    }
+
+    /// `worker_id`에게만 배달되는 초기화 작업을 꽂는다. 일반 큐와 달리 다른 워커가
+    /// 훔쳐갈 수 없다 — `broadcast_init`은 워커마다 정확히 한 번 실행되어야 한다.
+    fn push_init(&self, worker_id: usize, job: InitJob) {
+        *self.init_slots[worker_id].lock().unwrap() = Some(job);
+        self.condvar.notify_all();
+    }
+
+    /// 이 워커 앞으로 온 초기화 작업 → 로컬 큐 → 인젝터 → 다른 워커의 로컬 큐 순서로
+    /// 하나를 훔쳐온다. 어디에도 없으면 종료 신호가 뜨거나 새 작업이 들어올 때까지
+    /// 짧게 잠든다.
+    fn pop(&self, worker_id: usize) -> Option<WorkItem> {
+        loop {
+            if let Some(job) = self.init_slots[worker_id].lock().unwrap().take() {
+                return Some(WorkItem::Init(job));
+            }
+            if let Some(entry) = self.locals[worker_id].lock().unwrap().pop_front() {
+                return Some(queue_entry_to_work_item(entry));
+            }
+            if let Some(entry) = self.injector.lock().unwrap().pop_front() {
+                return Some(queue_entry_to_work_item(entry));
+            }
+            for (other_id, local) in self.locals.iter().enumerate() {
+                if other_id == worker_id {
+                    continue;
+                }
+                if let Some(entry) = local.lock().unwrap().pop_back() {
+                    return Some(queue_entry_to_work_item(entry));
+                }
+            }
+
+            if self.shutting_down.load(Ordering::Acquire) {
+                return None;
+            }
+
+            // 훔치기 경로는 전용 "뭔가 들어왔다" 신호가 없으므로, 타임아웃을 두고
+            // 주기적으로 다시 훔쳐보는 방식으로 기다린다.
+            let guard = self.park_lock.lock().unwrap();
+            let _ = self
+                .condvar
+                .wait_timeout(guard, Duration::from_millis(1))
+                .unwrap();
+        }
+    }
+
+    fn shutdown(&self) {
+        self.shutting_down.store(true, Ordering::Release);
+        self.condvar.notify_all();
+    }
+}
+
+fn queue_entry_to_work_item(entry: QueueEntry) -> WorkItem {
+    match entry {
+        QueueEntry::Translate(job) => WorkItem::Translate(job),
+        QueueEntry::Scoped(job) => WorkItem::Scoped(job),
+    }
+}
+
+/// 엔진이 아직 없으면 만들고 `initialize_ex`까지 마친 뒤 참조를 돌려준다. 이미 있으면
+/// 그대로 돌려준다 — 지연 생성과 `broadcast_init`의 즉시 생성이 이 한 곳을 공유한다.
+fn ensure_engine<'a>(
+    engine: &'a mut Option<EzTransEngine>,
+    dll_path: &Path,
+    dat_path: &Path,
+) -> Result<&'a EzTransEngine, EzTransError> {
+    if engine.is_none() {
+        let new_engine = EzTransEngine::new(dll_path)?;
+        new_engine.initialize_ex("CSUSER123455", dat_path.to_str().ok_or(EzTransError::InvalidPath)?)?;
+        *engine = Some(new_engine);
+    }
+    Ok(engine.as_ref().unwrap())
+}
+
+fn worker_loop(worker_id: usize, shared: Arc<Shared>, dll_path: PathBuf, dat_path: PathBuf) {
+    let mut engine: Option<EzTransEngine> = None;
+
+    while let Some(item) = shared.pop(worker_id) {
+        match item {
+            WorkItem::Translate(job) => match ensure_engine(&mut engine, &dll_path, &dat_path) {
+                Ok(engine) => {
+                    let result = engine.translate_mmnt(&job.text);
+                    let _ = job.reply.send(result);
+                }
+                Err(err) => {
+                    let _ = job.reply.send(Err(err));
+                }
+            },
+            WorkItem::Scoped(run) => {
+                run(ensure_engine(&mut engine, &dll_path, &dat_path));
+            }
+            WorkItem::Init(callback) => {
+                let result = ensure_engine(&mut engine, &dll_path, &dat_path).map(|_| ());
+                callback(result);
+            }
+        }
+    }
+}
+
+/// N개의 워커 스레드 위에 엔진을 각각 지연 생성해 두는 워크 스틸링 번역 풀.
+///
+/// `translate`는 즉시 반환되는 [`mpsc::Receiver`]로 결과를 전달하고, `translate_batch`는
+/// 입력 전부를 제출한 뒤 원래 순서대로 결과를 모아 돌려준다.
+pub struct TranslationPool {
+    shared: Arc<Shared>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl TranslationPool {
+    pub fn new<P: AsRef<Path>, Q: AsRef<Path>>(num_threads: usize, dll_path: P, dat_path: Q) -> Self {
+        let shared = Arc::new(Shared {
+            injector: Mutex::new(VecDeque::new()),
+            locals: (0..num_threads).map(|_| Mutex::new(VecDeque::new())).collect(),
+            init_slots: (0..num_threads).map(|_| Mutex::new(None)).collect(),
+            park_lock: Mutex::new(()),
+            condvar: Condvar::new(),
+            shutting_down: AtomicBool::new(false),
+        });
+
+        let dll_path = dll_path.as_ref().to_path_buf();
+        let dat_path = dat_path.as_ref().to_path_buf();
+
+        let workers = (0..num_threads)
+            .map(|worker_id| {
+                let shared = Arc::clone(&shared);
+                let dll_path = dll_path.clone();
+                let dat_path = dat_path.clone();
+                thread::Builder::new()
+                    .name(format!("eztrans-pool-worker-{worker_id}"))
+                    .spawn(move || worker_loop(worker_id, shared, dll_path, dat_path))
+                    .expect("번역 풀 워커 스레드 생성에 실패했습니다")
+            })
+            .collect();
+
+        Self { shared, workers }
+    }
+
+    pub fn worker_count(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// 작업을 인젝터 큐에 넣고, 결과를 받을 `Receiver`를 즉시 돌려준다.
+    pub fn translate(&self, text: impl Into<String>) -> mpsc::Receiver<Result<String, EzTransError>> {
+        let (reply, receiver) = mpsc::channel();
+        self.shared.push_injector(QueueEntry::Translate(Job {
+            text: text.into(),
+            reply,
+        }));
+        receiver
+    }
+
+    /// 입력 전부를 제출한 뒤, 입력 순서 그대로 결과를 모아 돌려준다.
+    pub fn translate_batch(&self, inputs: &[&str]) -> Vec<Result<String, EzTransError>> {
+        let receivers: Vec<_> = inputs.iter().map(|text| self.translate(*text)).collect();
+
+        receivers
+            .into_iter()
+            .map(|receiver| {
+                receiver.recv().unwrap_or_else(|_| {
+                    Err(EzTransError::FunctionCallFailed(
+                        "워커 스레드가 응답 없이 종료되었습니다".to_string(),
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    /// 모든 워커에게 동시에 "지금 엔진을 만들어라"라는 작업을 걸어, 첫 번역 요청을
+    /// 기다리지 않고 `.dat` 사전 로드 비용을 미리 한 번에 치르게 한다. 워커 하나당
+    /// 결과 하나가 들어올 때까지 호출자를 막아 두므로, 반환 시점에는 모든 워커가
+    /// 이미 초기화를 시도한 뒤다.
+    pub fn broadcast_init(&self) -> Vec<Result<(), EzTransError>> {
+        let worker_count = self.workers.len();
+        if worker_count == 0 {
+            return Vec::new();
+        }
+
+        let barrier = Arc::new(Barrier::new(worker_count + 1));
+        let results: Arc<Mutex<Vec<Option<Result<(), EzTransError>>>>> =
+            Arc::new(Mutex::new((0..worker_count).map(|_| None).collect()));
+
+        for worker_id in 0..worker_count {
+            let results = Arc::clone(&results);
+            let barrier = Arc::clone(&barrier);
+            self.shared.push_init(
+                worker_id,
+                Box::new(move |result: Result<(), EzTransError>| {
+                    results.lock().unwrap()[worker_id] = Some(result);
+                    barrier.wait();
+                }),
+            );
+        }
+
+        barrier.wait();
+
+        results
+            .lock()
+            .unwrap()
+            .drain(..)
+            .map(|slot| slot.expect("barrier를 통과한 시점엔 모든 워커 슬롯이 채워져 있다"))
+            .collect()
+    }
+
+    /// rayon-core의 `scope`을 본떠, 풀의 워커 위에서 돌아가되 `'scope`보다 짧게 사는
+    /// 스택 데이터를 빌릴 수 있는 작업을 제출한다. `scope`이 반환되기 전에 `s.spawn`으로
+    /// 넣은 작업이 전부 끝나는 것까지 블록해서 기다리므로, 빌린 참조가 작업보다 먼저
+    /// 죽는 일이 없다.
+    pub fn scope<'scope, F, R>(&'scope self, f: F) -> R
+    where
+        F: FnOnce(&Scope<'scope>) -> R,
+    {
+        let active = Arc::new((Mutex::new(0usize), Condvar::new()));
+        let panics: Arc<Mutex<Vec<Box<dyn std::any::Any + Send>>>> = Arc::new(Mutex::new(Vec::new()));
+
+        let scope = Scope {
+            shared: Arc::clone(&self.shared),
+            active: Arc::clone(&active),
+            panics: Arc::clone(&panics),
+            _marker: PhantomData,
+        };
+
+        let result = f(&scope);
+
+        let (lock, condvar) = &*active;
+        let mut count = lock.lock().unwrap();
+        while *count > 0 {
+            count = condvar.wait(count).unwrap();
+        }
+        drop(count);
+
+        let panics = std::mem::take(&mut *panics.lock().unwrap());
+        if let Some(payload) = panics.into_iter().next() {
+            std::panic::resume_unwind(payload);
+        }
+
+        result
+    }
+}
+
+/// `TranslationPool::scope`로 만든 스코프 안에서 작업을 제출하는 핸들.
+pub struct Scope<'scope> {
+    shared: Arc<Shared>,
+    active: Arc<(Mutex<usize>, Condvar)>,
+    panics: Arc<Mutex<Vec<Box<dyn std::any::Any + Send>>>>,
+    _marker: PhantomData<&'scope ()>,
+}
+
+impl<'scope> Scope<'scope> {
+    /// `f`를 풀의 어느 워커 한 곳에서 실행하도록 제출한다. `f`는 그 워커가 지연
+    /// 생성해 둔(또는 막 생성한) `EzTransEngine`의 `Result` 참조를 받는다 — 엔진
+    /// 초기화가 실패하면 `Err`가 전달된다. `scope`을 감싼 `TranslationPool::scope`이
+    /// 반환되기 전에 이 작업이 끝나는 것까지 기다리므로, `f`는 `'static`이 아니어도
+    /// 된다.
+    pub fn spawn<F>(&self, f: F)
+    where
+        F: FnOnce(Result<&EzTransEngine, EzTransError>) + Send + 'scope,
+    {
+        {
+            let mut count = self.active.0.lock().unwrap();
+            *count += 1;
+        }
+
+        let active = Arc::clone(&self.active);
+        let panics = Arc::clone(&self.panics);
+
+        let f: Box<dyn FnOnce(Result<&EzTransEngine, EzTransError>) + Send + 'scope> = Box::new(f);
+        // SAFETY: `TranslationPool::scope`는 이 클로저가 실행을 끝낼 때까지 블록한
+        // 뒤에야 반환되므로, 여기서 'scope를 'static으로 지운다고 해도 실제로 'scope
+        // 보다 오래 살아남는 일은 없다.
+        let f: Box<dyn FnOnce(Result<&EzTransEngine, EzTransError>) + Send + 'static> =
+            unsafe { std::mem::transmute(f) };
+
+        self.shared.push_injector(QueueEntry::Scoped(Box::new(
+            move |engine_result: Result<&EzTransEngine, EzTransError>| {
+                let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    f(engine_result)
+                }));
+                if let Err(payload) = result {
+                    panics.lock().unwrap().push(payload);
+                }
+
+                let mut count = active.0.lock().unwrap();
+                *count -= 1;
+                if *count == 0 {
+                    active.1.notify_all();
+                }
+            },
+        )));
+    }
+}
+
+impl Drop for TranslationPool {
+    fn drop(&mut self) {
+        self.shared.shutdown();
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
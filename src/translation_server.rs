@@ -0,0 +1,125 @@
+// 공유 메모리 채널 위에서 번역 요청을 계속 처리하는 상주 서버.
+//
+// `tests/shared_memory_tests.rs`의 `test_shared_memory_translation`과
+// `src/bin/shared_memory.rs`는 엔진 하나를 띄워 요청을 처리하는 1회성/단순 루프
+// 예제였다. J2KEngine.dll은 32비트 전용이고 스레드 안전하지도 않아 64비트 호스트
+// 프로세스가 직접 로드할 수 없으므로, `TranslationServer`는 같은 아이디어를 엔진을
+// 한 번만 초기화해 둔 채 여러 요청을 계속 받아 처리하는 재사용 가능한 서브시스템으로
+// 승격한다. 메시지 맨 앞 한 바이트를 연산 코드로 써서, 클라이언트가 매 요청마다
+// `default_translate`/`translate_mmntw`/정규화 번역 중 무엇을 쓸지, 혹은 서버를
+// 종료시킬지를 고를 수 있게 한다.
+
+use crate::normalize::NormalizationMode;
+use crate::{EzTransEngine, EzTransError};
+
+/// 요청 메시지의 첫 바이트로 전달되는 연산 코드.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestOpcode {
+    /// `default_translate`로 번역한다 (용어집 + 한글 인코딩 경유).
+    DefaultTranslate,
+    /// `translate_mmntw`로 바로 번역한다 (EHND, No Thread 모드).
+    RawMmntw,
+    /// 정규화(NFKC) 후 `default_translate`로 번역한다.
+    Normalized,
+    /// 서버를 깨끗이 종료시킨다.
+    Shutdown,
+}
+
+impl RequestOpcode {
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            b'0' => Some(Self::DefaultTranslate),
+            b'1' => Some(Self::RawMmntw),
+            b'2' => Some(Self::Normalized),
+            b'9' => Some(Self::Shutdown),
+            _ => None,
+        }
+    }
+}
+
+/// 한 요청의 처리 결과. `Ok`는 번역된 텍스트, `Err`는 `EzTransError`를 사람이 읽을 수
+/// 있는 메시지로 옮긴 것이다.
+pub type ResponseResult = Result<String, String>;
+
+/// 한 요청을 처리한 다음 서버 루프가 계속돼야 하는지 나타낸다.
+pub enum HandleOutcome {
+    /// 번역 결과(혹은 에러 메시지)를 돌려주고 계속 루프를 돈다.
+    Reply(ResponseResult),
+    /// `Shutdown` 연산 코드를 받아 루프를 끝내야 한다.
+    Shutdown,
+}
+
+/// 엔진 하나를 소유한 채 연산 코드가 붙은 요청 메시지를 계속 처리하는 서버.
+///
+/// 실제 전송 계층(공유 메모리, 파이프 등)은 이 타입이 알지 못한다. `handle_message`는
+/// 이미 수신된 메시지 바이트열을 받아 처리 결과만 돌려주므로, 채널에서 메시지를
+/// 읽고/쓰는 루프는 호출자(`src/bin/shared_memory.rs` 같은)의 몫이다.
+pub struct TranslationServer {
+    engine: EzTransEngine,
+}
+
+impl TranslationServer {
+    /// 이미 초기화된 엔진으로 서버를 만든다.
+    pub fn new(engine: EzTransEngine) -> Self {
+        Self { engine }
+    }
+
+    /// 연산 코드 한 바이트 + UTF-8 본문으로 이루어진 요청 메시지 하나를 처리한다.
+    pub fn handle_message(&self, message: &[u8]) -> HandleOutcome {
+        let Some((&opcode_byte, body)) = message.split_first() else {
+            return HandleOutcome::Reply(Err("empty request message".to_string()));
+        };
+
+        let Some(opcode) = RequestOpcode::from_byte(opcode_byte) else {
+            return HandleOutcome::Reply(Err(format!(
+                "unknown request opcode: {opcode_byte:#04x}"
+            )));
+        };
+
+        if opcode == RequestOpcode::Shutdown {
+            return HandleOutcome::Shutdown;
+        }
+
+        let text = match std::str::from_utf8(body) {
+            Ok(text) => text,
+            Err(e) => return HandleOutcome::Reply(Err(format!("invalid utf-8 payload: {e}"))),
+        };
+
+        let result = self.translate(opcode, text).map_err(|e| e.to_string());
+        HandleOutcome::Reply(result)
+    }
+
+    fn translate(&self, opcode: RequestOpcode, text: &str) -> Result<String, EzTransError> {
+        match opcode {
+            RequestOpcode::DefaultTranslate => self.engine.default_translate(text),
+            RequestOpcode::RawMmntw => self.engine.translate_mmntw(text),
+            RequestOpcode::Normalized => self
+                .engine
+                .translate_normalized(text, NormalizationMode::Compatibility),
+            RequestOpcode::Shutdown => unreachable!("shutdown is handled before translate"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_message_has_no_opcode_byte_to_split() {
+        match (RequestOpcode::from_byte(b'0'), [].split_first()) {
+            (Some(_), None) => {}
+            _ => panic!("expected no bytes to split"),
+        }
+    }
+
+    #[test]
+    fn test_unknown_opcode_is_rejected() {
+        assert_eq!(RequestOpcode::from_byte(b'?'), None);
+    }
+
+    #[test]
+    fn test_shutdown_opcode_recognized() {
+        assert_eq!(RequestOpcode::from_byte(b'9'), Some(RequestOpcode::Shutdown));
+    }
+}
@@ -0,0 +1,89 @@
+// 단일 엔진 액터 스레드 — DLL 전역 상태를 절대적으로 직렬화한다.
+//
+// 테스트 6/7이 보여주듯 서로 다른 스레드의 `EzTransEngine`이 동시에 `initialize_ex`를
+// 부르면 먼저 된 쪽을 덮어쓴다. `TranslationService`는 전용 스레드 하나가
+// `EzTransEngine`을 독점 소유하게 해서 이 문제를 구조적으로 차단한다 — 다른 모든
+// 스레드는 `(text, oneshot 회신)` 작업을 mpsc로 보내기만 할 뿐, DLL을 직접 건드리지
+// 않는다.
+//
+// 핸들은 `mpsc::Sender`를 감싼 것이라 값싸게 `Clone`할 수 있다. 마지막 클론이
+// 드롭되면 작업 채널이 끊기고(워커의 `for job in rx` 루프가 자연히 끝난다), 워커는
+// 그때까지 들어온 작업을 모두 처리한 뒤 엔진을 `terminate`한다.
+
+use std::path::Path;
+use std::sync::mpsc;
+use std::thread;
+
+use crate::{EzTransEngine, EzTransError};
+
+struct Job {
+    text: String,
+    reply: mpsc::Sender<Result<String, EzTransError>>,
+}
+
+/// 하나의 DLL 인스턴스만 독점적으로 소유하는 전용 워커 스레드의 핸들.
+#[derive(Clone)]
+pub struct TranslationService {
+    jobs: mpsc::Sender<Job>,
+}
+
+impl TranslationService {
+    /// 워커 스레드를 띄워 `dll_path`를 로드하고 `dat_path`로 초기화한다. 로드/초기화가
+    /// 모두 워커 스레드 위에서 일어나야 하므로, 이 함수는 그 결과를 돌려받을 때까지
+    /// 블록한다.
+    pub fn spawn<P: AsRef<Path>, Q: AsRef<Path>>(dll_path: P, dat_path: Q) -> Result<Self, EzTransError> {
+        let dll_path = dll_path.as_ref().to_path_buf();
+        let dat_path = dat_path.as_ref().to_path_buf();
+
+        let (jobs, rx) = mpsc::channel::<Job>();
+        let (init_tx, init_rx) = mpsc::channel::<Result<(), EzTransError>>();
+
+        thread::spawn(move || {
+            let engine = match EzTransEngine::new(&dll_path) {
+                Ok(engine) => engine,
+                Err(err) => {
+                    let _ = init_tx.send(Err(err));
+                    return;
+                }
+            };
+
+            let dat_path_str = match dat_path.to_str().ok_or(EzTransError::InvalidPath) {
+                Ok(dat_path_str) => dat_path_str,
+                Err(err) => {
+                    let _ = init_tx.send(Err(err));
+                    return;
+                }
+            };
+
+            if let Err(err) = engine.initialize_ex("CSUSER123455", dat_path_str) {
+                let _ = init_tx.send(Err(err));
+                return;
+            }
+
+            let _ = init_tx.send(Ok(()));
+
+            for job in rx {
+                let result = engine.translate_mmnt(&job.text);
+                let _ = job.reply.send(result);
+            }
+
+            let _ = engine.terminate();
+        });
+
+        init_rx
+            .recv()
+            .map_err(|_| EzTransError::FunctionCallFailed("워커 스레드가 초기화 중 panic했습니다".to_string()))??;
+
+        Ok(Self { jobs })
+    }
+
+    /// 작업을 워커 스레드로 보내고, 결과를 받을 `Receiver`를 즉시 돌려준다.
+    pub fn translate(&self, text: impl Into<String>) -> mpsc::Receiver<Result<String, EzTransError>> {
+        let (reply, receiver) = mpsc::channel();
+        let _ = self.jobs.send(Job {
+            text: text.into(),
+            reply,
+        });
+        receiver
+    }
+}
@@ -0,0 +1,127 @@
+// 블로킹/논블로킹 클라이언트를 가르는 두 트레이트.
+//
+// `SyncTranslator`는 오늘날 `EzTransEngine::default_translate`가 하는 일 그대로 —
+// 호출 스레드가 DLL 호출이 끝날 때까지 블록한다. `AsyncTranslator`는 그 반대로,
+// `pool::EzTransPool`(멀티프로세스 워커 풀)이나 `engine_pool::EzTransPool`(스레드
+// 풀)에 작업을 넘기고 워커가 결과를 내놓을 때 채워지는 `JobHandle`을 즉시 돌려준다 —
+// GUI나 게임 로컬라이제이션 호출자가 수백 줄을 한꺼번에 던져 놓고 끝나는 대로
+// 거둬들일 수 있게 한다.
+//
+// `client::AsyncPipeClient`/`translation_engine::AsyncTranslationEngine`과 마찬가지로
+// `JobHandle`은 이 모듈 전용으로 따로 둔다 — 다른 비동기 경로와 구조는 같지만
+// 채워주는 대상(풀의 워커 프로세스/스레드)이 다르므로 공유 제네릭 타입으로 묶지
+// 않는다.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use crate::{engine_pool, pool::EzTransPool, EzTransEngine, EzTransError};
+
+/// 호출 스레드가 DLL 호출이 끝날 때까지 블록하는 동기 번역기.
+pub trait SyncTranslator {
+    fn translate(&self, input: &str) -> Result<String, EzTransError>;
+}
+
+impl SyncTranslator for EzTransEngine {
+    fn translate(&self, input: &str) -> Result<String, EzTransError> {
+        self.default_translate(input)
+    }
+}
+
+struct JobState<T> {
+    result: Option<Result<T, EzTransError>>,
+    waker: Option<Waker>,
+}
+
+/// [`AsyncTranslator::submit`] 호출 하나의 결과를 표현하는 `Future`. 워커가 결과를
+/// 내놓으면 저장해 둔 `Waker`를 깨운다.
+pub struct JobHandle<T> {
+    state: Arc<Mutex<JobState<T>>>,
+}
+
+impl<T> Future for JobHandle<T> {
+    type Output = Result<T, EzTransError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+        if let Some(result) = state.result.take() {
+            Poll::Ready(result)
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+/// DLL 호출을 기다리지 않고 곧바로 돌아오는 비동기 번역기. `submit`은 워커에 작업을
+/// 넘기자마자 `JobHandle`을 돌려주며, 실제 번역은 워커가 결과 줄을 내놓을 때
+/// 백그라운드에서 끝난다.
+pub trait AsyncTranslator {
+    fn submit(&self, input: &str) -> JobHandle<String>;
+
+    /// `inputs` 각각에 대해 `submit`을 호출하고, 입력 순서 그대로 핸들을 모아 돌려준다.
+    fn submit_many(&self, inputs: &[String]) -> Vec<JobHandle<String>> {
+        inputs.iter().map(|text| self.submit(text)).collect()
+    }
+}
+
+impl AsyncTranslator for Arc<EzTransPool> {
+    fn submit(&self, input: &str) -> JobHandle<String> {
+        let state = Arc::new(Mutex::new(JobState {
+            result: None,
+            waker: None,
+        }));
+        let state_for_job = Arc::clone(&state);
+
+        let pool = Arc::clone(self);
+        let text = input.to_string();
+        let receiver = pool.submit(text);
+
+        std::thread::spawn(move || {
+            let result = receiver
+                .recv()
+                .unwrap_or_else(|_| Err(EzTransError::FunctionCallFailed("워커가 응답 없이 종료되었습니다".to_string())));
+            let mut state = state_for_job.lock().unwrap();
+            state.result = Some(result);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        });
+
+        JobHandle { state }
+    }
+}
+
+/// `engine_pool::EzTransPool::translate`는 호출 스레드를 DLL 응답이 올 때까지
+/// 블록시키므로 동기 번역기로도 구현한다(멀티프로세스 풀은 위의 비동기 구현만 있다).
+impl SyncTranslator for engine_pool::EzTransPool {
+    fn translate(&self, input: &str) -> Result<String, EzTransError> {
+        engine_pool::EzTransPool::translate(self, input)
+    }
+}
+
+impl AsyncTranslator for Arc<engine_pool::EzTransPool> {
+    fn submit(&self, input: &str) -> JobHandle<String> {
+        let state = Arc::new(Mutex::new(JobState {
+            result: None,
+            waker: None,
+        }));
+        let state_for_job = Arc::clone(&state);
+
+        let pool = Arc::clone(self);
+        let text = input.to_string();
+
+        std::thread::spawn(move || {
+            let result = pool.translate(text);
+            let mut state = state_for_job.lock().unwrap();
+            state.result = Some(result);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        });
+
+        JobHandle { state }
+    }
+}
@@ -0,0 +1,141 @@
+// `translate_mmntw`가 돌려주는 UTF-16 코드 단위 배열을 엄격하게 디코딩하거나(strict),
+// 짝이 맞지 않는 서로게이트를 U+FFFD로 치환하며 관대하게(lossy) 디코딩한다.
+//
+// `std::char::decode_utf16`은 이미 짝이 맞지 않는 서로게이트를 걸러내지만, 그 반복자가
+// 세는 인덱스는 "몇 번째로 디코딩된 문자인지"이지 "몇 번째 코드 단위에서 실패했는지"가
+// 아니어서, `TranscodeError::valid_up_to`가 요구하는 코드 단위 오프셋을 그대로 내줄 수
+// 없다. 그래서 서로게이트 쌍을 직접 검사하는 루프를 쓴다.
+
+use crate::TranscodeError;
+
+/// `unit`이 서로게이트 코드 단위(상위 0xD800~0xDBFF 또는 하위 0xDC00~0xDFFF)인지.
+fn is_surrogate(unit: u16) -> bool {
+    (0xD800..=0xDFFF).contains(&unit)
+}
+
+fn is_high_surrogate(unit: u16) -> bool {
+    (0xD800..=0xDBFF).contains(&unit)
+}
+
+fn is_low_surrogate(unit: u16) -> bool {
+    (0xDC00..=0xDFFF).contains(&unit)
+}
+
+fn combine_surrogate_pair(high: u16, low: u16) -> char {
+    let code = 0x10000 + ((high as u32 - 0xD800) << 10) + (low as u32 - 0xDC00);
+    char::from_u32(code).expect("유효한 서로게이트 쌍은 항상 유효한 코드포인트로 조합된다")
+}
+
+/// `units`를 엄격하게 디코딩한다. 짝이 맞지 않는 서로게이트를 만나면 그 오프셋과
+/// 코드 단위, 그리고 그 앞까지 디코딩된 부분 문자열을 담은 [`TranscodeError`]를
+/// 돌려준다.
+pub fn decode_strict(units: &[u16]) -> Result<String, TranscodeError> {
+    let mut output = String::with_capacity(units.len());
+    let mut i = 0;
+
+    while i < units.len() {
+        let unit = units[i];
+
+        if !is_surrogate(unit) {
+            output.push(char::from_u32(unit as u32).expect("서로게이트가 아닌 u16은 항상 유효한 스칼라 값이다"));
+            i += 1;
+            continue;
+        }
+
+        if is_high_surrogate(unit) {
+            if let Some(&low) = units.get(i + 1) {
+                if is_low_surrogate(low) {
+                    output.push(combine_surrogate_pair(unit, low));
+                    i += 2;
+                    continue;
+                }
+            }
+        }
+
+        return Err(TranscodeError {
+            valid_prefix: output,
+            valid_up_to: i,
+            invalid_unit: unit,
+        });
+    }
+
+    Ok(output)
+}
+
+/// `decode_strict`과 같은 규칙으로 훑되, 짝이 맞지 않는 서로게이트를 만나도 멈추지 않고
+/// U+FFFD로 치환한 뒤 계속 진행한다. 돌려주는 `Vec<usize>`는 치환이 일어난 코드 단위
+/// 오프셋들이다.
+pub fn decode_lossy(units: &[u16]) -> (String, Vec<usize>) {
+    let mut output = String::with_capacity(units.len());
+    let mut replaced = Vec::new();
+    let mut i = 0;
+
+    while i < units.len() {
+        let unit = units[i];
+
+        if !is_surrogate(unit) {
+            output.push(char::from_u32(unit as u32).expect("서로게이트가 아닌 u16은 항상 유효한 스칼라 값이다"));
+            i += 1;
+            continue;
+        }
+
+        if is_high_surrogate(unit) {
+            if let Some(&low) = units.get(i + 1) {
+                if is_low_surrogate(low) {
+                    output.push(combine_surrogate_pair(unit, low));
+                    i += 2;
+                    continue;
+                }
+            }
+        }
+
+        output.push('\u{FFFD}');
+        replaced.push(i);
+        i += 1;
+    }
+
+    (output, replaced)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf16(s: &str) -> Vec<u16> {
+        s.encode_utf16().collect()
+    }
+
+    #[test]
+    fn test_decode_strict_round_trips_bmp_and_surrogate_pairs() {
+        let units = utf16("한글🎉A");
+        assert_eq!(decode_strict(&units).unwrap(), "한글🎉A");
+    }
+
+    #[test]
+    fn test_decode_strict_reports_unpaired_high_surrogate() {
+        let mut units = utf16("ab");
+        units.push(0xD800); // 짝이 없는 상위 서로게이트
+        let err = decode_strict(&units).unwrap_err();
+        assert_eq!(err.valid_up_to(), 2);
+        assert_eq!(err.invalid_unit(), 0xD800);
+        assert_eq!(err.valid_prefix(), "ab");
+    }
+
+    #[test]
+    fn test_decode_strict_reports_lone_low_surrogate() {
+        let units = vec![0xDC00];
+        let err = decode_strict(&units).unwrap_err();
+        assert_eq!(err.valid_up_to(), 0);
+        assert_eq!(err.invalid_unit(), 0xDC00);
+    }
+
+    #[test]
+    fn test_decode_lossy_substitutes_replacement_char_and_records_offset() {
+        let mut units = utf16("ab");
+        units.push(0xD800); // 짝이 없는 상위 서로게이트
+        units.extend(utf16("cd"));
+        let (decoded, replaced) = decode_lossy(&units);
+        assert_eq!(decoded, "ab\u{FFFD}cd");
+        assert_eq!(replaced, vec![2]);
+    }
+}
@@ -4,10 +4,18 @@
 
 use eztrans_rs::EzTransEngine;
 use eztrans_rs::char_ranges::is_safe_chars;
+use eztrans_rs::char_ranges::generate;
 use serial_test::serial;
 use std::collections::{BTreeMap, HashSet};
 use std::sync::Mutex;
 
+/// `cargo:rerun-if-changed`으로 `build.rs`가 지켜보는, discovery 결과가 떨어지는 경로.
+/// `CARGO_MANIFEST_DIR` 기준으로 잡아야 어느 디렉터리에서 테스트를 돌려도 같은 파일에
+/// 쓰인다.
+fn unsafe_ranges_data_path() -> std::path::PathBuf {
+    std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("data/unsafe_ranges.json")
+}
+
 /// Wrapper to make EzTransEngine usable in static context
 struct EngineWrapper(EzTransEngine);
 unsafe impl Send for EngineWrapper {}
@@ -217,6 +225,14 @@ fn test_discover_problematic_unicode_ranges() {
                 println!("  ... and {} more", chars.len() - 20);
             }
         }
+
+        // Persist the findings so `build.rs` can compile them into
+        // `char_ranges::GENERATED_UNSAFE_RANGES` on the next build.
+        let ranges = generate::merge_ranges(problematic_chars.iter().map(|&c| c as u32));
+        let path = unsafe_ranges_data_path();
+        generate::write_table(&ranges, &path)
+            .unwrap_or_else(|e| panic!("Failed to write {}: {e}", path.display()));
+        println!("\nWrote {} range(s) to {}", ranges.len(), path.display());
     });
 }
 
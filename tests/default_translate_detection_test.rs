@@ -7,8 +7,9 @@
 
 use eztrans_rs::EzTransEngine;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::env;
-use std::fs::File;
+use std::fs::{File, OpenOptions};
 use std::io::{BufRead, BufReader, Write};
 use std::process::{Command, Stdio};
 use std::sync::mpsc;
@@ -108,6 +109,8 @@ struct DetectionResult {
 enum WorkerMessage {
     Progress {
         worker_id: usize,
+        /// 이 워커가 지금까지 완료한 마지막 절대 인덱스. 체크포인트 저장에 쓰인다.
+        abs_idx: u32,
         current_code: u32,
         tested: u32,
         detected: u32,
@@ -251,6 +254,7 @@ fn detection_worker_process(
         if last_progress.elapsed() >= Duration::from_millis(PROGRESS_INTERVAL_MS) {
             send_message(&WorkerMessage::Progress {
                 worker_id,
+                abs_idx,
                 current_code: code,
                 tested: total_tested,
                 detected: total_detected,
@@ -309,6 +313,52 @@ fn detection_worker() {
     }
 }
 
+/// 체크포인트 파일 경로. 스캔이 끝까지 완료되면 지워서 다음 실행이 처음부터
+/// 시작하도록 한다.
+const CHECKPOINT_PATH: &str = "default_translate_detection_checkpoint.json";
+const CHECKPOINT_SAVE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 워커별로 완료한 마지막 절대 인덱스를 기록하는 체크포인트. 중단 후 재시작할 때
+/// 각 워커의 범위를 `last_completed + 1`부터 다시 시작하도록 좁히는 데 쓰인다.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct ScanCheckpoint {
+    completed_through: HashMap<usize, u32>,
+}
+
+fn load_checkpoint() -> ScanCheckpoint {
+    std::fs::read_to_string(CHECKPOINT_PATH)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save_checkpoint(checkpoint: &ScanCheckpoint) {
+    if let Ok(json) = serde_json::to_string_pretty(checkpoint) {
+        let _ = std::fs::write(CHECKPOINT_PATH, json);
+    }
+}
+
+/// 이전 실행이 이미 CSV에 기록해 둔 코드포인트를 읽어온다. 체크포인트가 범위를
+/// 좁혀 두긴 하지만, 워커가 청크를 보내고 죽는 사이의 경계 구간처럼 체크포인트보다
+/// CSV가 더 최신인 경우를 대비한 이중 안전장치다.
+fn read_existing_codes(csv_path: &str) -> HashSet<u32> {
+    let mut codes = HashSet::new();
+    let Ok(file) = File::open(csv_path) else {
+        return codes;
+    };
+    for line in BufReader::new(file).lines().skip(1).flatten() {
+        let Some(code_field) = line.split(',').next() else {
+            continue;
+        };
+        if let Some(hex) = code_field.trim().strip_prefix("U+") {
+            if let Ok(code) = u32::from_str_radix(hex, 16) {
+                codes.insert(code);
+            }
+        }
+    }
+    codes
+}
+
 #[derive(Debug)]
 enum CoordinatorMessage {
     WorkerMessage {
@@ -408,7 +458,7 @@ fn detect_unicode_issues_multiprocess(num_processes_opt: Option<usize>) {
 
     // 작업 분배
     let chunk_size = total_codepoints / num_processes as u32;
-    let mut work_assignments: Vec<(usize, u32, u32)> = Vec::new();
+    let mut full_ranges: Vec<(usize, u32, u32)> = Vec::new();
 
     for worker_id in 0..num_processes {
         let abs_start = worker_id as u32 * chunk_size;
@@ -417,7 +467,40 @@ fn detect_unicode_issues_multiprocess(num_processes_opt: Option<usize>) {
         } else {
             (worker_id as u32 + 1) * chunk_size - 1
         };
-        work_assignments.push((worker_id, abs_start, abs_end));
+        full_ranges.push((worker_id, abs_start, abs_end));
+    }
+
+    // 체크포인트를 읽어 워커별 범위를 `last_completed + 1`부터 시작하도록 좁힌다.
+    // 이미 끝난 워커는 작업 목록에서 빠지고, 곧바로 완료 처리된다.
+    let mut checkpoint = load_checkpoint();
+    if !checkpoint.completed_through.is_empty() {
+        println!("Resuming from checkpoint: {:?}\n", checkpoint.completed_through);
+    }
+
+    let csv_path = "default_translate_detection.csv";
+    let resuming = std::path::Path::new(csv_path).exists();
+    let mut seen_codes = read_existing_codes(csv_path);
+
+    let mut work_assignments: Vec<(usize, u32, u32)> = Vec::new();
+    let mut worker_statuses: Vec<WorkerStatus> =
+        (0..num_processes).map(|_| WorkerStatus::new()).collect();
+    let mut workers_completed = 0usize;
+
+    for (worker_id, abs_start, abs_end) in &full_ranges {
+        let resume_start = checkpoint
+            .completed_through
+            .get(worker_id)
+            .map(|&last| last + 1)
+            .unwrap_or(*abs_start)
+            .max(*abs_start);
+
+        if resume_start > *abs_end {
+            worker_statuses[*worker_id].completed = true;
+            workers_completed += 1;
+            continue;
+        }
+
+        work_assignments.push((*worker_id, resume_start, *abs_end));
     }
 
     println!("Work distribution:");
@@ -434,16 +517,28 @@ fn detect_unicode_issues_multiprocess(num_processes_opt: Option<usize>) {
     }
     println!();
 
+    // CSV를 이어 쓸 수 있도록 연다. 이전 실행 결과가 있으면 append, 없으면 BOM과
+    // 헤더를 쓴 새 파일로 시작한다.
+    let mut csv_file = if resuming {
+        OpenOptions::new()
+            .append(true)
+            .open(csv_path)
+            .expect("failed to reopen detection CSV in append mode")
+    } else {
+        let mut file = File::create(csv_path).expect("failed to create detection CSV");
+        file.write_all("\u{FEFF}".as_bytes()).ok();
+        writeln!(file, "Code,Character,Translation,IssueType").ok();
+        file
+    };
+
     let overall_start_time = Instant::now();
     let current_exe = env::current_exe().expect("Failed to get current exe path");
 
     let (tx, rx) = mpsc::channel::<CoordinatorMessage>();
 
-    let mut worker_statuses: Vec<WorkerStatus> =
-        (0..num_processes).map(|_| WorkerStatus::new()).collect();
-    let mut workers_completed = 0usize;
     let mut all_results: Vec<DetectionResult> = Vec::new();
     let mut total_tested = 0u32;
+    let mut last_checkpoint_save = Instant::now();
 
     let mut reader_threads = Vec::new();
 
@@ -509,6 +604,11 @@ fn detect_unicode_issues_multiprocess(num_processes_opt: Option<usize>) {
 
     println!("\nProcessing...\n");
 
+    let worker_ranges: HashMap<usize, u32> = work_assignments
+        .iter()
+        .map(|(worker_id, _, abs_end)| (*worker_id, *abs_end))
+        .collect();
+
     let mut last_dashboard_update = Instant::now();
     let dashboard_interval = Duration::from_millis(500);
 
@@ -517,15 +617,34 @@ fn detect_unicode_issues_multiprocess(num_processes_opt: Option<usize>) {
             Ok(coord_msg) => match coord_msg {
                 CoordinatorMessage::WorkerMessage { worker_id, msg } => match msg {
                     WorkerMessage::Progress {
-                        tested, detected, ..
+                        abs_idx,
+                        tested,
+                        detected,
+                        ..
                     } => {
                         if worker_id < worker_statuses.len() {
                             worker_statuses[worker_id].tested = tested;
                             worker_statuses[worker_id].detected = detected;
                         }
+                        checkpoint.completed_through.insert(worker_id, abs_idx);
                     }
                     WorkerMessage::ChunkResult { results, .. } => {
-                        all_results.extend(results);
+                        for result in results {
+                            if seen_codes.insert(result.code) {
+                                let char_display = char::from_u32(result.code)
+                                    .map(|c| format!("{}", c))
+                                    .unwrap_or_else(|| "N/A".to_string());
+                                let translation_escaped = result.translation.replace('"', "\"\"");
+                                writeln!(
+                                    csv_file,
+                                    "U+{:06X},\"{}\",\"{}\",{}",
+                                    result.code, char_display, translation_escaped, result.issue_type
+                                )
+                                .ok();
+                                all_results.push(result);
+                            }
+                        }
+                        csv_file.flush().ok();
                     }
                     WorkerMessage::Complete {
                         worker_id: wid,
@@ -538,6 +657,10 @@ fn detect_unicode_issues_multiprocess(num_processes_opt: Option<usize>) {
                             worker_statuses[wid].detected = total_detected;
                             worker_statuses[wid].completed = true;
                         }
+                        if let Some(&abs_end) = worker_ranges.get(&wid) {
+                            checkpoint.completed_through.insert(wid, abs_end);
+                        }
+                        save_checkpoint(&checkpoint);
                         total_tested += tested;
                     }
                     WorkerMessage::Error {
@@ -565,6 +688,13 @@ fn detect_unicode_issues_multiprocess(num_processes_opt: Option<usize>) {
             last_dashboard_update = Instant::now();
         }
 
+        // 주기적으로 체크포인트를 디스크에 반영한다 (Progress 메시지만으로도 갱신되지만,
+        // 중간에 프로세스가 죽어도 최근 몇 초 이내 상태는 보존되도록 별도로 저장한다).
+        if last_checkpoint_save.elapsed() >= CHECKPOINT_SAVE_INTERVAL {
+            save_checkpoint(&checkpoint);
+            last_checkpoint_save = Instant::now();
+        }
+
         if workers_completed >= num_processes {
             break;
         }
@@ -582,43 +712,12 @@ fn detect_unicode_issues_multiprocess(num_processes_opt: Option<usize>) {
     );
     println!("Total codepoints tested: {}", total_tested);
     println!("Total issues detected: {}", all_results.len());
+    println!("\nResults appended to: {}", csv_path);
 
-    // CSV 파일로 저장 (UTF-8 BOM 포함)
-    let csv_path = "default_translate_detection.csv";
-
-    match File::create(csv_path) {
-        Ok(mut file) => {
-            // UTF-8 BOM
-            file.write_all("\u{FEFF}".as_bytes()).ok();
-
-            // CSV 헤더
-            writeln!(file, "Code,Character,Translation,IssueType").ok();
-
-            // 결과 정렬 (코드 순)
-            all_results.sort_by_key(|r| r.code);
-
-            // CSV 데이터 작성
-            for result in &all_results {
-                let char_display = char::from_u32(result.code)
-                    .map(|c| format!("{}", c))
-                    .unwrap_or_else(|| "N/A".to_string());
-
-                // CSV 이스케이프 처리
-                let translation_escaped = result.translation.replace('"', "\"\"");
-
-                writeln!(
-                    file,
-                    "U+{:06X},\"{}\",\"{}\",{}",
-                    result.code, char_display, translation_escaped, result.issue_type
-                )
-                .ok();
-            }
-
-            println!("\nResults saved to: {}", csv_path);
-        }
-        Err(e) => {
-            eprintln!("\nFailed to create CSV file: {}", e);
-        }
+    // 모든 워커가 자기 범위를 끝까지 마쳤을 때만 체크포인트를 지운다. 다음 실행은
+    // 처음부터(혹은 새 범위로) 다시 시작한다. 중간에 끝난 워커가 있으면 남겨 둔다.
+    if worker_statuses.iter().all(|s| s.completed) {
+        let _ = std::fs::remove_file(CHECKPOINT_PATH);
     }
 
     // 통계 출력
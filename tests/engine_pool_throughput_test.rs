@@ -0,0 +1,105 @@
+// Throughput comparison for EzTransPool vs the Mutex<UnsafeEngineWrapper> pattern
+// exercised in thread_safety_test.rs's "Test 3".
+//
+// Test 3 there shows that a single DLL instance guarded by a Mutex is thread-safe but
+// serializes every call onto one engine, so wall-clock time scales with the number of
+// requests regardless of how many threads are waiting. EzTransPool instead gives every
+// worker its own DLL instance, so this test checks that N requests sent through a pool
+// of N workers complete correctly and in less time than the same N requests serialized
+// through a single mutex-protected engine.
+//
+// Run with: cargo test --target i686-pc-windows-msvc --test engine_pool_throughput_test -- --ignored --nocapture
+
+use eztrans_rs::engine_pool::EzTransPool;
+use eztrans_rs::EzTransEngine;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Instant;
+
+fn get_engine_paths() -> (String, String) {
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let dll_path = format!("{}/../eztrans_dll/J2KEngine.dll", manifest_dir);
+    let dat_path = format!("{}/../eztrans_dll/Dat", manifest_dir);
+    (dll_path, dat_path)
+}
+
+const TEST_TEXTS: &[&str] = &[
+    "おはようございます。",
+    "こんにちは。",
+    "こんばんは。",
+    "ありがとうございます。",
+];
+
+#[test]
+#[ignore]
+fn test_pool_is_genuinely_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<EzTransPool>();
+}
+
+#[test]
+#[ignore]
+fn test_pool_outperforms_single_mutexed_engine() {
+    let (dll_path, dat_path) = get_engine_paths();
+    let num_workers = 4;
+    let iterations_per_worker = 25;
+    let total = num_workers * iterations_per_worker;
+
+    println!("\n=== EzTransPool vs Mutex<UnsafeEngineWrapper> ===");
+
+    let pool = EzTransPool::new(num_workers, &dll_path, &dat_path).expect("Failed to start pool");
+    let inputs: Vec<String> = (0..total)
+        .map(|i| TEST_TEXTS[i % TEST_TEXTS.len()].to_string())
+        .collect();
+    let pool_start = Instant::now();
+    let pool_results = pool.translate_batch(&inputs);
+    let pool_elapsed = pool_start.elapsed();
+    drop(pool);
+
+    let pool_successes = pool_results.iter().filter(|r| r.is_ok()).count();
+    println!("EzTransPool ({} workers):", num_workers);
+    println!("  Success: {}/{}", pool_successes, total);
+    println!("  Time: {:?}", pool_elapsed);
+
+    let mutexed = EzTransEngine::new(&dll_path).expect("Failed to load DLL");
+    mutexed
+        .initialize_ex("CSUSER123455", &dat_path)
+        .expect("Failed to initialize");
+    let mutexed = Arc::new(Mutex::new(mutexed));
+
+    let mutex_start = Instant::now();
+    let handles: Vec<_> = (0..num_workers)
+        .map(|worker_id| {
+            let mutexed = Arc::clone(&mutexed);
+            thread::spawn(move || {
+                let mut successes = 0;
+                for i in 0..iterations_per_worker {
+                    let text = TEST_TEXTS[(worker_id + i) % TEST_TEXTS.len()];
+                    if mutexed.lock().unwrap().translate_mmntw(text).is_ok() {
+                        successes += 1;
+                    }
+                }
+                successes
+            })
+        })
+        .collect();
+    let mutex_successes: usize = handles.into_iter().map(|h| h.join().unwrap()).sum();
+    let mutex_elapsed = mutex_start.elapsed();
+
+    println!("Mutex<EzTransEngine> (1 shared engine):");
+    println!("  Success: {}/{}", mutex_successes, total);
+    println!("  Time: {:?}", mutex_elapsed);
+
+    assert_eq!(pool_successes, total, "every pooled request should succeed");
+    assert_eq!(mutex_successes, total, "every mutexed request should succeed");
+
+    println!(
+        "\nPool was {:.2}x the mutex's duration",
+        pool_elapsed.as_secs_f64() / mutex_elapsed.as_secs_f64()
+    );
+    assert!(
+        pool_elapsed < mutex_elapsed,
+        "pool with {} independent DLL instances should beat a single mutex-serialized engine",
+        num_workers
+    );
+}
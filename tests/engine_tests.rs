@@ -2,7 +2,7 @@
 // Note: These tests require the EzTrans DLL (32-bit) to be present.
 // Run with: cargo test --target i686-pc-windows-msvc --test engine_tests -- --include-ignored --test-threads=1
 
-use eztrans_rs::EzTransEngine;
+use eztrans_rs::{EzTransEngine, GlossaryMode};
 use serial_test::serial;
 use std::sync::Mutex;
 
@@ -51,7 +51,7 @@ where
 fn test_engine_new() {
     with_engine(|engine| {
         // If we got here, the engine was created successfully
-        assert!(!engine.module.is_invalid());
+        assert!(engine.terminate.is_some());
     });
 }
 
@@ -174,6 +174,61 @@ fn test_hangul_encode_decode_roundtrip() {
     });
 }
 
+#[test]
+#[ignore]
+#[serial]
+fn test_needs_encoding_cluster_true_when_any_codepoint_is_hangul() {
+    with_engine(|engine| {
+        // 결합 문자(U+0301)가 끼어 있어도, 클러스터 안에 한글 음절이 하나라도 있으면
+        // 전체 클러스터를 이스케이프 대상으로 본다.
+        let cluster = format!("{}{}", '안', '\u{0301}');
+        assert!(engine.needs_encoding_cluster(&cluster));
+    });
+}
+
+#[test]
+#[ignore]
+#[serial]
+fn test_needs_encoding_cluster_false_for_plain_ascii() {
+    with_engine(|engine| {
+        assert!(!engine.needs_encoding_cluster("i"));
+    });
+}
+
+#[test]
+#[ignore]
+#[serial]
+fn test_hangul_encode_clusters_escapes_whole_hangul_syllable() {
+    with_engine(|engine| {
+        let encoded = engine.hangul_encode_clusters("안녕");
+        assert!(!encoded.contains('안'));
+        assert!(encoded.contains("+x"));
+    });
+}
+
+#[test]
+#[ignore]
+#[serial]
+fn test_hangul_encode_clusters_decode_roundtrip() {
+    with_engine(|engine| {
+        let original = "ì•ˆë…•í•˜ì„¸ìš” Hello ä¸–ç•Œ";
+        let encoded = engine.hangul_encode_clusters(original);
+        let decoded = engine.hangul_decode(&encoded);
+
+        assert_eq!(decoded, original);
+    });
+}
+
+#[test]
+#[ignore]
+#[serial]
+fn test_hangul_encode_clusters_leaves_plain_ascii_untouched() {
+    with_engine(|engine| {
+        let encoded = engine.hangul_encode_clusters("Hello world");
+        assert_eq!(encoded, "Hello world");
+    });
+}
+
 #[test]
 #[ignore]
 #[serial]
@@ -470,3 +525,78 @@ fn test_emoji_only() {
         }
     });
 }
+
+// ============================================
+// Glossary Tests
+// ============================================
+
+#[test]
+#[ignore]
+#[serial]
+fn test_add_term_builds_on_prior_set_glossary() {
+    with_engine(|engine| {
+        engine
+            .set_glossary(&[("剣".to_string(), Some("검".to_string()))])
+            .unwrap();
+        engine
+            .add_term("ドル", Some("골드"), GlossaryMode::Pre)
+            .unwrap();
+
+        let translated = engine.default_translate("剣とドルを手に入れた").unwrap();
+        assert!(translated.contains("검"));
+        assert!(translated.contains("골드"));
+
+        engine.clear_glossary();
+    });
+}
+
+#[test]
+#[ignore]
+#[serial]
+fn test_clear_glossary_resets_pre_terms() {
+    with_engine(|engine| {
+        engine
+            .add_term("剣", Some("검"), GlossaryMode::Pre)
+            .unwrap();
+
+        engine.clear_glossary();
+
+        let translated = engine.default_translate("剣を手に入れた").unwrap();
+        assert!(!translated.contains("검"));
+    });
+}
+
+#[test]
+#[ignore]
+#[serial]
+fn test_post_mode_corrects_translated_output() {
+    with_engine(|engine| {
+        // 엔진이 실제로 내놓는 출력을 미리 한 번 확인한 뒤, 그 일부를 POST 용어로
+        // 등록해 다음 호출부터는 교정되는지 확인한다.
+        let baseline = engine.default_translate("剣を手に入れた").unwrap();
+        let wrong_fragment: String = baseline.chars().take(1).collect();
+        if wrong_fragment.is_empty() {
+            engine.clear_glossary();
+            return;
+        }
+
+        engine
+            .add_term(&wrong_fragment, Some("교정됨"), GlossaryMode::Post)
+            .unwrap();
+        let corrected = engine.default_translate("剣を手に入れた").unwrap();
+        assert!(corrected.contains("교정됨"));
+
+        engine.clear_glossary();
+    });
+}
+
+#[test]
+#[ignore]
+#[serial]
+fn test_add_term_post_mode_requires_target() {
+    with_engine(|engine| {
+        let result = engine.add_term("용어", None, GlossaryMode::Post);
+        assert!(result.is_err());
+        engine.clear_glossary();
+    });
+}
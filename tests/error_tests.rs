@@ -1,6 +1,6 @@
 // Error Type Tests
 
-use eztrans_rs::{EzTransError, TransErr};
+use eztrans_rs::{EzTransError, TransErr, TranscodeError};
 
 #[test]
 fn test_trans_err_display_null_pointer() {
@@ -9,9 +9,27 @@ fn test_trans_err_display_null_pointer() {
 }
 
 #[test]
-fn test_trans_err_display_euc_kr_decode_failed() {
-    let err = TransErr::EucKrDecodeFailed;
-    assert_eq!(format!("{}", err), "EUC-KR decoding failed");
+fn test_trans_err_display_invalid_byte_sequence() {
+    let err = TransErr::InvalidByteSequence {
+        bytes: vec![0x80],
+        offset: 3,
+    };
+    assert_eq!(
+        format!("{}", err),
+        "invalid EUC-KR byte sequence at offset 3: 80"
+    );
+}
+
+#[test]
+fn test_trans_err_display_undefined_conversion() {
+    let err = TransErr::UndefinedConversion { offset: 7 };
+    assert_eq!(format!("{}", err), "undefined EUC-KR conversion at offset 7");
+}
+
+#[test]
+fn test_trans_err_display_incomplete_input() {
+    let err = TransErr::IncompleteInput;
+    assert_eq!(format!("{}", err), "incomplete EUC-KR input");
 }
 
 #[test]
@@ -19,8 +37,8 @@ fn test_trans_err_debug() {
     let err = TransErr::NullPointer;
     assert_eq!(format!("{:?}", err), "NullPointer");
 
-    let err = TransErr::EucKrDecodeFailed;
-    assert_eq!(format!("{:?}", err), "EucKrDecodeFailed");
+    let err = TransErr::IncompleteInput;
+    assert_eq!(format!("{:?}", err), "IncompleteInput");
 }
 
 #[test]
@@ -86,6 +104,41 @@ fn test_eztrans_error_from_utf16_error() {
     }
 }
 
+#[test]
+fn test_transcode_error_display_and_accessors() {
+    let units = [b'a' as u16, b'b' as u16, 0xD800];
+    let err = eztrans_rs::utf16_decode::decode_strict(&units).unwrap_err();
+
+    assert_eq!(err.valid_up_to(), 2);
+    assert_eq!(err.invalid_unit(), 0xD800);
+    assert_eq!(err.valid_prefix(), "ab");
+    assert_eq!(
+        format!("{}", err),
+        "invalid UTF-16 at unit offset 2: unpaired surrogate 0xD800"
+    );
+}
+
+#[test]
+fn test_eztrans_error_from_transcode_error() {
+    let units = [0xDC00u16];
+    let transcode_err = eztrans_rs::utf16_decode::decode_strict(&units).unwrap_err();
+    let ez_err: EzTransError = transcode_err.into();
+
+    match ez_err {
+        EzTransError::TranscodeError(inner) => {
+            assert_eq!(inner.invalid_unit(), 0xDC00);
+        }
+        _ => panic!("Expected TranscodeError"),
+    }
+}
+
+#[test]
+fn test_transcode_error_into_valid_prefix() {
+    let units = [b'x' as u16, 0xD800];
+    let err: TranscodeError = eztrans_rs::utf16_decode::decode_strict(&units).unwrap_err();
+    assert_eq!(err.into_valid_prefix(), "x");
+}
+
 #[test]
 fn test_eztrans_error_debug() {
     let err = EzTransError::InvalidPath;
@@ -2,15 +2,20 @@
 // Tests EVERY valid Unicode codepoint (U+0000 to U+10FFFF)
 // Run with: cargo test --target i686-pc-windows-msvc --test full_unicode_scan -- --include-ignored --test-threads=1 --nocapture
 
+use eztrans_rs::translation_engine::TranslationEngine;
 use eztrans_rs::EzTransEngine;
 use serde::{Deserialize, Serialize};
 use std::env;
+use std::fmt;
 use std::fs::File;
-use std::io::{BufRead, BufReader, Write};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::PathBuf;
 use std::process::{Command, Stdio};
 use std::sync::mpsc;
 use std::thread;
 use std::time::{Duration, Instant};
+use thiserror::Error;
+use unicode_segmentation::UnicodeSegmentation;
 
 fn get_engine_paths() -> (String, String) {
     let manifest_dir = env!("CARGO_MANIFEST_DIR");
@@ -95,6 +100,162 @@ fn codepoint_to_absolute(code: u32) -> Option<u32> {
     None
 }
 
+// ============================================================================
+// 로깅 서브시스템
+// - 워커/코디네이터 진단이 println!/eprintln!에 점점이 흩어져 있어 레벨을 조절하거나
+//   나중에 어느 워커가 남긴 줄인지 구분하기 어려웠다. `ScanLogger`는 TRACE~ERROR
+//   레벨, worker_id+경과시간 자동 태깅, `UNICODE_SCAN_LOG_LEVEL` 환경 변수로 조절
+//   가능한 문턱값을 제공한다. 항상 stderr로만 내보내므로, 워커 stdout의
+//   `WorkerMessageV3` JSON 스트림(코디네이터가 `serde_json::from_str`로 파싱하는
+//   그 채널)과 절대 섞이지 않는다.
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    fn as_str(&self) -> &'static str {
+        match self {
+            LogLevel::Trace => "TRACE",
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Info => "INFO",
+            LogLevel::Warn => "WARN",
+            LogLevel::Error => "ERROR",
+        }
+    }
+
+    fn from_env_str(s: &str) -> Option<Self> {
+        match s.to_ascii_uppercase().as_str() {
+            "TRACE" => Some(LogLevel::Trace),
+            "DEBUG" => Some(LogLevel::Debug),
+            "INFO" => Some(LogLevel::Info),
+            "WARN" | "WARNING" => Some(LogLevel::Warn),
+            "ERROR" => Some(LogLevel::Error),
+            _ => None,
+        }
+    }
+}
+
+/// `UNICODE_SCAN_LOG_LEVEL` 환경 변수로 읽은 문턱값. 설정하지 않거나 알 수 없는
+/// 값이면 INFO로 기본값을 둔다.
+fn log_threshold() -> LogLevel {
+    env::var("UNICODE_SCAN_LOG_LEVEL")
+        .ok()
+        .and_then(|s| LogLevel::from_env_str(&s))
+        .unwrap_or(LogLevel::Info)
+}
+
+/// 로그 한 줄마다 `scope`/worker id/경과 시간을 자동으로 붙여 stderr에 내보내는
+/// 스코프. `scan_worker`/`coordinator`처럼 논리적인 영역 하나당 하나씩 만들어 두고
+/// `log.info(...)`/`log.warn(...)`을 부르면 호출부에서 매번 태그를 직접 조립할
+/// 필요가 없다.
+struct ScanLogger {
+    scope: &'static str,
+    worker_id: Option<usize>,
+    started_at: Instant,
+    threshold: LogLevel,
+}
+
+impl ScanLogger {
+    fn new(scope: &'static str, worker_id: Option<usize>, started_at: Instant) -> Self {
+        Self {
+            scope,
+            worker_id,
+            started_at,
+            threshold: log_threshold(),
+        }
+    }
+
+    /// 같은 scope/시작 시각을 공유하되 특정 worker id로 태깅하는 로거를 새로 만든다.
+    /// 코디네이터가 워커별 이벤트를 받아 찍을 때 쓴다.
+    fn for_worker(&self, worker_id: usize) -> Self {
+        Self {
+            scope: self.scope,
+            worker_id: Some(worker_id),
+            started_at: self.started_at,
+            threshold: self.threshold,
+        }
+    }
+
+    fn log(&self, level: LogLevel, message: &str) {
+        if level < self.threshold {
+            return;
+        }
+
+        let elapsed = self.started_at.elapsed().as_secs_f64();
+        match self.worker_id {
+            Some(id) => eprintln!(
+                "[{} {} worker={} +{:.3}s] {}",
+                level.as_str(),
+                self.scope,
+                id,
+                elapsed,
+                message
+            ),
+            None => eprintln!(
+                "[{} {} +{:.3}s] {}",
+                level.as_str(),
+                self.scope,
+                elapsed,
+                message
+            ),
+        }
+    }
+
+    fn debug(&self, message: impl AsRef<str>) {
+        self.log(LogLevel::Debug, message.as_ref());
+    }
+    fn info(&self, message: impl AsRef<str>) {
+        self.log(LogLevel::Info, message.as_ref());
+    }
+    fn warn(&self, message: impl AsRef<str>) {
+        self.log(LogLevel::Warn, message.as_ref());
+    }
+    fn error(&self, message: impl AsRef<str>) {
+        self.log(LogLevel::Error, message.as_ref());
+    }
+}
+
+#[cfg(test)]
+mod scan_log_tests {
+    use super::*;
+
+    #[test]
+    fn test_log_level_ordering_allows_threshold_comparison() {
+        assert!(LogLevel::Trace < LogLevel::Debug);
+        assert!(LogLevel::Debug < LogLevel::Info);
+        assert!(LogLevel::Info < LogLevel::Warn);
+        assert!(LogLevel::Warn < LogLevel::Error);
+    }
+
+    #[test]
+    fn test_from_env_str_is_case_insensitive() {
+        assert_eq!(LogLevel::from_env_str("debug"), Some(LogLevel::Debug));
+        assert_eq!(LogLevel::from_env_str("WARN"), Some(LogLevel::Warn));
+        assert_eq!(LogLevel::from_env_str("warning"), Some(LogLevel::Warn));
+    }
+
+    #[test]
+    fn test_from_env_str_rejects_unknown_values() {
+        assert_eq!(LogLevel::from_env_str("verbose"), None);
+    }
+
+    #[test]
+    fn test_for_worker_preserves_scope_and_threshold() {
+        let log = ScanLogger::new("coordinator", None, Instant::now());
+        let scoped = log.for_worker(3);
+        assert_eq!(scoped.scope, "coordinator");
+        assert_eq!(scoped.worker_id, Some(3));
+        assert_eq!(scoped.threshold, log.threshold);
+    }
+}
+
 // ============================================================================
 // 인코딩 검증 테스트 V3
 // - 원본 문자가 보존되는지 확인 (기존 로직)
@@ -102,12 +263,351 @@ fn codepoint_to_absolute(code: u32) -> Option<u32> {
 // ============================================================================
 
 /// 문제가 있는 문자 정보
+///
+/// 테스트 대상 코드포인트(`code`) 하나만 기록하면, 결합 문자(combining mark)가
+/// 앞 글자에 붙어 만드는 그래핌 클러스터나 BMP 밖 코드포인트(수학 알파벳 기호 등)를
+/// ezTrans가 "하나의 단위"로 뭉개버리는 경우를 제대로 설명할 수 없다. `cluster`
+/// 계열 필드는 `test_str`에서 `code`가 속한 그래핌 클러스터 전체를 가리킨다.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 struct ProblematicChar {
     code: u32,
+    /// `code`가 속한 그래핌 클러스터를 이루는 코드포인트 전체 (결합 문자 시퀀스면 2개 이상).
+    cluster_codepoints: Vec<u32>,
+    /// 그 클러스터가 `original` 문자열에서 시작하는 바이트 오프셋.
+    cluster_byte_offset: usize,
+    /// 클러스터 안에 BMP(U+0000..=U+FFFF) 밖 코드포인트가 하나라도 있는지.
+    is_astral: bool,
     original: String,
     translated: String,
-    issue_type: String, // "square_bracket", "question_mark", "different"
+    // "square_bracket", "question_mark", "different", "roundtrip_mismatch",
+    // "astral_or_combining" (BMP 밖 코드포인트나 결합 문자 클러스터가 문제를 일으킨 경우)
+    issue_type: String,
+}
+
+impl ProblematicChar {
+    /// `code`가 속한 그래핌 클러스터의 코드포인트를 문자열로 이어붙인 표시용 텍스트.
+    /// 결합 문자 클러스터는 단일 `char`로는 표현할 수 없으므로, CSV/Checkstyle처럼
+    /// "문자 하나"를 보여줘야 하는 출력은 이 값을 써야 한다.
+    fn cluster_display(&self) -> String {
+        self.cluster_codepoints
+            .iter()
+            .filter_map(|&cp| char::from_u32(cp))
+            .collect()
+    }
+}
+
+/// `haystack`에서 `target_byte_offset`에 있는 문자를 포함하는 그래핌 클러스터를 찾아,
+/// 그 클러스터의 (시작 바이트 오프셋, 구성 코드포인트 목록)을 반환한다.
+fn grapheme_cluster_at(haystack: &str, target_byte_offset: usize) -> (usize, Vec<u32>) {
+    for (offset, cluster) in haystack.grapheme_indices(true) {
+        if target_byte_offset >= offset && target_byte_offset < offset + cluster.len() {
+            return (offset, cluster.chars().map(|c| c as u32).collect());
+        }
+    }
+    (target_byte_offset, Vec::new())
+}
+
+// ============================================================================
+// 문제 문자 리포트 에미터
+// - CSV 작성 코드가 출력 포맷 하나만 하드코딩하고 있어, CI 대시보드/코드 리뷰
+//   도구가 이미 파싱할 줄 아는 JSON이나 Checkstyle-XML로 내보내고 싶어도 방법이
+//   없었다. `Emitter`로 포맷을 분리하고 `UNICODE_SCAN_REPORT_FORMAT` 환경 변수로
+//   고른다 (이 테스트 바이너리는 `cargo test`로 구동되어 커스텀 CLI 플래그를 받을
+//   수 없으므로, `UNICODE_SCAN_INPUT_FILE`/`UNICODE_SCAN_LOG_LEVEL`과 같은 방식의
+//   환경 변수 스위치를 쓴다).
+// ============================================================================
+
+/// 문제 문자 리포트를 쓰다가 날 수 있는 오류. 실패를 `.ok()`로 삼키고 stderr에
+/// 사람이 읽을 메시지만 찍어서는, 이 코드를 라이브러리처럼 쓰는 호출자가 부분 쓰기를
+/// 감지하거나 실패한 경로를 알아낼 방법이 없다. 이 모듈이 실제로 거치는 실패 경로는
+/// 파일 I/O뿐이라 변형도 하나뿐이다.
+#[derive(Error, Debug)]
+enum ReportError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// 문제 문자 목록을 특정 포맷으로 직렬화한다. 호출 순서는 항상
+/// `emit_header` → `emit_record`(문자 수만큼) → `emit_footer`.
+trait Emitter {
+    fn emit_header(&mut self, out: &mut dyn Write) -> Result<(), ReportError>;
+    fn emit_record(&mut self, out: &mut dyn Write, record: &ProblematicChar) -> Result<(), ReportError>;
+    fn emit_footer(&mut self, out: &mut dyn Write) -> Result<(), ReportError>;
+}
+
+struct CsvEmitter;
+
+impl Emitter for CsvEmitter {
+    fn emit_header(&mut self, out: &mut dyn Write) -> Result<(), ReportError> {
+        writeln!(out, "Code,Character,Original,Translated,IssueType")?;
+        Ok(())
+    }
+
+    fn emit_record(&mut self, out: &mut dyn Write, record: &ProblematicChar) -> Result<(), ReportError> {
+        let char_display = record.cluster_display();
+        let original_escaped = record.original.replace('"', "\"\"");
+        let translated_escaped = record.translated.replace('"', "\"\"");
+
+        writeln!(
+            out,
+            "U+{:06X},\"{}\",\"{}\",\"{}\",{}",
+            record.code, char_display, original_escaped, translated_escaped, record.issue_type
+        )?;
+        Ok(())
+    }
+
+    fn emit_footer(&mut self, _out: &mut dyn Write) -> Result<(), ReportError> {
+        Ok(())
+    }
+}
+
+/// 레코드 사이에 쉼표가 필요한지 추적하는 상태만 갖는 최소한의 JSON 배열 에미터.
+struct JsonEmitter {
+    wrote_first_record: bool,
+}
+
+impl JsonEmitter {
+    fn new() -> Self {
+        Self {
+            wrote_first_record: false,
+        }
+    }
+}
+
+impl Emitter for JsonEmitter {
+    fn emit_header(&mut self, out: &mut dyn Write) -> Result<(), ReportError> {
+        write!(out, "[")?;
+        Ok(())
+    }
+
+    fn emit_record(&mut self, out: &mut dyn Write, record: &ProblematicChar) -> Result<(), ReportError> {
+        if self.wrote_first_record {
+            write!(out, ",")?;
+        }
+        self.wrote_first_record = true;
+        let json = serde_json::to_string(record).expect("ProblematicChar always serializes");
+        write!(out, "{}", json)?;
+        Ok(())
+    }
+
+    fn emit_footer(&mut self, out: &mut dyn Write) -> Result<(), ReportError> {
+        writeln!(out, "]")?;
+        Ok(())
+    }
+}
+
+/// XML 속성 값으로 안전하게 쓸 수 있도록 `<`, `>`, `&`, `'`, `"`를 이스케이프한다.
+/// `&`를 제일 먼저 치환해야 나머지 치환이 만든 엔티티를 다시 깨뜨리지 않는다.
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('\'', "&apos;")
+        .replace('"', "&quot;")
+}
+
+/// 각 `ProblematicChar`를 `<file name=…><error .../></file>`로 감싸는 Checkstyle
+/// 4.3 포맷 에미터. CI 대시보드/코드 리뷰 도구가 이미 파싱할 줄 아는 포맷이다.
+struct CheckstyleEmitter;
+
+impl Emitter for CheckstyleEmitter {
+    fn emit_header(&mut self, out: &mut dyn Write) -> Result<(), ReportError> {
+        writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(out, r#"<checkstyle version="4.3">"#)?;
+        Ok(())
+    }
+
+    fn emit_record(&mut self, out: &mut dyn Write, record: &ProblematicChar) -> Result<(), ReportError> {
+        let char_display = record.cluster_display();
+        let message = format!(
+            "U+{:06X} ('{}'): {} -> {} [{}]",
+            record.code, char_display, record.original, record.translated, record.issue_type
+        );
+
+        writeln!(out, r#"  <file name="{}">"#, xml_escape(&format!("U+{:06X}", record.code)))?;
+        writeln!(
+            out,
+            r#"    <error line="1" severity="warning" message="{}"/>"#,
+            xml_escape(&message)
+        )?;
+        writeln!(out, "  </file>")?;
+        Ok(())
+    }
+
+    fn emit_footer(&mut self, out: &mut dyn Write) -> Result<(), ReportError> {
+        writeln!(out, "</checkstyle>")?;
+        Ok(())
+    }
+}
+
+/// 문제 문자 리포트 출력 포맷. `UNICODE_SCAN_REPORT_FORMAT` 환경 변수로 고른다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReportFormat {
+    Csv,
+    Json,
+    Checkstyle,
+}
+
+impl ReportFormat {
+    fn from_env() -> Self {
+        match env::var("UNICODE_SCAN_REPORT_FORMAT") {
+            Ok(s) if s.eq_ignore_ascii_case("json") => ReportFormat::Json,
+            Ok(s) if s.eq_ignore_ascii_case("checkstyle") => ReportFormat::Checkstyle,
+            _ => ReportFormat::Csv,
+        }
+    }
+
+    fn make_emitter(&self) -> Box<dyn Emitter> {
+        match self {
+            ReportFormat::Csv => Box::new(CsvEmitter),
+            ReportFormat::Json => Box::new(JsonEmitter::new()),
+            ReportFormat::Checkstyle => Box::new(CheckstyleEmitter),
+        }
+    }
+
+    fn default_path(&self) -> &'static str {
+        match self {
+            ReportFormat::Csv => "problematic_chars.csv",
+            ReportFormat::Json => "problematic_chars.json",
+            ReportFormat::Checkstyle => "problematic_chars_checkstyle.xml",
+        }
+    }
+}
+
+/// `records`를 `format`으로 직렬화해 `path`에 쓰고, 성공하면 실제로 쓴 경로를
+/// 돌려준다. 실패를 그냥 삼키고 출력해 버리는 대신 `ReportError`로 돌려주므로,
+/// 이 함수를 라이브러리처럼 호출하는 쪽이 부분 쓰기를 감지하거나 실패한 경로를 보고
+/// 자기 방식대로 로깅할 수 있다.
+fn write_problematic_chars_report(
+    records: &[ProblematicChar],
+    format: ReportFormat,
+    path: &str,
+) -> Result<PathBuf, ReportError> {
+    let mut emitter = format.make_emitter();
+    let mut file = File::create(path)?;
+    emitter.emit_header(&mut file)?;
+    for record in records {
+        emitter.emit_record(&mut file, record)?;
+    }
+    emitter.emit_footer(&mut file)?;
+    Ok(PathBuf::from(path))
+}
+
+#[cfg(test)]
+mod report_emitter_tests {
+    use super::*;
+
+    fn sample() -> ProblematicChar {
+        ProblematicChar {
+            code: 0x3042,
+            cluster_codepoints: vec![0x3042],
+            cluster_byte_offset: 0,
+            is_astral: false,
+            original: "あ\"test\"い".to_string(),
+            translated: "?".to_string(),
+            issue_type: "question_mark".to_string(),
+        }
+    }
+
+    fn run_emitter(mut emitter: impl Emitter, records: &[ProblematicChar]) -> String {
+        let mut buf: Vec<u8> = Vec::new();
+        emitter.emit_header(&mut buf).unwrap();
+        for record in records {
+            emitter.emit_record(&mut buf, record).unwrap();
+        }
+        emitter.emit_footer(&mut buf).unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn test_csv_emitter_escapes_embedded_quotes() {
+        let out = run_emitter(CsvEmitter, &[sample()]);
+        assert!(out.starts_with("Code,Character,Original,Translated,IssueType\n"));
+        assert!(out.contains("\"\"test\"\""));
+    }
+
+    #[test]
+    fn test_json_emitter_produces_valid_array_with_commas() {
+        let out = run_emitter(JsonEmitter::new(), &[sample(), sample()]);
+        let parsed: Vec<ProblematicChar> = serde_json::from_str(&out).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].code, 0x3042);
+    }
+
+    #[test]
+    fn test_json_emitter_handles_empty_record_list() {
+        let out = run_emitter(JsonEmitter::new(), &[]);
+        assert_eq!(out, "[]\n");
+    }
+
+    #[test]
+    fn test_checkstyle_emitter_escapes_xml_special_chars() {
+        let out = run_emitter(CheckstyleEmitter, &[sample()]);
+        assert!(out.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"));
+        assert!(out.contains(r#"<checkstyle version="4.3">"#));
+        assert!(out.contains("&quot;test&quot;"));
+        assert!(out.ends_with("</checkstyle>\n"));
+    }
+
+    #[test]
+    fn test_xml_escape_escapes_ampersand_before_generated_entities() {
+        assert_eq!(xml_escape("a & b < c > d ' e \" f"), "a &amp; b &lt; c &gt; d &apos; e &quot; f");
+    }
+
+    #[test]
+    fn test_report_format_from_env_defaults_to_csv() {
+        env::remove_var("UNICODE_SCAN_REPORT_FORMAT");
+        assert_eq!(ReportFormat::from_env(), ReportFormat::Csv);
+    }
+}
+
+#[cfg(test)]
+mod grapheme_cluster_tests {
+    use super::*;
+
+    #[test]
+    fn test_combining_mark_merges_with_preceding_base_char() {
+        // U+0300 COMBINING GRAVE ACCENT attaches to the preceding "あ".
+        let test_str = format!("あ{}い", '\u{0300}');
+        let combining_mark_offset = "あ".len(); // byte offset of the combining mark itself
+        let (offset, codepoints) = grapheme_cluster_at(&test_str, combining_mark_offset);
+        assert_eq!(offset, 0);
+        assert_eq!(codepoints, vec!['あ' as u32, 0x0300]);
+    }
+
+    #[test]
+    fn test_single_codepoint_cluster_has_exactly_one_codepoint() {
+        let test_str = format!("あ{}い", 'X');
+        let x_offset = "あ".len();
+        let (offset, codepoints) = grapheme_cluster_at(&test_str, x_offset);
+        assert_eq!(offset, x_offset);
+        assert_eq!(codepoints, vec!['X' as u32]);
+    }
+
+    #[test]
+    fn test_astral_codepoint_is_a_single_element_cluster() {
+        // U+1D5B3 MATHEMATICAL SANS-SERIF SMALL T is a single `char`, but lies outside the BMP.
+        let c = char::from_u32(0x1D5B3).unwrap();
+        let test_str = format!("あ{}い", c);
+        let c_offset = "あ".len();
+        let (_, codepoints) = grapheme_cluster_at(&test_str, c_offset);
+        assert_eq!(codepoints, vec![0x1D5B3]);
+        assert!(codepoints.iter().any(|&cp| cp > 0xFFFF));
+    }
+
+    #[test]
+    fn test_cluster_display_joins_codepoints_into_one_string() {
+        let prob_char = ProblematicChar {
+            code: 'あ' as u32,
+            cluster_codepoints: vec!['あ' as u32, 0x0300],
+            cluster_byte_offset: 0,
+            is_astral: false,
+            original: String::new(),
+            translated: String::new(),
+            issue_type: "astral_or_combining".to_string(),
+        };
+        assert_eq!(prob_char.cluster_display(), format!("あ{}", '\u{0300}'));
+    }
 }
 
 /// V3 워커에서 코디네이터로 보내는 메시지
@@ -121,7 +621,10 @@ enum WorkerMessageV3 {
     },
     ChunkResult {
         worker_id: usize,
-        safe_chars: Vec<u32>, // 안 깨지는 문자들
+        safe_chars: Vec<u32>, // 원본 그대로 안 깨지는 문자들
+        // hangul_encode → 번역 → hangul_decode 왕복을 거쳐야만 안 깨지는 문자들
+        // (원본째로는 안전하지 않지만 인코딩을 거치면 복원되는 문자)
+        roundtrip_safe_chars: Vec<u32>,
     },
     ProblematicChars {
         worker_id: usize,
@@ -146,7 +649,10 @@ fn send_message_v3(msg: &WorkerMessageV3) {
 }
 
 /// V3 워커 프로세스 - 안 깨지는 문자를 기록
-fn scan_worker_process_v3(
+///
+/// 번역 백엔드를 [`TranslationEngine`]으로 추상화해 두어, 실제 J2K DLL(`EzTransEngine`)
+/// 말고도 DLL 없이 동작하는 목 구현을 꽂아 같은 스캔 로직을 돌릴 수 있다.
+fn scan_worker_process_v3<E: TranslationEngine>(
     worker_id: usize,
     abs_start: u32,
     abs_end: u32,
@@ -154,10 +660,13 @@ fn scan_worker_process_v3(
     dat_path: &str,
 ) {
     let start_time = Instant::now();
+    let log = ScanLogger::new("scan_worker", Some(worker_id), start_time);
 
-    let engine = match EzTransEngine::new(dll_path) {
+    log.debug(format!("loading engine from {}", dll_path));
+    let engine = match E::load(dll_path) {
         Ok(e) => e,
         Err(err) => {
+            log.error(format!("failed to load DLL: {:?}", err));
             send_message_v3(&WorkerMessageV3::Error {
                 worker_id,
                 message: format!("Failed to load DLL: {:?}", err),
@@ -167,15 +676,22 @@ fn scan_worker_process_v3(
     };
 
     if let Err(err) = engine.initialize_ex("CSUSER123455", dat_path) {
+        log.error(format!("failed to initialize engine: {:?}", err));
         send_message_v3(&WorkerMessageV3::Error {
             worker_id,
             message: format!("Failed to initialize engine: {:?}", err),
         });
         return;
     }
+    log.info(format!(
+        "engine initialized, scanning abs range {}..={}",
+        abs_start, abs_end
+    ));
 
     let mut safe_chars = Vec::new(); // 번역 시 안 깨지는 문자
     let mut pending_safe = Vec::new();
+    let mut roundtrip_safe_chars = Vec::new(); // 인코딩 왕복을 거치면 안 깨지는 문자
+    let mut pending_roundtrip_safe = Vec::new();
     let mut problematic_chars = Vec::new(); // 문제가 있는 문자들
     let mut pending_problematic = Vec::new();
     let mut total_tested = 0u32;
@@ -185,6 +701,9 @@ fn scan_worker_process_v3(
     const CHUNK_SIZE: usize = 1000;
     const PROGRESS_INTERVAL_MS: u64 = 500;
     const CHUNK_INTERVAL_SECS: u64 = 5;
+    // 일시적인 DLL hiccup 한두 번으로 멀쩡한 문자가 problematic_chars에 잘못
+    // 기록되지 않도록, translate_mmntw를 바로 실패로 치지 않고 이만큼 재시도한다.
+    const MAX_TRANSLATE_RETRIES: u32 = 2;
 
     /// 한글인지 체크하는 함수
     fn is_korean(s: &str) -> bool {
@@ -210,8 +729,16 @@ fn scan_worker_process_v3(
         if let Some(c) = char::from_u32(code) {
             let test_str = format!("あ{}い", c);
 
-            // 원본 문자가 "?"로 변경되었는지 확인
-            let result = engine.translate_mmntw(&test_str);
+            // `c`가 속한 그래핌 클러스터 (결합 문자가 "あ"에 들러붙으면 2개 이상의
+            // 코드포인트가 한 클러스터를 이룬다). BMP 밖 코드포인트나 결합 클러스터는
+            // 단일 `char`로 다룰 수 없는 별도 실패 유형으로 표시한다.
+            let (cluster_byte_offset, cluster_codepoints) =
+                grapheme_cluster_at(&test_str, "あ".len());
+            let is_astral = cluster_codepoints.iter().any(|&cp| cp > 0xFFFF);
+            let is_special_cluster = is_astral || cluster_codepoints.len() > 1;
+
+            // 원본 문자가 "?"로 변경되었는지 확인 (재시도 끝에도 실패해야 진짜 불안전으로 판정)
+            let result = engine.translate_and_confirm(&test_str, MAX_TRANSLATE_RETRIES);
             let mut is_safe = true;
             let mut issue_type = None;
 
@@ -238,9 +765,16 @@ fn scan_worker_process_v3(
                         if let Some(issue) = issue_type {
                             let prob_char = ProblematicChar {
                                 code,
+                                cluster_codepoints: cluster_codepoints.clone(),
+                                cluster_byte_offset,
+                                is_astral,
                                 original: test_str.clone(),
                                 translated: translated.clone(),
-                                issue_type: issue.to_string(),
+                                issue_type: if is_special_cluster {
+                                    "astral_or_combining".to_string()
+                                } else {
+                                    issue.to_string()
+                                },
                             };
                             problematic_chars.push(prob_char.clone());
                             pending_problematic.push(prob_char);
@@ -255,6 +789,54 @@ fn scan_worker_process_v3(
             if is_safe {
                 safe_chars.push(code);
                 pending_safe.push(code);
+            } else {
+                // 원본째로는 안 깨지지 않았으니, 헤더 주석이 원래 약속한 대로
+                // hangul_encode → 번역 → hangul_decode 왕복 후 원본과 같은 문자가
+                // 돌아오는지 확인한다. 인코딩을 거치면 복원되는 문자와, 그래도
+                // 복원되지 않는 진짜로 깨지는 문자를 구분하기 위함이다.
+                let encoded = engine.hangul_encode(&test_str);
+                match engine.translate_and_confirm(&encoded, MAX_TRANSLATE_RETRIES) {
+                    Ok(translated_encoded) => {
+                        let recovered = engine.hangul_decode(&translated_encoded);
+                        if recovered.contains(c) {
+                            roundtrip_safe_chars.push(code);
+                            pending_roundtrip_safe.push(code);
+                        } else {
+                            let prob_char = ProblematicChar {
+                                code,
+                                cluster_codepoints: cluster_codepoints.clone(),
+                                cluster_byte_offset,
+                                is_astral,
+                                original: test_str.clone(),
+                                translated: recovered,
+                                issue_type: if is_special_cluster {
+                                    "astral_or_combining".to_string()
+                                } else {
+                                    "roundtrip_mismatch".to_string()
+                                },
+                            };
+                            problematic_chars.push(prob_char.clone());
+                            pending_problematic.push(prob_char);
+                        }
+                    }
+                    Err(_) => {
+                        let prob_char = ProblematicChar {
+                            code,
+                            cluster_codepoints: cluster_codepoints.clone(),
+                            cluster_byte_offset,
+                            is_astral,
+                            original: test_str.clone(),
+                            translated: String::new(),
+                            issue_type: if is_special_cluster {
+                                "astral_or_combining".to_string()
+                            } else {
+                                "roundtrip_mismatch".to_string()
+                            },
+                        };
+                        problematic_chars.push(prob_char.clone());
+                        pending_problematic.push(prob_char);
+                    }
+                }
             }
         }
 
@@ -271,14 +853,17 @@ fn scan_worker_process_v3(
 
         // 청크 결과 전송
         if pending_safe.len() >= CHUNK_SIZE
+            || pending_roundtrip_safe.len() >= CHUNK_SIZE
             || (last_chunk_send.elapsed() >= Duration::from_secs(CHUNK_INTERVAL_SECS)
-                && !pending_safe.is_empty())
+                && (!pending_safe.is_empty() || !pending_roundtrip_safe.is_empty()))
         {
             send_message_v3(&WorkerMessageV3::ChunkResult {
                 worker_id,
                 safe_chars: pending_safe.clone(),
+                roundtrip_safe_chars: pending_roundtrip_safe.clone(),
             });
             pending_safe.clear();
+            pending_roundtrip_safe.clear();
             last_chunk_send = Instant::now();
         }
 
@@ -296,10 +881,11 @@ fn scan_worker_process_v3(
     }
 
     // 남은 청크 전송
-    if !pending_safe.is_empty() {
+    if !pending_safe.is_empty() || !pending_roundtrip_safe.is_empty() {
         send_message_v3(&WorkerMessageV3::ChunkResult {
             worker_id,
             safe_chars: pending_safe,
+            roundtrip_safe_chars: pending_roundtrip_safe,
         });
     }
 
@@ -311,6 +897,13 @@ fn scan_worker_process_v3(
     }
 
     let elapsed = start_time.elapsed();
+    log.info(format!(
+        "done: tested={} safe={} problematic={} in {:.2}s",
+        total_tested,
+        safe_chars.len(),
+        problematic_chars.len(),
+        elapsed.as_secs_f64()
+    ));
 
     send_message_v3(&WorkerMessageV3::Complete {
         worker_id,
@@ -333,12 +926,217 @@ fn unicode_scan_worker_v3() {
             let dll_path = parts[3];
             let dat_path = parts[4];
 
-            scan_worker_process_v3(worker_id, abs_start, abs_end, dll_path, dat_path);
+            scan_worker_process_v3::<EzTransEngine>(worker_id, abs_start, abs_end, dll_path, dat_path);
             std::process::exit(0);
         }
     }
 }
 
+// ============================================================================
+// 파일 입력 모드
+// - 합성 코드포인트 대신 실제 번역 소스 파일을 읽어 줄 단위로 검증
+// - 선두 BOM으로 인코딩을 자동 감지 (UTF-16 입력은 String으로 트랜스코딩)
+// ============================================================================
+
+/// [`read_source_with_bom`]이 감지한 입력 인코딩.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum SourceEncoding {
+    /// 선두에 `EF BB BF`가 있었다.
+    Utf8Bom,
+    /// 선두에 `FF FE`가 있었다.
+    Utf16Le,
+    /// 선두에 `FE FF`가 있었다.
+    Utf16Be,
+    /// BOM이 없어 UTF-8로 간주했다.
+    Utf8,
+}
+
+impl fmt::Display for SourceEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            SourceEncoding::Utf8Bom => "UTF-8 (BOM)",
+            SourceEncoding::Utf16Le => "UTF-16 LE",
+            SourceEncoding::Utf16Be => "UTF-16 BE",
+            SourceEncoding::Utf8 => "UTF-8 (BOM 없음)",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// `bytes`의 선두 BOM을 보고 인코딩을 감지해 `String`으로 디코딩한다.
+///
+/// `EF BB BF` → UTF-8, `FF FE` → UTF-16 LE, `FE FF` → UTF-16 BE 순으로 확인하고,
+/// 셋 다 아니면 UTF-8로 가정한다. BOM 바이트 자체는 디코딩 결과(첫 줄)에 남지 않도록
+/// 감지한 BOM만큼 건너뛰고 디코딩한다.
+fn read_source_with_bom(bytes: &[u8]) -> (String, SourceEncoding) {
+    if let Some(rest) = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]) {
+        (
+            encoding_rs::UTF_8.decode(rest).0.into_owned(),
+            SourceEncoding::Utf8Bom,
+        )
+    } else if let Some(rest) = bytes.strip_prefix(&[0xFF, 0xFE]) {
+        (
+            encoding_rs::UTF_16LE.decode(rest).0.into_owned(),
+            SourceEncoding::Utf16Le,
+        )
+    } else if let Some(rest) = bytes.strip_prefix(&[0xFE, 0xFF]) {
+        (
+            encoding_rs::UTF_16BE.decode(rest).0.into_owned(),
+            SourceEncoding::Utf16Be,
+        )
+    } else {
+        (
+            encoding_rs::UTF_8.decode(bytes).0.into_owned(),
+            SourceEncoding::Utf8,
+        )
+    }
+}
+
+/// 번역 후 "□"나, 원본에 없던 "?"가 섞여 들어간 줄 하나.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct FileTranslationIssue {
+    line_number: usize,
+    original: String,
+    translated: String,
+}
+
+/// [`scan_file_input_v1`] 한 번 실행의 요약. 호출자가 파일이 어떤 인코딩으로
+/// 해석됐는지 확인할 수 있도록 `detected_encoding`을 그대로 담아 둔다.
+#[derive(Debug, Serialize, Deserialize)]
+struct FileScanSummary {
+    detected_encoding: String,
+    total_lines: usize,
+    issues: Vec<FileTranslationIssue>,
+}
+
+/// `path`의 번역 소스 파일을 읽어 인코딩을 자동 감지한 뒤, 줄 단위로 번역해 깨진
+/// 줄을 모아 리포트한다.
+fn scan_file_with_detected_encoding<E: TranslationEngine>(
+    engine: &E,
+    path: &str,
+) -> std::io::Result<FileScanSummary> {
+    let mut bytes = Vec::new();
+    File::open(path)?.read_to_end(&mut bytes)?;
+
+    let (text, detected_encoding) = read_source_with_bom(&bytes);
+
+    let mut issues = Vec::new();
+    let mut total_lines = 0usize;
+
+    for (idx, line) in text.lines().enumerate() {
+        total_lines += 1;
+        if line.is_empty() {
+            continue;
+        }
+
+        match engine.translate_and_confirm(line, 2) {
+            Ok(translated) => {
+                let gained_question_mark = !line.contains('?') && translated.contains('?');
+                if translated.contains('□') || gained_question_mark {
+                    issues.push(FileTranslationIssue {
+                        line_number: idx + 1,
+                        original: line.to_string(),
+                        translated,
+                    });
+                }
+            }
+            Err(err) => {
+                issues.push(FileTranslationIssue {
+                    line_number: idx + 1,
+                    original: line.to_string(),
+                    translated: format!("<translate failed: {:?}>", err),
+                });
+            }
+        }
+    }
+
+    Ok(FileScanSummary {
+        detected_encoding: detected_encoding.to_string(),
+        total_lines,
+        issues,
+    })
+}
+
+/// 파일 입력 모드 전용 테스트. `UNICODE_SCAN_INPUT_FILE` 환경 변수로 가리키는 파일을
+/// 읽어 줄 단위로 번역을 검증하고, 감지된 인코딩과 깨진 줄 목록을 보고한다. 에디터가
+/// 어떤 BOM으로 파일을 저장했든 상관없이 같은 회귀 테스트를 돌릴 수 있다.
+///
+/// Run with: `UNICODE_SCAN_INPUT_FILE=path/to/source.txt cargo test --test full_unicode_scan -- --ignored --nocapture scan_file_input_v1`
+#[test]
+#[ignore]
+fn scan_file_input_v1() {
+    let Ok(input_path) = env::var("UNICODE_SCAN_INPUT_FILE") else {
+        eprintln!("UNICODE_SCAN_INPUT_FILE 환경 변수가 설정되지 않아 건너뜁니다.");
+        return;
+    };
+
+    let (dll_path, dat_path) = get_engine_paths();
+    let engine = EzTransEngine::new(&dll_path).expect("Failed to load DLL");
+    engine
+        .initialize_ex("CSUSER123455", &dat_path)
+        .expect("Failed to initialize engine");
+
+    let summary =
+        scan_file_with_detected_encoding(&engine, &input_path).expect("Failed to read input file");
+
+    println!("Detected encoding: {}", summary.detected_encoding);
+    println!("Total lines: {}", summary.total_lines);
+    println!("Issues found: {}", summary.issues.len());
+
+    for issue in &summary.issues {
+        println!(
+            "  line {}: {:?} -> {:?}",
+            issue.line_number, issue.original, issue.translated
+        );
+    }
+
+    let json = serde_json::to_string_pretty(&summary).unwrap();
+    std::fs::write("file_scan_results.json", json).ok();
+}
+
+#[cfg(test)]
+mod file_input_tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_utf8_bom() {
+        let mut bytes = vec![0xEF, 0xBB, 0xBF];
+        bytes.extend_from_slice("안녕".as_bytes());
+        let (text, encoding) = read_source_with_bom(&bytes);
+        assert_eq!(text, "안녕");
+        assert_eq!(encoding, SourceEncoding::Utf8Bom);
+    }
+
+    #[test]
+    fn test_detects_utf16_le_bom() {
+        let (text, encoding) = read_source_with_bom(&[0xFF, 0xFE, 0x41, 0x00]);
+        assert_eq!(text, "A");
+        assert_eq!(encoding, SourceEncoding::Utf16Le);
+    }
+
+    #[test]
+    fn test_detects_utf16_be_bom() {
+        let (text, encoding) = read_source_with_bom(&[0xFE, 0xFF, 0x00, 0x41]);
+        assert_eq!(text, "A");
+        assert_eq!(encoding, SourceEncoding::Utf16Be);
+    }
+
+    #[test]
+    fn test_no_bom_assumes_utf8() {
+        let (text, encoding) = read_source_with_bom("hello".as_bytes());
+        assert_eq!(text, "hello");
+        assert_eq!(encoding, SourceEncoding::Utf8);
+    }
+
+    #[test]
+    fn test_bom_does_not_leak_into_first_line() {
+        let mut bytes = vec![0xEF, 0xBB, 0xBF];
+        bytes.extend_from_slice("first\nsecond".as_bytes());
+        let (text, _) = read_source_with_bom(&bytes);
+        assert_eq!(text.lines().next(), Some("first"));
+    }
+}
+
 #[derive(Debug)]
 enum CoordinatorMessageV3 {
     WorkerMessage {
@@ -470,6 +1268,7 @@ fn scan_multiprocess_v3(num_processes_opt: Option<usize>) {
     println!();
 
     let overall_start_time = Instant::now();
+    let log = ScanLogger::new("coordinator", None, overall_start_time);
     let current_exe = env::current_exe().expect("Failed to get current exe path");
 
     let (tx, rx) = mpsc::channel::<CoordinatorMessageV3>();
@@ -479,6 +1278,7 @@ fn scan_multiprocess_v3(num_processes_opt: Option<usize>) {
         (0..num_processes).map(|_| WorkerStatusV3::new()).collect();
     let mut workers_completed = 0usize;
     let mut all_safe_chars: Vec<u32> = Vec::new(); // 안 깨지는 문자들
+    let mut all_roundtrip_safe_chars: Vec<u32> = Vec::new(); // 인코딩 왕복으로만 안 깨지는 문자들
     let mut all_problematic_chars: Vec<ProblematicChar> = Vec::new(); // 문제가 있는 문자들
     let mut total_tested = 0u32;
 
@@ -531,10 +1331,11 @@ fn scan_multiprocess_v3(num_processes_opt: Option<usize>) {
                 });
 
                 reader_threads.push(reader_thread);
+                log.for_worker(*worker_id).debug("spawned");
                 println!("Spawned worker {}", worker_id);
             }
             Err(e) => {
-                eprintln!("Failed to spawn worker {}: {}", worker_id, e);
+                log.for_worker(*worker_id).error(format!("failed to spawn: {}", e));
                 workers_completed += 1;
             }
         }
@@ -561,8 +1362,13 @@ fn scan_multiprocess_v3(num_processes_opt: Option<usize>) {
                             worker_statuses[worker_id].found_safe = found_safe;
                         }
                     }
-                    WorkerMessageV3::ChunkResult { safe_chars, .. } => {
+                    WorkerMessageV3::ChunkResult {
+                        safe_chars,
+                        roundtrip_safe_chars,
+                        ..
+                    } => {
                         all_safe_chars.extend(safe_chars);
+                        all_roundtrip_safe_chars.extend(roundtrip_safe_chars);
                     }
                     WorkerMessageV3::ProblematicChars { chars, .. } => {
                         all_problematic_chars.extend(chars);
@@ -584,14 +1390,14 @@ fn scan_multiprocess_v3(num_processes_opt: Option<usize>) {
                         worker_id: wid,
                         message,
                     } => {
-                        eprintln!("\n[Worker {}] Error: {}", wid, message);
+                        log.for_worker(wid).error(&message);
                     }
                 },
                 CoordinatorMessageV3::WorkerEof { .. } => {
                     workers_completed += 1;
                 }
                 CoordinatorMessageV3::WorkerError { worker_id, error } => {
-                    eprintln!("\n[Worker {}] Read error: {}", worker_id, error);
+                    log.for_worker(worker_id).error(format!("read error: {}", error));
                     workers_completed += 1;
                 }
             },
@@ -679,12 +1485,37 @@ fn scan_multiprocess_v3(num_processes_opt: Option<usize>) {
     println!("    )");
     println!("}}");
 
-    // needs_special_encoding은 반대
-    println!("\n=== USAGE ===\n");
-    println!("// needs_special_encoding은 is_safe_char의 반대:");
+    // needs_special_encoding - 원본째로는 안전하지 않지만 hangul_encode → 번역 →
+    // hangul_decode 왕복을 거치면 복원되는 문자들 (is_safe_char와 달리 "안 깨지는"
+    // 문자가 아니라 "인코딩을 거쳐야 안 깨지는" 문자만 담는다)
+    all_roundtrip_safe_chars.sort_unstable();
+    all_roundtrip_safe_chars.dedup();
+
+    let safe_codes: std::collections::HashSet<u32> = all_safe_chars.iter().copied().collect();
+    let encoding_safe_chars: Vec<char> = all_roundtrip_safe_chars
+        .iter()
+        .filter(|code| !safe_codes.contains(code))
+        .filter_map(|&code| char::from_u32(code))
+        .collect();
+    let encoding_ranges = find_continuous_ranges(&encoding_safe_chars);
+
+    println!(
+        "\n=== GENERATED RUST CODE (needs encoding - only safe via hangul_encode/hangul_decode) ===\n"
+    );
     println!("#[inline]");
     println!("pub const fn needs_special_encoding(c: char) -> bool {{");
-    println!("    !is_safe_char(c)");
+    println!("    let code = c as u32;");
+    println!("    matches!(code,");
+
+    for (start, end) in &encoding_ranges {
+        if start == end {
+            println!("        0x{:06X} |", start);
+        } else {
+            println!("        0x{:06X}..=0x{:06X} |", start, end);
+        }
+    }
+
+    println!("    )");
     println!("}}");
 
     // 결과 저장
@@ -695,6 +1526,10 @@ fn scan_multiprocess_v3(num_processes_opt: Option<usize>) {
     output.push_str(&format!("Total time: {}\n", format_duration(total_elapsed)));
     output.push_str(&format!("Total tested: {}\n", total_tested));
     output.push_str(&format!("Safe: {}\n", safe_chars.len()));
+    output.push_str(&format!(
+        "Needs encoding (roundtrip-safe only): {}\n",
+        encoding_safe_chars.len()
+    ));
     output.push_str(&format!("Corrupted: {}\n\n", total_corrupted));
 
     output.push_str("Safe characters (don't need encoding):\n");
@@ -714,43 +1549,19 @@ fn scan_multiprocess_v3(num_processes_opt: Option<usize>) {
     std::fs::write(output_path, output).ok();
     println!("\nResults saved to: {}", output_path);
 
-    // CSV 파일에 문제가 있는 문자 저장
+    // 문제가 있는 문자 리포트 저장 (포맷은 UNICODE_SCAN_REPORT_FORMAT으로 선택)
     if !all_problematic_chars.is_empty() {
-        let csv_path = "problematic_chars.csv";
-        match File::create(csv_path) {
-            Ok(mut file) => {
-                // CSV 헤더
-                writeln!(file, "Code,Character,Original,Translated,IssueType").ok();
-
-                // 문제가 있는 문자들 정렬 (코드 순)
-                all_problematic_chars.sort_by_key(|c| c.code);
-
-                // CSV 데이터 작성
-                for prob_char in &all_problematic_chars {
-                    let char_display = char::from_u32(prob_char.code)
-                        .map(|c| format!("{}", c))
-                        .unwrap_or_else(|| "N/A".to_string());
-
-                    // CSV 이스케이프 처리
-                    let original_escaped = prob_char.original.replace('"', "\"\"");
-                    let translated_escaped = prob_char.translated.replace('"', "\"\"");
-
-                    writeln!(
-                        file,
-                        "U+{:06X},\"{}\",\"{}\",\"{}\",{}",
-                        prob_char.code,
-                        char_display,
-                        original_escaped,
-                        translated_escaped,
-                        prob_char.issue_type
-                    ).ok();
-                }
+        all_problematic_chars.sort_by_key(|c| c.code);
 
-                println!("\nProblematic characters saved to: {}", csv_path);
+        let report_format = ReportFormat::from_env();
+        let report_path = report_format.default_path();
+        match write_problematic_chars_report(&all_problematic_chars, report_format, report_path) {
+            Ok(path) => {
+                println!("\nProblematic characters saved to: {}", path.display());
                 println!("Total problematic characters: {}", all_problematic_chars.len());
             }
             Err(e) => {
-                eprintln!("\nFailed to create CSV file: {}", e);
+                log.error(format!("failed to write {:?} report: {}", report_format, e));
             }
         }
     }
@@ -0,0 +1,50 @@
+// Glossary Tests
+
+use eztrans_rs::glossary::Glossary;
+
+#[test]
+fn test_protect_replaces_known_term_with_sentinel() {
+    let glossary = Glossary::parse("ドル\tGOLD\n");
+    let protected = glossary.protect("ドルを手に入れた");
+    assert!(!protected.contains("ドル"));
+}
+
+#[test]
+fn test_protect_restore_round_trip() {
+    let glossary = Glossary::parse("ドル\t골드\n剣\t검");
+    let protected = glossary.protect("ドルと剣を手に入れた");
+    let restored = glossary.restore(&protected);
+    assert_eq!(restored, "골드と검を手に入れた");
+}
+
+#[test]
+fn test_protect_restore_exact_round_trip() {
+    let glossary = Glossary::parse("ドル\t골드");
+    let protected = glossary.protect("ドルを手に入れた");
+    let restored = glossary.restore(&protected);
+    assert_eq!(restored, "골드を手に入れた");
+}
+
+#[test]
+fn test_longest_match_wins_over_shorter_prefix() {
+    let glossary = Glossary::parse("剣\t검\n剣士\t검사");
+    let protected = glossary.protect("剣士だ");
+    let restored = glossary.restore(&protected);
+    assert_eq!(restored, "검사だ");
+}
+
+#[test]
+fn test_no_terms_is_identity() {
+    let glossary = Glossary::parse("");
+    let input = "そのまま";
+    assert_eq!(glossary.protect(input), input);
+    assert_eq!(glossary.restore(input), input);
+}
+
+#[test]
+fn test_blank_and_malformed_lines_are_ignored() {
+    let glossary = Glossary::parse("\n\tno-source\nドル\t골드\nno-tab-here\n");
+    let protected = glossary.protect("ドルだけ");
+    let restored = glossary.restore(&protected);
+    assert_eq!(restored, "골드だけ");
+}
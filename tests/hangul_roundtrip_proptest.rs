@@ -0,0 +1,167 @@
+// Property-based round-trip verification for the encoding layer.
+//
+// `tests/full_unicode_scan.rs`/`tests/char_optimization.rs` only ever check a fixed
+// sample of codepoints and a one-shot scan against whatever `J2KEngine.dll` happens to
+// do today; a case that only breaks when several codepoints combine in an unusual order
+// (long runs, mixed scripts, a code point sitting right on a boundary) can slip past
+// both. `proptest` generates arbitrary inputs against the same invariants, shrinks any
+// failure down to a minimal reproducer, and persists it under
+// `tests/proptest-regressions/hangul_roundtrip_proptest.txt` so every future run replays
+// it first — turning a one-off scan into a corpus that only grows.
+//
+// Run with:
+//   cargo test --test hangul_roundtrip_proptest
+//   cargo test --target i686-pc-windows-msvc --test hangul_roundtrip_proptest -- --include-ignored --test-threads=1
+
+use eztrans_rs::utf16_decode::{decode_lossy, decode_strict};
+use eztrans_rs::EzTransEngine;
+use proptest::prelude::*;
+use std::sync::Mutex;
+
+fn get_engine_paths() -> (String, String) {
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let dll_path = format!("{}/../eztrans_dll/J2KEngine.dll", manifest_dir);
+    let dat_path = format!("{}/../eztrans_dll/Dat", manifest_dir);
+    (dll_path, dat_path)
+}
+
+struct EngineWrapper(EzTransEngine);
+unsafe impl Send for EngineWrapper {}
+unsafe impl Sync for EngineWrapper {}
+
+static ENGINE: Mutex<Option<EngineWrapper>> = Mutex::new(None);
+
+fn with_engine<F, R>(f: F) -> R
+where
+    F: FnOnce(&EzTransEngine) -> R,
+{
+    let mut guard = ENGINE.lock().unwrap();
+    if guard.is_none() {
+        let (dll_path, dat_path) = get_engine_paths();
+        let engine = EzTransEngine::new(&dll_path).expect("Failed to load DLL");
+        engine
+            .initialize_ex("CSUSER123455", &dat_path)
+            .expect("Failed to initialize engine");
+        *guard = Some(EngineWrapper(engine));
+    }
+    f(&guard.as_ref().unwrap().0)
+}
+
+/// 경계값(서로게이트 바로 옆, BMP 끝, 아스트랄 평면 시작/끝), 일반 문자, 긴 반복 구간을
+/// 섞어 임의의 `char` 시퀀스를 만든다.
+fn arb_chars() -> impl Strategy<Value = Vec<char>> {
+    let boundary = prop_oneof![
+        Just('\u{0}'),
+        Just('\u{7F}'),
+        Just('\u{D7FF}'), // 서로게이트 바로 앞
+        Just('\u{E000}'), // 서로게이트 바로 뒤 (사설 영역 시작)
+        Just('\u{FFFD}'),
+        Just('\u{FFFF}'),   // BMP 끝
+        Just('\u{10000}'),  // 아스트랄 평면 시작
+        Just('\u{10FFFF}'), // 유니코드 최대값
+        Just('가'),         // 한글 음절 시작(U+AC00)
+        Just('힣'),         // 한글 음절 끝(U+D7A3)
+        Just('あ'),
+        Just('漢'),
+        Just('👨'),
+        Just('\u{0301}'), // 결합 급강세 악센트
+        Just('\u{200D}'), // ZWJ
+    ];
+    let any_char = prop_oneof![3 => any::<char>(), 1 => boundary];
+    prop::collection::vec(any_char, 0..64)
+}
+
+proptest! {
+    // 서로게이트 쌍/홀로 남은 서로게이트(u16 코드 유닛 단계에서만 의미가 있어 Rust
+    // `char`로는 표현할 수 없는 값)를 직접 다루는 것은 `utf16_decode` 모듈의 몫이라,
+    // 엔진 없이도 돌릴 수 있는 순수 함수 속성 테스트로 검증한다.
+    #[test]
+    fn decode_strict_round_trips_any_valid_char_sequence(chars in arb_chars()) {
+        let text: String = chars.into_iter().collect();
+        let units: Vec<u16> = text.encode_utf16().collect();
+
+        let decoded = decode_strict(&units).expect("valid UTF-16 units must decode");
+        prop_assert_eq!(decoded, text);
+    }
+
+    #[test]
+    fn decode_lossy_never_panics_and_agrees_with_decode_strict(
+        units in prop::collection::vec(any::<u16>(), 0..64)
+    ) {
+        let (lossy, offsets) = decode_lossy(&units);
+
+        match decode_strict(&units) {
+            Ok(strict) => {
+                prop_assert_eq!(lossy, strict);
+                prop_assert!(offsets.is_empty());
+            }
+            Err(_) => {
+                // 서로게이트가 깨진 지점이 최소 하나는 있어야 한다.
+                prop_assert!(!offsets.is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn decode_strict_reports_valid_prefix_up_to_lone_high_surrogate(
+        prefix in prop::collection::vec(0x0020u16..0x007F, 0..16)
+    ) {
+        let mut units = prefix.clone();
+        units.push(0xD800); // 짝이 없는 상위 서로게이트
+
+        let err = decode_strict(&units).expect_err("lone high surrogate must fail");
+        prop_assert_eq!(err.valid_up_to(), prefix.len());
+        prop_assert_eq!(err.invalid_unit(), 0xD800);
+        let expected_prefix: String = prefix.iter().map(|&u| u as u8 as char).collect();
+        prop_assert_eq!(err.valid_prefix(), expected_prefix.as_str());
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    #[ignore]
+    fn hangul_encode_decode_round_trips_arbitrary_unicode(chars in arb_chars()) {
+        let text: String = chars.into_iter().collect();
+        with_engine(|engine| {
+            let encoded = engine.hangul_encode(&text);
+            prop_assert_eq!(engine.hangul_decode(&encoded), text.clone());
+
+            let encoded_clusters = engine.hangul_encode_clusters(&text);
+            prop_assert_eq!(engine.hangul_decode(&encoded_clusters), text);
+            Ok(())
+        })?;
+    }
+
+    /// 아스키/가나/한자로만 이뤄진 문자열(이 중 이스케이프되는 것은 `@`뿐)이라면, `hangul_encode`류를
+    /// 거친 번역 결과를 되돌린 것이 엔진에 직접 보낸 번역 결과와 같아야 한다 — 인코딩
+    /// 계층이 안전한 텍스트를 조용히 바꿔 버리는 회귀를 잡아낸다.
+    #[test]
+    #[ignore]
+    fn encoded_translation_matches_direct_translation_for_safe_text(
+        chars in prop::collection::vec(
+            prop_oneof![
+                (0x0020u32..0x007F).prop_map(|c| char::from_u32(c).unwrap()),
+                Just('あ'),
+                Just('ん'),
+                Just('漢'),
+                Just('字'),
+            ],
+            1..32,
+        )
+    ) {
+        let text: String = chars.into_iter().collect();
+        with_engine(|engine| {
+            let direct = engine.translate_mmntw(&text);
+            let via_encoding = engine
+                .translate_mmntw(&engine.hangul_encode_clusters(&text))
+                .map(|translated| engine.hangul_decode(&translated));
+
+            if let (Ok(direct), Ok(via_encoding)) = (direct, via_encoding) {
+                prop_assert_eq!(direct, via_encoding);
+            }
+            Ok(())
+        })?;
+    }
+}
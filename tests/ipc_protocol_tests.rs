@@ -28,28 +28,17 @@ fn test_initialize_request_size() {
 }
 
 #[test]
-fn test_translate_mmnt_request_size() {
-    // 4 bytes (data0) + 4096 bytes (text)
-    assert_eq!(size_of::<TranslateMMNTRequest>(), 4100);
+fn test_translate_request_header_size() {
+    // 4 bytes (data0); the variable-length text follows separately in the
+    // wire payload, sized by MessageHeader::payload_size.
+    assert_eq!(size_of::<TranslateRequestHeader>(), 4);
 }
 
 #[test]
-fn test_translate_mmnt_response_size() {
-    // 4 bytes (status) + 4 bytes (result_code) + 4096 bytes (translated)
-    assert_eq!(size_of::<TranslateMMNTResponse>(), 4104);
-}
-
-#[test]
-fn test_translate_mmntw_request_size() {
-    // 4 bytes (data0) + 4096 * 2 bytes (UTF-16 text) = 8196 bytes
-    // (packed(8) alignment doesn't add padding for this layout)
-    assert_eq!(size_of::<TranslateMMNTWRequest>(), 8196);
-}
-
-#[test]
-fn test_translate_mmntw_response_size() {
-    // 4 bytes (status) + 4 bytes (result_code) + 4096 * 2 bytes (UTF-16 translated)
-    assert_eq!(size_of::<TranslateMMNTWResponse>(), 8200);
+fn test_translate_response_header_size() {
+    // 4 bytes (status) + 4 bytes (result_code); the variable-length
+    // translated text follows separately in the wire payload.
+    assert_eq!(size_of::<TranslateResponseHeader>(), 8);
 }
 
 #[test]
@@ -86,34 +75,20 @@ fn test_message_header_creation() {
 }
 
 #[test]
-fn test_buffer_initialization() {
-    let mut request = TranslateMMNTRequest {
-        data0: 0,
-        text: [0; 4096],
-    };
-
-    // Test writing to buffer
-    let test_str = b"Hello";
-    request.text[..test_str.len()].copy_from_slice(test_str);
-
-    assert_eq!(&request.text[..5], b"Hello");
-    assert_eq!(request.text[5], 0);
+fn test_translate_request_header_creation() {
+    let request = TranslateRequestHeader { data0: 0 };
+    assert_eq!(request.data0, 0);
 }
 
 #[test]
-fn test_wide_buffer_initialization() {
-    let mut request = TranslateMMNTWRequest {
-        data0: 0,
-        text: [0; 4096],
+fn test_translate_response_header_creation() {
+    let response = TranslateResponseHeader {
+        status: Status::Success,
+        result_code: 0,
     };
 
-    // Test writing UTF-16 to buffer
-    let test_str = "테스트";
-    let encoded: Vec<u16> = test_str.encode_utf16().collect();
-    request.text[..encoded.len()].copy_from_slice(&encoded);
-
-    let decoded = String::from_utf16_lossy(&request.text[..encoded.len()]);
-    assert_eq!(decoded, test_str);
+    assert_eq!(response.status as u32, Status::Success as u32);
+    assert_eq!(response.result_code, 0);
 }
 
 #[test]
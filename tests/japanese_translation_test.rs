@@ -2,9 +2,11 @@
 // Tests Japanese character ranges and validates translation output
 // Run with: cargo test --target i686-pc-windows-msvc --test japanese_translation_test -- --include-ignored --test-threads=1 --nocapture
 
-use eztrans_rs::EzTransEngine;
+use eztrans_rs::{EzTransEngine, EzTransError};
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::env;
+use std::fs::{File, OpenOptions};
 use std::io::{BufRead, BufReader, Write};
 use std::process::{Command, Stdio};
 use std::sync::mpsc;
@@ -79,6 +81,8 @@ struct NonKoreanResult {
 enum WorkerMessage {
     Progress {
         worker_id: usize,
+        /// 이 워커가 지금까지 완료한 마지막 절대 인덱스. 체크포인트 저장에 쓰인다.
+        abs_idx: u32,
         current_code: u32,
         tested: u32,
         non_korean_count: u32,
@@ -144,6 +148,81 @@ fn japanese_scan_worker_process(
     const CHUNK_SIZE: usize = 1000;
     const PROGRESS_INTERVAL_MS: u64 = 500;
     const CHUNK_INTERVAL_SECS: u64 = 5;
+    // 문자 하나마다 파이프/DLL 호출을 한 번씩 하면 왕복 비용이 지배적이 된다. 최대
+    // 이만큼을 줄바꿈으로 묶어 translate_mmntw를 한 번만 호출한 뒤, 결과를 같은
+    // 개수의 줄로 다시 쪼개 문자별로 배분한다.
+    const TRANSLATE_BATCH_SIZE: usize = 256;
+
+    let mut batch: Vec<(u32, char)> = Vec::with_capacity(TRANSLATE_BATCH_SIZE);
+    // 번역까지 끝난(배치가 비워진) 마지막 절대 인덱스. `Progress`는 이 값만 보낸다.
+    let mut last_flushed_abs_idx: Option<u32> = None;
+
+    let flush_batch = |batch: &mut Vec<(u32, char)>,
+                           total_tested: &mut u32,
+                           non_korean_count: &mut u32,
+                           non_korean_results: &mut Vec<NonKoreanResult>,
+                           pending_results: &mut Vec<NonKoreanResult>| {
+        if batch.is_empty() {
+            return;
+        }
+
+        let joined: String = batch
+            .iter()
+            .map(|(_, c)| c.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let translations: Result<Vec<String>, EzTransError> = engine
+            .translate_mmntw(&joined)
+            .map(|translated| translated.split('\n').map(|s| s.to_string()).collect());
+
+        for (i, &(code, c)) in batch.iter().enumerate() {
+            *total_tested += 1;
+            let test_str = c.to_string();
+
+            // 줄 수가 어긋나면(번역기가 줄바꿈을 보존하지 않는 드문 경우) 해당 문자는
+            // 에러로 기록하고 넘어간다 — 다른 문자의 결과와 뒤섞여 오염되는 것을 막는다.
+            let per_char = match &translations {
+                Ok(lines) if lines.len() == batch.len() => Ok(lines[i].clone()),
+                Ok(_) => Err("batch translation line count mismatch".to_string()),
+                Err(e) => Err(format!("{:?}", e)),
+            };
+
+            match per_char {
+                Ok(translated) => {
+                    let has_korean = contains_korean(&translated);
+                    let is_unchanged = translated == test_str;
+
+                    if !has_korean || is_unchanged {
+                        *non_korean_count += 1;
+                        let result = NonKoreanResult {
+                            codepoint: format!("U+{:04X}", code),
+                            character: c.to_string(),
+                            translation: translated.clone(),
+                            has_korean,
+                            error: String::new(),
+                        };
+                        non_korean_results.push(result.clone());
+                        pending_results.push(result);
+                    }
+                }
+                Err(message) => {
+                    *non_korean_count += 1;
+                    let result = NonKoreanResult {
+                        codepoint: format!("U+{:04X}", code),
+                        character: c.to_string(),
+                        translation: String::new(),
+                        has_korean: false,
+                        error: message,
+                    };
+                    non_korean_results.push(result.clone());
+                    pending_results.push(result);
+                }
+            }
+        }
+
+        batch.clear();
+    };
 
     for abs_idx in abs_start..=abs_end {
         let Some(code) = absolute_to_japanese_codepoint(abs_idx) else {
@@ -154,51 +233,30 @@ fn japanese_scan_worker_process(
             continue;
         };
 
-        total_tested += 1;
-
-        // 일본어 문자를 번역
-        let test_str = c.to_string();
-        match engine.translate_mmntw(&test_str) {
-            Ok(translated) => {
-                // 번역 결과에 한국어가 없거나, 원본과 동일한 경우 기록
-                let has_korean = contains_korean(&translated);
-                let is_unchanged = translated == test_str;
-
-                if !has_korean || is_unchanged {
-                    non_korean_count += 1;
-                    let result = NonKoreanResult {
-                        codepoint: format!("U+{:04X}", code),
-                        character: c.to_string(),
-                        translation: translated.clone(),
-                        has_korean,
-                        error: String::new(),
-                    };
-                    non_korean_results.push(result.clone());
-                    pending_results.push(result);
-                }
-            }
-            Err(e) => {
-                non_korean_count += 1;
-                let result = NonKoreanResult {
-                    codepoint: format!("U+{:04X}", code),
-                    character: c.to_string(),
-                    translation: String::new(),
-                    has_korean: false,
-                    error: format!("{:?}", e),
-                };
-                non_korean_results.push(result.clone());
-                pending_results.push(result);
-            }
+        batch.push((code, c));
+
+        if batch.len() >= TRANSLATE_BATCH_SIZE {
+            flush_batch(
+                &mut batch,
+                &mut total_tested,
+                &mut non_korean_count,
+                &mut non_korean_results,
+                &mut pending_results,
+            );
+            last_flushed_abs_idx = Some(abs_idx);
         }
 
         // 진행률 업데이트
         if last_progress.elapsed() >= Duration::from_millis(PROGRESS_INTERVAL_MS) {
-            send_message(&WorkerMessage::Progress {
-                worker_id,
-                current_code: code,
-                tested: total_tested,
-                non_korean_count,
-            });
+            if let Some(done_abs_idx) = last_flushed_abs_idx {
+                send_message(&WorkerMessage::Progress {
+                    worker_id,
+                    abs_idx: done_abs_idx,
+                    current_code: code,
+                    tested: total_tested,
+                    non_korean_count,
+                });
+            }
             last_progress = Instant::now();
         }
 
@@ -216,6 +274,14 @@ fn japanese_scan_worker_process(
         }
     }
 
+    flush_batch(
+        &mut batch,
+        &mut total_tested,
+        &mut non_korean_count,
+        &mut non_korean_results,
+        &mut pending_results,
+    );
+
     // 남은 청크 전송
     if !pending_results.is_empty() {
         send_message(&WorkerMessage::ChunkResult {
@@ -253,6 +319,49 @@ fn japanese_scan_worker() {
     }
 }
 
+/// 체크포인트 파일 경로. 스캔이 끝까지 완료되면 지워서 다음 실행이 처음부터
+/// 시작하도록 한다.
+const CHECKPOINT_PATH: &str = "japanese_scan_checkpoint.json";
+const CHECKPOINT_SAVE_INTERVAL: Duration = Duration::from_secs(5);
+/// DLL 자체가 고장 나 있는 경우 같은 워커를 무한히 재시작하지 않도록 두는 상한.
+const MAX_RESTARTS_PER_WORKER: u32 = 3;
+
+/// 워커별로 완료한 마지막 절대 인덱스를 기록하는 체크포인트. 중단 후 재시작할 때
+/// 각 워커의 범위를 `last_completed + 1`부터 다시 시작하도록 좁히는 데 쓰인다.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct ScanCheckpoint {
+    completed_through: HashMap<usize, u32>,
+}
+
+fn load_checkpoint() -> ScanCheckpoint {
+    std::fs::read_to_string(CHECKPOINT_PATH)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save_checkpoint(checkpoint: &ScanCheckpoint) {
+    if let Ok(json) = serde_json::to_string_pretty(checkpoint) {
+        let _ = std::fs::write(CHECKPOINT_PATH, json);
+    }
+}
+
+/// 이전 실행이 이미 CSV에 기록해 둔 코드포인트를 읽어온다. 체크포인트가 범위를
+/// 좁혀 두긴 하지만, 워커가 청크를 보내고 죽는 사이의 경계 구간처럼 체크포인트보다
+/// CSV가 더 최신인 경우를 대비한 이중 안전장치다.
+fn read_existing_codepoints(csv_path: &str) -> HashSet<String> {
+    let mut codes = HashSet::new();
+    let Ok(mut reader) = csv::Reader::from_path(csv_path) else {
+        return codes;
+    };
+    for record in reader.records().flatten() {
+        if let Some(codepoint) = record.get(0) {
+            codes.insert(codepoint.to_string());
+        }
+    }
+    codes
+}
+
 #[derive(Debug)]
 enum CoordinatorMessage {
     WorkerMessage {
@@ -260,7 +369,6 @@ enum CoordinatorMessage {
         msg: WorkerMessage,
     },
     WorkerEof {
-        #[allow(dead_code)]
         worker_id: usize,
     },
     WorkerError {
@@ -375,9 +483,10 @@ fn scan_japanese_multiprocess(num_processes_opt: Option<usize>) {
     }
     println!();
 
-    // 작업 분배
+    // 작업 분배 — 전체 범위는 체크포인트 유무와 무관하게 항상 고정된다. 각 워커의
+    // 재시작 상한(`worker_ends`)을 계산하는 데 쓰인다.
     let chunk_size = total_codepoints / num_processes as u32;
-    let mut work_assignments: Vec<(usize, u32, u32)> = Vec::new();
+    let mut full_ranges: Vec<(usize, u32, u32)> = Vec::new();
 
     for worker_id in 0..num_processes {
         let abs_start = worker_id as u32 * chunk_size;
@@ -386,7 +495,50 @@ fn scan_japanese_multiprocess(num_processes_opt: Option<usize>) {
         } else {
             (worker_id as u32 + 1) * chunk_size - 1
         };
-        work_assignments.push((worker_id, abs_start, abs_end));
+        full_ranges.push((worker_id, abs_start, abs_end));
+    }
+
+    // 체크포인트를 읽어 워커별 범위를 `last_completed + 1`부터 시작하도록 좁힌다.
+    // 이미 끝난 워커는 시작부터 완료 처리된다.
+    let mut checkpoint = load_checkpoint();
+    if !checkpoint.completed_through.is_empty() {
+        println!("Resuming from checkpoint: {:?}\n", checkpoint.completed_through);
+    }
+
+    let csv_path = "japanese_non_korean_translations.csv";
+    let resuming = std::path::Path::new(csv_path).exists();
+    let mut seen_codepoints = read_existing_codepoints(csv_path);
+
+    let worker_ends: HashMap<usize, u32> = full_ranges
+        .iter()
+        .map(|(worker_id, _, abs_end)| (*worker_id, *abs_end))
+        .collect();
+    // 크래시 직후 재스폰인데 아직 `Progress` 메시지를 한 번도 못 받아 체크포인트가
+    // 비어 있는 경우를 위한 대체 시작점. 체크포인트 기반 재개 로직과 동일하게
+    // `last_completed + 1`을 우선하되, 없으면 이 값으로 떨어진다.
+    let mut worker_starts: HashMap<usize, u32> = HashMap::new();
+
+    let mut work_assignments: Vec<(usize, u32, u32)> = Vec::new();
+    let mut worker_statuses: Vec<WorkerStatus> =
+        (0..num_processes).map(|_| WorkerStatus::new()).collect();
+    let mut workers_completed = 0usize;
+
+    for (worker_id, abs_start, abs_end) in &full_ranges {
+        let resume_start = checkpoint
+            .completed_through
+            .get(worker_id)
+            .map(|&last| last + 1)
+            .unwrap_or(*abs_start)
+            .max(*abs_start);
+
+        if resume_start > *abs_end {
+            worker_statuses[*worker_id].completed = true;
+            workers_completed += 1;
+            continue;
+        }
+
+        worker_starts.insert(*worker_id, resume_start);
+        work_assignments.push((*worker_id, resume_start, *abs_end));
     }
 
     println!("Work distribution:");
@@ -403,20 +555,38 @@ fn scan_japanese_multiprocess(num_processes_opt: Option<usize>) {
     }
     println!();
 
+    // CSV를 이어 쓸 수 있도록 연다. 이전 실행 결과가 있으면 append, 없으면 헤더를
+    // 쓴 새 파일로 시작한다.
+    let mut csv_file = if resuming {
+        OpenOptions::new()
+            .append(true)
+            .open(csv_path)
+            .expect("failed to reopen results CSV in append mode")
+    } else {
+        let mut file = File::create(csv_path).expect("failed to create results CSV");
+        writeln!(file, "Codepoint,Character,Translation,Has Korean,Error").ok();
+        file
+    };
+
     let overall_start_time = Instant::now();
     let current_exe = env::current_exe().expect("Failed to get current exe path");
 
     let (tx, rx) = mpsc::channel::<CoordinatorMessage>();
 
-    let mut worker_statuses: Vec<WorkerStatus> =
-        (0..num_processes).map(|_| WorkerStatus::new()).collect();
-    let mut workers_completed = 0usize;
     let mut all_non_korean_results: Vec<NonKoreanResult> = Vec::new();
     let mut total_tested = 0u32;
+    let mut last_checkpoint_save = Instant::now();
+    let mut restart_counts: HashMap<usize, u32> = HashMap::new();
 
     let mut reader_threads = Vec::new();
 
-    for (worker_id, abs_start, abs_end) in &work_assignments {
+    // 워커 하나를 스폰하고, stdout을 읽어 coordinator 채널로 중계하는 리더 스레드를
+    // 돌려준다. 최초 스폰과 크래시 후 재스폰 양쪽에서 공유한다.
+    let spawn_worker = |worker_id: usize,
+                        abs_start: u32,
+                        abs_end: u32,
+                        tx: &mpsc::Sender<CoordinatorMessage>|
+     -> Option<thread::JoinHandle<()>> {
         let worker_params = format!(
             "{}|{}|{}|{}|{}",
             worker_id, abs_start, abs_end, dll_path, dat_path
@@ -435,7 +605,6 @@ fn scan_japanese_multiprocess(num_processes_opt: Option<usize>) {
             Ok(mut child) => {
                 let stdout = child.stdout.take().expect("Failed to get stdout");
                 let tx_clone = tx.clone();
-                let wid = *worker_id;
 
                 let reader_thread = thread::spawn(move || {
                     let reader = BufReader::new(stdout);
@@ -444,38 +613,43 @@ fn scan_japanese_multiprocess(num_processes_opt: Option<usize>) {
                             Ok(line) => {
                                 if let Ok(msg) = serde_json::from_str::<WorkerMessage>(&line) {
                                     let _ = tx_clone.send(CoordinatorMessage::WorkerMessage {
-                                        worker_id: wid,
+                                        worker_id,
                                         msg,
                                     });
                                 }
                             }
                             Err(e) => {
                                 let _ = tx_clone.send(CoordinatorMessage::WorkerError {
-                                    worker_id: wid,
+                                    worker_id,
                                     error: e.to_string(),
                                 });
                                 break;
                             }
                         }
                     }
-                    let _ = tx_clone.send(CoordinatorMessage::WorkerEof { worker_id: wid });
+                    let _ = tx_clone.send(CoordinatorMessage::WorkerEof { worker_id });
                     let _ = child.wait();
                 });
 
-                reader_threads.push(reader_thread);
-                println!("Spawned worker {}", worker_id);
+                println!("Spawned worker {} (U+{:04X}..)", worker_id, abs_start);
+                Some(reader_thread)
             }
             Err(e) => {
                 eprintln!("Failed to spawn worker {}: {}", worker_id, e);
-                workers_completed += 1;
+                None
             }
         }
+    };
+
+    for (worker_id, abs_start, abs_end) in &work_assignments {
+        match spawn_worker(*worker_id, *abs_start, *abs_end, &tx) {
+            Some(reader_thread) => reader_threads.push(reader_thread),
+            None => workers_completed += 1,
+        }
 
         thread::sleep(Duration::from_millis(100));
     }
 
-    drop(tx);
-
     println!("\nProcessing...\n");
 
     let mut last_dashboard_update = Instant::now();
@@ -486,6 +660,7 @@ fn scan_japanese_multiprocess(num_processes_opt: Option<usize>) {
             Ok(coord_msg) => match coord_msg {
                 CoordinatorMessage::WorkerMessage { worker_id, msg } => match msg {
                     WorkerMessage::Progress {
+                        abs_idx,
                         tested,
                         non_korean_count,
                         ..
@@ -494,9 +669,25 @@ fn scan_japanese_multiprocess(num_processes_opt: Option<usize>) {
                             worker_statuses[worker_id].tested = tested;
                             worker_statuses[worker_id].non_korean_count = non_korean_count;
                         }
+                        checkpoint.completed_through.insert(worker_id, abs_idx);
                     }
                     WorkerMessage::ChunkResult { results, .. } => {
-                        all_non_korean_results.extend(results);
+                        for result in results {
+                            if seen_codepoints.insert(result.codepoint.clone()) {
+                                writeln!(
+                                    csv_file,
+                                    "{},\"{}\",\"{}\",{},\"{}\"",
+                                    result.codepoint,
+                                    result.character.replace('"', "\"\""),
+                                    result.translation.replace('"', "\"\""),
+                                    result.has_korean,
+                                    result.error.replace('"', "\"\"")
+                                )
+                                .ok();
+                                all_non_korean_results.push(result);
+                            }
+                        }
+                        csv_file.flush().ok();
                     }
                     WorkerMessage::Complete {
                         worker_id: wid,
@@ -509,6 +700,10 @@ fn scan_japanese_multiprocess(num_processes_opt: Option<usize>) {
                             worker_statuses[wid].non_korean_count = non_korean_count;
                             worker_statuses[wid].completed = true;
                         }
+                        if let Some(&abs_end) = worker_ends.get(&wid) {
+                            checkpoint.completed_through.insert(wid, abs_end);
+                        }
+                        save_checkpoint(&checkpoint);
                         total_tested += tested;
                     }
                     WorkerMessage::Error {
@@ -518,18 +713,65 @@ fn scan_japanese_multiprocess(num_processes_opt: Option<usize>) {
                         eprintln!("\n[Worker {}] Error: {}", wid, message);
                     }
                 },
-                CoordinatorMessage::WorkerEof { .. } => {
-                    workers_completed += 1;
+                CoordinatorMessage::WorkerEof { worker_id } => {
+                    let already_done = worker_id >= worker_statuses.len()
+                        || worker_statuses[worker_id].completed;
+
+                    if already_done {
+                        workers_completed += 1;
+                        continue;
+                    }
+
+                    // `Complete`보다 먼저 EOF가 왔다는 건 엔진이 죽었다는 뜻이다. 체크포인트에
+                    // 남은 마지막 위치부터 같은 워커를 재스폰해 나머지 범위를 이어서 스캔한다.
+                    let restarts = restart_counts.entry(worker_id).or_insert(0);
+                    let Some(&abs_end) = worker_ends.get(&worker_id) else {
+                        workers_completed += 1;
+                        continue;
+                    };
+                    let resume_start = checkpoint
+                        .completed_through
+                        .get(&worker_id)
+                        .map(|&last| last + 1)
+                        .unwrap_or_else(|| worker_starts.get(&worker_id).copied().unwrap_or(abs_end + 1));
+
+                    if resume_start > abs_end || *restarts >= MAX_RESTARTS_PER_WORKER {
+                        eprintln!(
+                            "\n[Worker {}] gave up after {} restarts",
+                            worker_id, *restarts
+                        );
+                        workers_completed += 1;
+                        continue;
+                    }
+
+                    *restarts += 1;
+                    eprintln!(
+                        "\n[Worker {}] crashed before completing; restarting (attempt {}) from U+{:04X}",
+                        worker_id,
+                        *restarts,
+                        absolute_to_japanese_codepoint(resume_start).unwrap_or(0)
+                    );
+                    match spawn_worker(worker_id, resume_start, abs_end, &tx) {
+                        Some(reader_thread) => reader_threads.push(reader_thread),
+                        None => workers_completed += 1,
+                    }
                 }
                 CoordinatorMessage::WorkerError { worker_id, error } => {
                     eprintln!("\n[Worker {}] Read error: {}", worker_id, error);
-                    workers_completed += 1;
                 }
             },
             Err(mpsc::RecvTimeoutError::Timeout) => {}
             Err(mpsc::RecvTimeoutError::Disconnected) => break,
         }
 
+        // 주기적으로 체크포인트를 디스크에 반영한다 (Progress/Complete 메시지로도
+        // 갱신되지만, 중간에 프로세스가 죽어도 최근 몇 초 이내 상태는 보존되도록
+        // 별도로 저장한다).
+        if last_checkpoint_save.elapsed() >= CHECKPOINT_SAVE_INTERVAL {
+            save_checkpoint(&checkpoint);
+            last_checkpoint_save = Instant::now();
+        }
+
         // 대시보드 업데이트
         if last_dashboard_update.elapsed() >= dashboard_interval {
             print_progress_dashboard(&worker_statuses, total_codepoints, overall_start_time);
@@ -553,31 +795,14 @@ fn scan_japanese_multiprocess(num_processes_opt: Option<usize>) {
     );
     println!("Total Japanese characters tested: {}", total_tested);
     println!("Non-Korean translations found: {}", all_non_korean_results.len());
+    println!("\nResults appended to: {}", csv_path);
 
-    // CSV 파일로 저장
-    let csv_path = "japanese_non_korean_translations.csv";
-    let mut wtr = csv::Writer::from_path(csv_path).expect("Failed to create CSV file");
-
-    // CSV 헤더
-    wtr.write_record(&["Codepoint", "Character", "Translation", "Has Korean", "Error"])
-        .expect("Failed to write CSV header");
-
-    // CSV 데이터
-    for result in &all_non_korean_results {
-        wtr.write_record(&[
-            &result.codepoint,
-            &result.character,
-            &result.translation,
-            &result.has_korean.to_string(),
-            &result.error,
-        ])
-        .expect("Failed to write CSV record");
+    // 모든 워커가 자기 범위를 끝까지 마쳤을 때만 체크포인트를 지운다. 다음 실행은
+    // 처음부터(혹은 새 범위로) 다시 시작한다. 포기한 워커가 있으면 남겨 둔다.
+    if worker_statuses.iter().all(|s| s.completed) {
+        let _ = std::fs::remove_file(CHECKPOINT_PATH);
     }
 
-    wtr.flush().expect("Failed to flush CSV writer");
-
-    println!("\nResults saved to: {}", csv_path);
-
     // 통계 출력
     let error_count = all_non_korean_results.iter().filter(|r| !r.error.is_empty()).count();
     let unchanged_count = all_non_korean_results
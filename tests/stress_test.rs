@@ -0,0 +1,257 @@
+// Randomized-Interleaving Concurrency Stress Harness for EzTrans DLL
+//
+// `thread_local_test.rs`/`thread_safety_test.rs` only drive the DLL through a handful
+// of fixed schedules (one `EzTransEngine` per thread, two engines serialized, staggered
+// init). That answers "does this one schedule corrupt output?" but not "which
+// interleavings does the DLL actually tolerate?". This harness borrows Miri's idea of a
+// tunable, seed-reproducible randomized scheduler: each worker thread derives its own
+// sub-seed from a single run seed and picks random delays/texts/init-translate-drop
+// orderings from it, so a failing run can be replayed exactly by re-running the same
+// seed.
+//
+// Run with: cargo test --target i686-pc-windows-msvc --test stress_test -- --ignored --nocapture
+
+use eztrans_rs::EzTransEngine;
+use std::sync::{Arc, Barrier};
+use std::thread;
+use std::time::Duration;
+
+fn get_engine_paths() -> (String, String) {
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let dll_path = format!("{}/../eztrans_dll/J2KEngine.dll", manifest_dir);
+    let dat_path = format!("{}/../eztrans_dll/Dat", manifest_dir);
+    (dll_path, dat_path)
+}
+
+/// Check if output looks corrupted. Same heuristic as `thread_local_test`/
+/// `thread_safety_test`, duplicated here so this file stays runnable on its own.
+fn is_corrupted(input: &str, output: &str) -> bool {
+    if !input.is_empty() && output.is_empty() {
+        return true;
+    }
+
+    if output.contains('\0') {
+        return true;
+    }
+
+    for c in output.chars() {
+        if c.is_control() && c != '\n' && c != '\r' && c != '\t' {
+            return true;
+        }
+    }
+
+    let korean_count = output
+        .chars()
+        .filter(|c| {
+            let code = *c as u32;
+            (code >= 0xAC00 && code <= 0xD7A3)
+                || (code >= 0x3000 && code <= 0x303F)
+                || c.is_ascii_punctuation()
+                || c.is_whitespace()
+        })
+        .count();
+
+    let total = output.chars().count();
+    if total > 5 {
+        let ratio = korean_count as f64 / total as f64;
+        if ratio < 0.3 {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// A tiny splitmix64-based PRNG. Not cryptographic, just deterministic and fast —
+/// the same seed always produces the same stream, which is the whole point of this
+/// harness (a failing schedule must be replayable from its seed alone).
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform-enough value in `0..bound`. `bound` must be nonzero.
+    fn next_below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound
+    }
+}
+
+const SAMPLE_TEXTS: [&str; 4] = ["おはよう", "こんにちは", "ありがとう", "さようなら"];
+
+/// One worker's randomized plan, derived entirely from its sub-seed: how long to wait
+/// before initializing its engine, which texts to translate in which order, and
+/// whether to drop the engine partway through and recreate it.
+struct WorkerPlan {
+    init_delay: Duration,
+    steps: Vec<PlanStep>,
+}
+
+enum PlanStep {
+    Translate(&'static str),
+    DropAndReinit,
+}
+
+fn build_plan(rng: &mut SplitMix64, steps_per_worker: usize) -> WorkerPlan {
+    let init_delay = Duration::from_micros(rng.next_below(2000));
+    let steps = (0..steps_per_worker)
+        .map(|_| {
+            // One in eight steps drops and recreates the engine instead of translating,
+            // to interleave init/drop traffic with ordinary translation calls.
+            if rng.next_below(8) == 0 {
+                PlanStep::DropAndReinit
+            } else {
+                let text = SAMPLE_TEXTS[rng.next_below(SAMPLE_TEXTS.len() as u64) as usize];
+                PlanStep::Translate(text)
+            }
+        })
+        .collect();
+    WorkerPlan { init_delay, steps }
+}
+
+/// What a failing run reports: the seed that reproduces it, which worker saw it, and
+/// the corrupted input/output pair.
+#[derive(Debug)]
+struct CorruptionReport {
+    seed: u64,
+    worker_id: usize,
+    input: String,
+    output: String,
+}
+
+/// Runs one randomized schedule for `seed` across `worker_count` threads, each doing
+/// `steps_per_worker` randomized init/translate/drop actions. Returns the corruption
+/// reported by the lowest-numbered worker that saw one, if any.
+fn run_schedule(
+    seed: u64,
+    worker_count: usize,
+    steps_per_worker: usize,
+    dll_path: &str,
+    dat_path: &str,
+) -> Option<CorruptionReport> {
+    let barrier = Arc::new(Barrier::new(worker_count));
+
+    let handles: Vec<_> = (0..worker_count)
+        .map(|worker_id| {
+            // Each worker gets its own sub-seed so its schedule is fully determined by
+            // (seed, worker_id), not by what other workers happen to do.
+            let mut seed_rng = SplitMix64::new(seed);
+            for _ in 0..=worker_id {
+                seed_rng.next_u64();
+            }
+            let mut rng = SplitMix64::new(seed_rng.next_u64());
+            let plan = build_plan(&mut rng, steps_per_worker);
+
+            let dll_path = dll_path.to_string();
+            let dat_path = dat_path.to_string();
+            let barrier = Arc::clone(&barrier);
+
+            thread::spawn(move || -> Option<CorruptionReport> {
+                barrier.wait();
+                thread::sleep(plan.init_delay);
+
+                let mut engine = EzTransEngine::new(&dll_path).ok()?;
+                engine.initialize_ex("CSUSER123455", &dat_path).ok()?;
+
+                for step in plan.steps {
+                    match step {
+                        PlanStep::Translate(text) => {
+                            if let Ok(output) = engine.translate_mmntw(text) {
+                                if is_corrupted(text, &output) {
+                                    return Some(CorruptionReport {
+                                        seed,
+                                        worker_id,
+                                        input: text.to_string(),
+                                        output,
+                                    });
+                                }
+                            }
+                        }
+                        PlanStep::DropAndReinit => {
+                            engine = EzTransEngine::new(&dll_path).ok()?;
+                            engine.initialize_ex("CSUSER123455", &dat_path).ok()?;
+                        }
+                    }
+                }
+                None
+            })
+        })
+        .collect();
+
+    handles
+        .into_iter()
+        .filter_map(|handle| handle.join().ok().flatten())
+        .next()
+}
+
+/// Tries `seed_count` seeds (0, 1, 2, ...) and reports the smallest one that reproduces
+/// a corruption, so maintainers get a minimal, replayable schedule rather than having to
+/// wade through every seed tried.
+#[test]
+#[ignore]
+fn test_randomized_interleaving_stress() {
+    let (dll_path, dat_path) = get_engine_paths();
+    let worker_count = 4;
+    let steps_per_worker = 25;
+    let seed_count = 200;
+
+    println!("\n=== Randomized Interleaving Stress Test ===");
+    println!(
+        "workers={worker_count} steps/worker={steps_per_worker} seeds=0..{seed_count}\n"
+    );
+
+    let mut first_failure: Option<CorruptionReport> = None;
+    for seed in 0..seed_count {
+        if let Some(report) = run_schedule(seed, worker_count, steps_per_worker, &dll_path, &dat_path) {
+            println!(
+                "  seed {} reproduced corruption on worker {}: {:?} -> {:?}",
+                report.seed, report.worker_id, report.input, report.output
+            );
+            first_failure = Some(report);
+            break;
+        }
+    }
+
+    match first_failure {
+        Some(report) => {
+            panic!(
+                "minimal reproducing seed is {} (worker {}); replay with `STRESS_SEED={} cargo test --test stress_test -- --ignored test_replay_seed --nocapture`",
+                report.seed, report.worker_id, report.seed
+            );
+        }
+        None => {
+            println!("✓ no corruption observed across {} seeds", seed_count);
+        }
+    }
+}
+
+/// Replays a single seed captured from a previous failing run of
+/// `test_randomized_interleaving_stress`, read from the `STRESS_SEED` env var.
+#[test]
+#[ignore]
+fn test_replay_seed() {
+    let seed: u64 = std::env::var("STRESS_SEED")
+        .expect("set STRESS_SEED to the seed reported by test_randomized_interleaving_stress")
+        .parse()
+        .expect("STRESS_SEED must be a u64");
+
+    let (dll_path, dat_path) = get_engine_paths();
+    println!("\n=== Replaying stress seed {} ===", seed);
+
+    match run_schedule(seed, 4, 25, &dll_path, &dat_path) {
+        Some(report) => panic!(
+            "seed {} reproduced corruption on worker {}: {:?} -> {:?}",
+            report.seed, report.worker_id, report.input, report.output
+        ),
+        None => println!("✓ seed {} did not reproduce a corruption", seed),
+    }
+}
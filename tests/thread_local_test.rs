@@ -710,10 +710,14 @@ fn test_dll_handle_identity() {
     let engine2 = EzTransEngine::new(&dll_path).expect("Failed to create engine 2");
     // Don't initialize engine2 to see if the handle is the same
 
-    println!("  Engine 1 HMODULE: {:?}", engine1.module);
-    println!("  Engine 2 HMODULE: {:?}", engine2.module);
-
-    if engine1.module == engine2.module {
+    // `EzTransEngine`은 더 이상 HMODULE을 직접 노출하지 않으므로, 대신 로드된 함수
+    // 포인터 주소를 비교해 같은 DLL 인스턴스를 가리키는지 확인한다.
+    let terminate1 = engine1.terminate.map(|f| f as usize);
+    let terminate2 = engine2.terminate.map(|f| f as usize);
+    println!("  Engine 1 J2K_Terminate addr: {:?}", terminate1);
+    println!("  Engine 2 J2K_Terminate addr: {:?}", terminate2);
+
+    if terminate1 == terminate2 {
         println!("\n✗ Both engines share the SAME DLL handle!");
         println!("  LoadLibrary returns the same HMODULE for already-loaded DLLs.");
         println!("  This means Thread-Local engines are actually sharing the same DLL instance.");